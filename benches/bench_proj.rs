@@ -28,10 +28,32 @@ fn etmerc_transform(itermax: usize) {
     transform(&from, &to, data.as_mut_slice()).unwrap();
 }
 
+fn lcc_inverse_transform(itermax: usize) {
+    let d = 1.0 / (itermax as f64);
+
+    let mut data: Vec<(f64, f64, f64)> = (1..=itermax)
+        .map(|i| {
+            (
+                -200_000. + (i as f64) * 400_000. * d,
+                -100_000. + (i as f64) * 200_000. * d,
+                0.,
+            )
+        })
+        .collect();
+
+    let from = Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+    let to = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+
+    transform(&from, &to, data.as_mut_slice()).unwrap();
+}
+
 fn criterion_benchmark_proj(c: &mut Criterion) {
     c.bench_function("tmerc forward", |b| {
         b.iter(|| etmerc_transform(black_box(10_000usize)))
     });
+    c.bench_function("lcc inverse", |b| {
+        b.iter(|| lcc_inverse_transform(black_box(10_000usize)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark_proj);
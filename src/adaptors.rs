@@ -4,10 +4,101 @@
 #[cfg(feature = "geo-types")]
 pub mod geo_types;
 
-use crate::errors::Result;
+use crate::errors::{Error, Result};
+use crate::math::consts::TAU;
 use crate::proj::Proj;
 use crate::transform::{transform, Transform, TransformClosure};
 
+/// A 3D coordinate with explicit units
+///
+/// Coordinates are stored in the natural units of the library:
+/// radians for geographic coordinates and meters (or projection
+/// units) for projected coordinates. Use the constructors
+/// to state explicitly the units of the input values.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::Coord3;
+/// use proj4rs::transform::transform;
+///
+/// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+///
+/// let mut pt = Coord3::from_degrees(2.0, 1.0, 0.0);
+/// transform(&src, &dst, &mut pt).unwrap();
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Coord3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Coord3 {
+    /// Create a geographic coordinate from longitude and latitude in degrees
+    pub fn from_degrees(lon: f64, lat: f64, z: f64) -> Self {
+        Self {
+            x: lon.to_radians(),
+            y: lat.to_radians(),
+            z,
+        }
+    }
+
+    /// Create a geographic coordinate from longitude and latitude in radians
+    pub fn from_radians(lon: f64, lat: f64, z: f64) -> Self {
+        Self { x: lon, y: lat, z }
+    }
+
+    /// Create a projected or geocentric coordinate in meters
+    pub fn from_meters(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Return (longitude, latitude, z) in degrees,
+    /// assuming a geographic coordinate
+    pub fn to_degrees(&self) -> (f64, f64, f64) {
+        (self.x.to_degrees(), self.y.to_degrees(), self.z)
+    }
+
+    /// Return (longitude, latitude, z) in radians,
+    /// assuming a geographic coordinate
+    pub fn to_radians(&self) -> (f64, f64, f64) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Return (x, y, z) in meters, assuming a projected
+    /// or geocentric coordinate
+    pub fn to_meters(&self) -> (f64, f64, f64) {
+        (self.x, self.y, self.z)
+    }
+}
+
+impl From<(f64, f64, f64)> for Coord3 {
+    fn from(xyz: (f64, f64, f64)) -> Self {
+        Self {
+            x: xyz.0,
+            y: xyz.1,
+            z: xyz.2,
+        }
+    }
+}
+
+impl From<Coord3> for (f64, f64, f64) {
+    fn from(c: Coord3) -> Self {
+        (c.x, c.y, c.z)
+    }
+}
+
+//
+// Transform a Coord3
+//
+impl Transform for Coord3 {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        (self.x, self.y, self.z) = f(self.x, self.y, self.z)?;
+        Ok(())
+    }
+}
+
 //
 // Transform a 3-tuple
 //
@@ -36,7 +127,6 @@ impl Transform for (f64, f64) {
 ///
 /// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
 /// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
-
 /// let (x, y, z) = transform_vertex_3d(&src, &dst, (2.0, 1.0, 0.0)).unwrap();
 /// ```
 pub fn transform_vertex_3d(src: &Proj, dst: &Proj, pt: (f64, f64, f64)) -> Result<(f64, f64, f64)> {
@@ -47,13 +137,14 @@ pub fn transform_vertex_3d(src: &Proj, dst: &Proj, pt: (f64, f64, f64)) -> Resul
 
 /// Transform a 2-tuple
 ///
+/// The height is assumed to be zero, see also [`transform_2d`](crate::transform::transform_2d).
+///
 /// ```rust
 /// use proj4rs::Proj;
 /// use proj4rs::adaptors::transform_vertex_2d;
 ///
 /// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
 /// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
-
 /// let (x, y) = transform_vertex_2d(&src, &dst, (2.0, 1.0)).unwrap();
 /// ```
 #[inline(always)]
@@ -63,13 +154,14 @@ pub fn transform_vertex_2d(src: &Proj, dst: &Proj, pt: (f64, f64)) -> Result<(f6
 
 /// Transform x, y and z value
 ///
+/// Unlike [`transform_xy`], the height is transformed and returned.
+///
 /// ```rust
 /// use proj4rs::Proj;
 /// use proj4rs::adaptors::transform_xyz;
 ///
 /// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
 /// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
-
 /// let (x, y, z) = transform_xyz(&src, &dst, 2.0, 1.0, 0.0).unwrap();
 /// ```
 #[inline(always)]
@@ -85,7 +177,6 @@ pub fn transform_xyz(src: &Proj, dst: &Proj, x: f64, y: f64, z: f64) -> Result<(
 ///
 /// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
 /// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
-
 /// let (x, y) = transform_xy(&src, &dst, 2.0, 1.0).unwrap();
 /// ```
 #[inline(always)]
@@ -93,6 +184,70 @@ pub fn transform_xy(src: &Proj, dst: &Proj, x: f64, y: f64) -> Result<(f64, f64)
     transform_xyz(src, dst, x, y, 0.).map(|(x, y, _)| (x, y))
 }
 
+/// Transform an array of 3-tuple into a separate output buffer
+///
+/// `src` is left untouched and the transformed coordinates are
+/// written to `dst`. Return [`Error::InvalidNumberOfCoordinates`]
+/// if the buffers have different lengths.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::transform_many_into;
+///
+/// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+///
+/// let input = [(0.01, 0.02, 0.), (0.03, 0.04, 0.)];
+/// let mut output = [(0., 0., 0.); 2];
+/// transform_many_into(&src, &dst, &input, &mut output).unwrap();
+/// ```
+pub fn transform_many_into(
+    from: &Proj,
+    to: &Proj,
+    src: &[(f64, f64, f64)],
+    dst: &mut [(f64, f64, f64)],
+) -> Result<()> {
+    if src.len() != dst.len() {
+        return Err(Error::InvalidNumberOfCoordinates);
+    }
+    dst.copy_from_slice(src);
+    transform(from, to, dst)
+}
+
+/// Unwrap the longitudes of a sequence of geographic coordinates
+///
+/// Longitudes (in radians) are shifted by multiples of 2π so that
+/// consecutive points differ by less than π. This makes polylines
+/// crossing the antimeridian continuous after an inverse
+/// projection: the output longitudes may be out of the
+/// [-π, π] range.
+///
+/// Unlike the `+over` parameter, this operates on the transformed
+/// sequence and not on individual points.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::unwrap_longitudes;
+/// use proj4rs::transform::transform;
+///
+/// let src = Proj::from_proj_string("+proj=merc +ellps=WGS84").unwrap();
+/// let dst = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+///
+/// let mut line = [(20037000., 0., 0.), (-20037000., 0., 0.)];
+/// transform(&src, &dst, line.as_mut_slice()).unwrap();
+/// unwrap_longitudes(&mut line);
+/// assert!(line[1].0 > line[0].0);
+/// ```
+pub fn unwrap_longitudes(pts: &mut [(f64, f64, f64)]) {
+    let mut prev: Option<f64> = None;
+    pts.iter_mut().for_each(|pt| {
+        if let Some(prev) = prev {
+            pt.0 -= TAU * ((pt.0 - prev) / TAU).round();
+        }
+        prev = Some(pt.0);
+    });
+}
+
 //
 // Transform an array of 3-tuple:
 //
@@ -112,3 +267,121 @@ impl Transform for [(f64, f64)] {
             .try_for_each(|xy| xy.transform_coordinates(f))
     }
 }
+
+//
+// Transform an array of Coord3
+//
+impl Transform for [Coord3] {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        self.iter_mut()
+            .try_for_each(|xyz| xyz.transform_coordinates(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn coord3_round_trip() {
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let utm = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
+
+        let mut pt = Coord3::from_degrees(2.0, 1.0, 0.0);
+        assert_eq!(
+            pt,
+            Coord3::from_radians(2f64.to_radians(), 1f64.to_radians(), 0.)
+        );
+
+        transform(&geo, &utm, &mut pt).unwrap();
+        let (x, y, _) = pt.to_meters();
+        assert_abs_diff_eq!(x, 1057002.4054912976, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(y, 110955.14117594929, epsilon = 1.0e-6);
+
+        transform(&utm, &geo, &mut pt).unwrap();
+        let (lon, lat, z) = pt.to_degrees();
+        assert_abs_diff_eq!(lon, 2.0, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(lat, 1.0, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(z, 0.0, epsilon = 1.0e-10);
+
+        // Conversion from/to tuples
+        let xyz: (f64, f64, f64) = Coord3::from((1., 2., 3.)).into();
+        assert_eq!(xyz, (1., 2., 3.));
+    }
+
+    #[test]
+    fn transform_many_into_buffer() {
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let utm = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
+
+        let src = [
+            (2f64.to_radians(), 1f64.to_radians(), 0.),
+            (-3f64.to_radians(), 45f64.to_radians(), 10.),
+        ];
+        let copy = src;
+        let mut dst = [(0., 0., 0.); 2];
+
+        transform_many_into(&geo, &utm, &src, &mut dst).unwrap();
+
+        // Source is unchanged
+        assert_eq!(src, copy);
+
+        for (s, d) in src.iter().zip(dst.iter()) {
+            let expected = transform_vertex_3d(&geo, &utm, *s).unwrap();
+            assert_eq!(*d, expected);
+        }
+        assert_abs_diff_eq!(dst[0].0, 1057002.4054912976, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(dst[0].1, 110955.14117594929, epsilon = 1.0e-6);
+
+        // Buffers must have the same length
+        let mut short = [(0., 0., 0.); 1];
+        assert!(matches!(
+            transform_many_into(&geo, &utm, &src, &mut short),
+            Err(Error::InvalidNumberOfCoordinates)
+        ));
+    }
+
+    #[test]
+    fn transform_xyz_datum_shift() {
+        let src = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+        let dst = Proj::from_proj_string(concat!(
+            "+proj=latlong +ellps=bessel ",
+            "+towgs84=414.1,41.3,603.1,-0.855,2.141,-7.023,0"
+        ))
+        .unwrap();
+
+        let (lon, lat, z) =
+            transform_xyz(&src, &dst, 15f64.to_radians(), 58f64.to_radians(), 100.).unwrap();
+
+        assert_abs_diff_eq!(lon.to_degrees(), 15.002968865381387, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(lat.to_degrees(), 58.000354173162215, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(z, 63.19804048930886, epsilon = 1.0e-6);
+
+        // The height is preserved by the inverse transformation,
+        // within the accuracy of the small angles approximation of
+        // the Helmert rotation.
+        let (lon, lat, z) = transform_xyz(&dst, &src, lon, lat, z).unwrap();
+        assert_abs_diff_eq!(lon.to_degrees(), 15., epsilon = 1.0e-7);
+        assert_abs_diff_eq!(lat.to_degrees(), 58., epsilon = 1.0e-7);
+        assert_abs_diff_eq!(z, 100., epsilon = 1.0e-2);
+    }
+
+    #[test]
+    fn unwrap_longitudes_antimeridian() {
+        let src = Proj::from_proj_string("+proj=merc +ellps=WGS84 +lon_0=180").unwrap();
+        let dst = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+
+        // A line crossing the antimeridian eastward
+        let mut line: Vec<_> = (-5..=5)
+            .map(|i| (i as f64 * 100000., 1000000., 0.))
+            .collect();
+        transform(&src, &dst, line.as_mut_slice()).unwrap();
+        assert!(line.windows(2).any(|w| w[1].0 < w[0].0));
+
+        unwrap_longitudes(&mut line);
+        assert!(line.windows(2).all(|w| w[1].0 > w[0].0));
+        assert!(line.iter().all(|pt| pt.0 > 0.));
+        assert_abs_diff_eq!(line[5].0.abs(), std::f64::consts::PI, epsilon = 1.0e-12);
+    }
+}
@@ -1,14 +1,127 @@
 use geo_types::geometry::*;
 
 use crate::{
-    errors::Result,
-    transform::{Transform, TransformClosure},
+    errors::{Error, Result},
+    proj::Proj,
+    transform::{transform, Transform, TransformClosure},
 };
 
+/// Error returned by [`transform_indexed`]
+#[derive(thiserror::Error, Debug)]
+#[error("{error} (coordinate index: {index:?})")]
+pub struct IndexedError {
+    /// Index of the coordinate that failed, in the order the
+    /// coordinates are visited; `None` if the error is not
+    /// related to a coordinate
+    pub index: Option<usize>,
+    #[source]
+    pub error: Error,
+}
+
+/// Transform the rectangle `rect` from `src` to `dst` CRS
+///
+/// A transformed rectangle is not axis-aligned in general: the
+/// four corners are transformed and the bounding box of the
+/// transformed corners is returned.
+///
+/// Note that this bounding box may not contain the whole transformed
+/// rectangle if the edges are curved by the transformation. Transform
+/// the corresponding [`Polygon`] to get the exact shape.
+///
+/// `Rect` does not implement [`Transform`]: the bounding box can only
+/// be computed once the corners have gone through the whole transformation.
+pub fn transform_rect(src: &Proj, dst: &Proj, rect: &Rect) -> Result<Rect> {
+    let (min, max) = (rect.min(), rect.max());
+    let mut corners = LineString::new(vec![
+        min,
+        Coord { x: max.x, y: min.y },
+        max,
+        Coord { x: min.x, y: max.y },
+    ]);
+    transform(src, dst, &mut corners)?;
+
+    let first = corners.0[0];
+    let (min, max) = corners
+        .coords()
+        .skip(1)
+        .fold((first, first), |(min, max), c| {
+            (
+                Coord {
+                    x: min.x.min(c.x),
+                    y: min.y.min(c.y),
+                },
+                Coord {
+                    x: max.x.max(c.x),
+                    y: max.y.max(c.y),
+                },
+            )
+        });
+    Ok(Rect::new(min, max))
+}
+
+/// Transform `geometry` from `src` to `dst` CRS, reporting the index of
+/// the coordinate that failed on error
+///
+/// Coordinates are indexed in the order they are visited: points of
+/// multi-geometries in order, the exterior ring of polygons before
+/// their interiors, and so on. As with [`transform`], the geometry is
+/// left partially transformed on error.
+///
+/// ```rust
+/// use geo_types::line_string;
+/// use proj4rs::Proj;
+/// use proj4rs::adaptors::geo_types::transform_indexed;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+/// let dst = Proj::from_proj_string("+proj=merc +ellps=GRS80").unwrap();
+///
+/// // The pole cannot be projected with merc
+/// let mut line = line_string![(x: 0., y: 0.), (x: 0., y: 1.5707963267948966)];
+/// let err = transform_indexed(&src, &dst, &mut line).unwrap_err();
+/// assert_eq!(err.index, Some(1));
+/// ```
+pub fn transform_indexed<T>(
+    src: &Proj,
+    dst: &Proj,
+    geometry: &mut T,
+) -> std::result::Result<(), IndexedError>
+where
+    T: Transform + ?Sized,
+{
+    let mut indexed = Indexed {
+        geometry,
+        failed: None,
+    };
+    transform(src, dst, &mut indexed).map_err(|error| IndexedError {
+        index: indexed.failed,
+        error,
+    })
+}
+
+struct Indexed<'a, T: ?Sized> {
+    geometry: &'a mut T,
+    failed: Option<usize>,
+}
+
+impl<T: Transform + ?Sized> Transform for Indexed<'_, T> {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        let mut index = 0;
+        let failed = &mut self.failed;
+        self.geometry.transform_coordinates(&mut |x, y, z| {
+            let rv = f(x, y, z);
+            if rv.is_err() {
+                *failed = Some(index);
+            }
+            index += 1;
+            rv
+        })
+    }
+}
+
 impl Transform for Coord {
     fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
         let mut xy = (self.x, self.y);
-        (&mut xy).transform_coordinates(f)?;
+        xy.transform_coordinates(f)?;
         *self = Coord::from(xy);
         Ok(())
     }
@@ -70,25 +183,19 @@ impl Transform for MultiPolygon {
     }
 }
 
-impl Transform for Rect {
-    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
-        let (mut min, mut max) = (self.min(), self.max());
-        min.transform_coordinates(f)?;
-        max.transform_coordinates(f)?;
-        self.set_min(min);
-        self.set_max(max);
-        Ok(())
-    }
-}
-
 impl Transform for Triangle {
     fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
-        self.0.transform_coordinates(f)?;
-        self.1.transform_coordinates(f)?;
-        self.2.transform_coordinates(f)
+        let [mut v1, mut v2, mut v3] = self.to_array();
+        v1.transform_coordinates(f)?;
+        v2.transform_coordinates(f)?;
+        v3.transform_coordinates(f)?;
+        *self = Triangle::new(v1, v2, v3);
+        Ok(())
     }
 }
 
+/// A [`Rect`] is not axis-aligned once transformed: it is
+/// replaced by the corresponding [`Polygon`].
 impl Transform for Geometry {
     fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
         match self {
@@ -99,7 +206,12 @@ impl Transform for Geometry {
             Geometry::MultiPoint(geometry) => geometry.transform_coordinates(f),
             Geometry::MultiLineString(geometry) => geometry.transform_coordinates(f),
             Geometry::MultiPolygon(geometry) => geometry.transform_coordinates(f),
-            Geometry::Rect(geometry) => geometry.transform_coordinates(f),
+            Geometry::Rect(geometry) => {
+                let mut polygon = geometry.to_polygon();
+                polygon.transform_coordinates(f)?;
+                *self = Geometry::Polygon(polygon);
+                Ok(())
+            }
             Geometry::Triangle(geometry) => geometry.transform_coordinates(f),
             Geometry::GeometryCollection(geometry) => geometry.transform_coordinates(f),
         }
@@ -127,7 +239,7 @@ mod tests {
     const COORD_0: Coord = Coord { x: X_0, y: Y_0 };
 
     const X_1: f64 = 222650.79679758527;
-    const Y_1: f64 = 110642.22941193319;
+    const Y_1: f64 = 110642.2294119332;
     const COORD_1: Coord = Coord { x: X_1, y: Y_1 };
 
     const EPS: f64 = 1.0e-10;
@@ -200,18 +312,270 @@ mod tests {
         })
     }
 
+    #[test]
+    fn transforms_multi_polygon() {
+        let polygon = Polygon::new(
+            LineString::new(vec![-COORD_0, COORD_0]),
+            vec![LineString::new(vec![COORD_0])],
+        );
+        let mut multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        transform_helper(&mut multi_polygon);
+
+        multi_polygon.into_iter().for_each(|polygon| {
+            let (exterior, interiors) = polygon.into_inner();
+            assert_cord_eq(-COORD_1, exterior.0[0]);
+            assert_cord_eq(COORD_1, exterior.0[1]);
+            interiors.into_iter().for_each(|line_string| {
+                assert_cord_eq(COORD_1, line_string.0[0]);
+            })
+        })
+    }
+
     #[test]
     fn transforms_rect() {
-        let mut rect = Rect::new(-COORD_0, COORD_0);
-        transform_helper(&mut rect);
+        let rect = Rect::new(-COORD_0, COORD_0);
+        let rect = transform_rect(&latlong(), &etmerc(), &rect).unwrap();
         assert_cord_eq(-COORD_1, rect.min());
         assert_cord_eq(COORD_1, rect.max());
     }
 
-    fn transform_helper<T: Transform>(geometry: &mut T) {
+    #[test]
+    fn transforms_rect_bounding_box() {
+        // The corners of the transformed rectangle
+        // are not axis-aligned
+        let rect = Rect::new(
+            Coord {
+                x: 0.,
+                y: 40f64.to_radians(),
+            },
+            Coord {
+                x: 10f64.to_radians(),
+                y: 50f64.to_radians(),
+            },
+        );
+        let transformed = transform_rect(&latlong(), &etmerc(), &rect).unwrap();
+
+        let mut polygon = rect.to_polygon();
+        transform_helper(&mut polygon);
+
+        let (min, max) = (transformed.min(), transformed.max());
+        polygon.exterior().coords().for_each(|c| {
+            assert!(c.x >= min.x - EPS && c.x <= max.x + EPS);
+            assert!(c.y >= min.y - EPS && c.y <= max.y + EPS);
+        });
+
+        // The transformed corners are not axis-aligned: the bounding
+        // box is reached by different corners
+        let mut lower_left = rect.min();
+        let mut lower_right = Coord {
+            x: rect.max().x,
+            y: rect.min().y,
+        };
+        transform_helper(&mut lower_left);
+        transform_helper(&mut lower_right);
+        assert!(lower_right.y > lower_left.y);
+        assert_abs_diff_eq!(lower_left.y, min.y, epsilon = EPS);
+        assert_abs_diff_eq!(lower_right.x, max.x, epsilon = EPS);
+    }
+
+    #[test]
+    fn transforms_rect_projected() {
+        // Projected to projected transformation goes through
+        // geographic coordinates
+        let from = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+        let to = Proj::from_proj_string("+proj=utm +zone=33 +datum=WGS84").unwrap();
+
+        let rect = Rect::new(
+            Coord {
+                x: 300000.,
+                y: 5000000.,
+            },
+            Coord {
+                x: 700000.,
+                y: 5400000.,
+            },
+        );
+        let transformed = transform_rect(&from, &to, &rect).unwrap();
+
+        // Bounding box of the transformed corners
+        let corners = rect.to_polygon().exterior().0.clone();
+        let (mut min, mut max) = (
+            Coord {
+                x: f64::INFINITY,
+                y: f64::INFINITY,
+            },
+            Coord {
+                x: f64::NEG_INFINITY,
+                y: f64::NEG_INFINITY,
+            },
+        );
+        for mut c in corners {
+            transform(&from, &to, &mut c).unwrap();
+            min = Coord {
+                x: min.x.min(c.x),
+                y: min.y.min(c.y),
+            };
+            max = Coord {
+                x: max.x.max(c.x),
+                y: max.y.max(c.y),
+            };
+        }
+        assert_abs_diff_eq!(transformed.min().x, min.x, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(transformed.min().y, min.y, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(transformed.max().x, max.x, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(transformed.max().y, max.y, epsilon = 1.0e-6);
+
+        // A Rect in a Geometry is transformed to a Polygon
+        let mut geometry = Geometry::Rect(rect);
+        transform(&from, &to, &mut geometry).unwrap();
+        match geometry {
+            Geometry::Polygon(polygon) => {
+                let mut expected = rect.to_polygon();
+                transform(&from, &to, &mut expected).unwrap();
+                assert_eq!(polygon, expected);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn transforms_triangle() {
+        let mut triangle = Triangle::new(-COORD_0, COORD_0, -COORD_0);
+        transform_helper(&mut triangle);
+        let [v1, v2, v3] = triangle.to_array();
+        assert_cord_eq(-COORD_1, v1);
+        assert_cord_eq(COORD_1, v2);
+        assert_cord_eq(-COORD_1, v3);
+    }
+
+    #[test]
+    fn transforms_geometry() {
+        let mut polygon = Geometry::Polygon(Polygon::new(
+            LineString::new(vec![-COORD_0, COORD_0]),
+            vec![LineString::new(vec![COORD_0])],
+        ));
+        transform_helper(&mut polygon);
+        match polygon {
+            Geometry::Polygon(polygon) => {
+                assert_cord_eq(-COORD_1, polygon.exterior().0[0]);
+                assert_cord_eq(COORD_1, polygon.exterior().0[1]);
+                assert_cord_eq(COORD_1, polygon.interiors()[0].0[0]);
+            }
+            _ => unreachable!(),
+        }
+
+        let mut multi_point = Geometry::MultiPoint(MultiPoint::new(vec![
+            Point::from(COORD_0),
+            Point::from(-COORD_0),
+        ]));
+        transform_helper(&mut multi_point);
+        match multi_point {
+            Geometry::MultiPoint(multi_point) => {
+                assert_cord_eq(COORD_1, multi_point.0[0].0);
+                assert_cord_eq(-COORD_1, multi_point.0[1].0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn transforms_geometry_collection() {
+        let mut collection = GeometryCollection::new_from(vec![
+            Geometry::Point(Point::from(COORD_0)),
+            Geometry::LineString(LineString::new(vec![-COORD_0, COORD_0])),
+            Geometry::Polygon(Polygon::new(LineString::new(vec![-COORD_0]), vec![])),
+        ]);
+        transform_helper(&mut collection);
+
+        let mut iter = collection.into_iter();
+        match iter.next() {
+            Some(Geometry::Point(point)) => assert_cord_eq(COORD_1, point.0),
+            _ => unreachable!(),
+        }
+        match iter.next() {
+            Some(Geometry::LineString(line_string)) => {
+                assert_cord_eq(-COORD_1, line_string.0[0]);
+                assert_cord_eq(COORD_1, line_string.0[1]);
+            }
+            _ => unreachable!(),
+        }
+        match iter.next() {
+            Some(Geometry::Polygon(polygon)) => assert_cord_eq(-COORD_1, polygon.exterior().0[0]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn transforms_line_string_degrees() {
         let from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
         let to = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
-        transform(&from, &to, geometry).unwrap();
+
+        let coord_0 = Coord {
+            x: X_0.to_degrees(),
+            y: Y_0.to_degrees(),
+        };
+        let mut line_string = LineString::new(vec![-coord_0, coord_0]);
+        from.transform_degrees_in_place(&to, &mut line_string)
+            .unwrap();
+        assert_cord_eq(-COORD_1, line_string.0[0]);
+        assert_cord_eq(COORD_1, line_string.0[1]);
+
+        // And back to degrees
+        to.transform_degrees_in_place(&from, &mut line_string)
+            .unwrap();
+        assert_cord_eq(-coord_0, line_string.0[0]);
+        assert_cord_eq(coord_0, line_string.0[1]);
+    }
+
+    #[test]
+    fn transforms_indexed() {
+        let from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let to = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
+
+        let out_of_range = Coord {
+            x: 0.,
+            y: 100f64.to_radians(),
+        };
+
+        let mut line_string =
+            LineString::new(vec![COORD_0, -COORD_0, COORD_0, out_of_range, COORD_0]);
+        let err = transform_indexed(&from, &to, &mut line_string).unwrap_err();
+        assert_eq!(err.index, Some(3));
+        assert!(matches!(err.error, Error::CoordinateOutOfRange));
+
+        // Coordinates are indexed across the parts of the geometry
+        let polygon = Polygon::new(
+            LineString::new(vec![COORD_0, -COORD_0, COORD_0]),
+            vec![LineString::new(vec![-COORD_0, out_of_range])],
+        );
+        let mut multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        let err = transform_indexed(&from, &to, &mut multi_polygon).unwrap_err();
+        assert_eq!(err.index, Some(4));
+
+        // Errors not related to a coordinate
+        let no_inverse = Proj::from_proj_string("+proj=gins8 +R=6400000").unwrap();
+        let mut point = Point::from(COORD_1);
+        let err = transform_indexed(&no_inverse, &from, &mut point).unwrap_err();
+        assert_eq!(err.index, None);
+        assert!(matches!(err.error, Error::NoInverseProjectionDefined));
+
+        // Success
+        let mut line_string = LineString::new(vec![-COORD_0, COORD_0]);
+        transform_indexed(&from, &to, &mut line_string).unwrap();
+        assert_cord_eq(-COORD_1, line_string.0[0]);
+        assert_cord_eq(COORD_1, line_string.0[1]);
+    }
+
+    fn latlong() -> Proj {
+        Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap()
+    }
+
+    fn etmerc() -> Proj {
+        Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap()
+    }
+
+    fn transform_helper<T: Transform>(geometry: &mut T) {
+        transform(&latlong(), &etmerc(), geometry).unwrap();
     }
 
     fn assert_cord_eq(expected_coord: Coord, actual_coord: Coord) {
@@ -0,0 +1,86 @@
+//!
+//! Areas of use of EPSG definitions
+//!
+//! Bounding boxes are taken from the EPSG registry and expressed
+//! in degrees as `(west, south, east, north)`. When `west` is greater
+//! than `east`, the area crosses the antimeridian.
+//!
+
+type Bbox = (f64, f64, f64, f64);
+
+/// Static areas of use table
+#[rustfmt::skip]
+const AREAS: [(u16, Bbox); 11] = [
+    // WGS 84
+    (4326,  (-180.,   -90.,   180.,   90.)),
+    // ETRS89
+    (4258,  (-16.1,   32.88,  40.18,  84.73)),
+    // NAD83
+    (4269,  (167.65,  14.92,  -40.73, 86.45)),
+    // RGF93 v1
+    (4171,  (-9.86,   41.15,  10.38,  51.56)),
+    // RGF93 v1 / Lambert-93
+    (2154,  (-9.86,   41.15,  10.38,  51.56)),
+    // WGS 84 / Pseudo-Mercator
+    (3857,  (-180.,   -85.06, 180.,   85.06)),
+    // WGS 84 / World Mercator
+    (3395,  (-180.,   -80.,   180.,   84.)),
+    // ETRS89-extended / LAEA Europe
+    (3035,  (-35.58,  24.6,   44.83,  84.73)),
+    // OSGB36 / British National Grid
+    (27700, (-9.01,   49.75,  2.01,   61.01)),
+    // Amersfoort / RD New
+    (28992, (3.2,     50.75,  7.22,   53.7)),
+    // S-JTSK / Krovak East North
+    (5514,  (12.09,   47.73,  22.56,  51.06)),
+];
+
+/// Return the area of use of the EPSG `code`
+pub(crate) fn find_area_of_use(code: u16) -> Option<Bbox> {
+    match code {
+        // WGS 84 / UTM zones
+        32601..=32660 => Some(utm_zone_bbox(code - 32600, false)),
+        32701..=32760 => Some(utm_zone_bbox(code - 32700, true)),
+        _ => AREAS
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, bbox)| *bbox),
+    }
+}
+
+fn utm_zone_bbox(zone: u16, south: bool) -> Bbox {
+    let west = -180. + 6. * (zone - 1) as f64;
+    if south {
+        (west, -80., west + 6., 0.)
+    } else {
+        (west, 0., west + 6., 84.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn areas_find() {
+        assert_eq!(find_area_of_use(2154), Some((-9.86, 41.15, 10.38, 51.56)));
+        assert_eq!(find_area_of_use(32631), Some((0., 0., 6., 84.)));
+        assert_eq!(find_area_of_use(32701), Some((-180., -80., -174., 0.)));
+        assert_eq!(find_area_of_use(32760), Some((174., -80., 180., 0.)));
+        assert_eq!(find_area_of_use(32600), None);
+        assert_eq!(find_area_of_use(0), None);
+    }
+
+    #[test]
+    fn areas_antimeridian() {
+        // NAD83 goes from the Aleutian islands, west of the
+        // antimeridian, to Greenland
+        let (west, _, east, _) = find_area_of_use(4269).unwrap();
+        assert_eq!((west, east), (167.65, -40.73));
+        assert!(west > east);
+        // Other areas do not cross the antimeridian
+        for (code, (west, _, east, _)) in AREAS.iter().filter(|(c, _)| *c != 4269) {
+            assert!(west <= east, "{code}");
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//!
+//! Library version and compiled-in features
+//!
+use crate::projections::NUM_PROJECTIONS;
+
+/// Optional features compiled in the library
+///
+/// ```rust
+/// let caps = proj4rs::capabilities();
+/// println!("proj4rs {} ({} projections)", caps.version, caps.num_projections);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Version of the library
+    pub version: &'static str,
+    /// Number of registered projections, including aliases
+    pub num_projections: usize,
+    /// Compiled-in definitions of common EPSG codes
+    pub epsg: bool,
+    /// Support for [geo-types](https://docs.rs/geo-types/latest/geo_types/)
+    pub geo_types: bool,
+    /// Multi-thread support for nadgrids
+    pub multi_thread: bool,
+    /// Support for gzip compressed grid files
+    pub compressed_grids: bool,
+    /// Logging with the `log` crate
+    pub logging: bool,
+    /// Strict mode for WASM
+    pub wasm_strict: bool,
+}
+
+/// Return the version of the library
+pub fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+/// Return the features compiled in the library
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        version: version(),
+        num_projections: NUM_PROJECTIONS,
+        epsg: cfg!(feature = "epsg"),
+        geo_types: cfg!(feature = "geo-types"),
+        multi_thread: cfg!(feature = "multi-thread"),
+        compressed_grids: cfg!(feature = "flate2"),
+        logging: cfg!(feature = "logging"),
+        wasm_strict: cfg!(feature = "wasm-strict"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projections::find_projection;
+
+    #[test]
+    fn capabilities_features() {
+        let caps = capabilities();
+        assert_eq!(caps.version, env!("CARGO_PKG_VERSION"));
+
+        #[cfg(feature = "epsg")]
+        assert!(caps.epsg);
+        #[cfg(not(feature = "epsg"))]
+        assert!(!caps.epsg);
+
+        #[cfg(feature = "geo-types")]
+        assert!(caps.geo_types);
+        #[cfg(not(feature = "geo-types"))]
+        assert!(!caps.geo_types);
+
+        #[cfg(feature = "multi-thread")]
+        assert!(caps.multi_thread);
+        #[cfg(not(feature = "multi-thread"))]
+        assert!(!caps.multi_thread);
+
+        #[cfg(feature = "flate2")]
+        assert!(caps.compressed_grids);
+        #[cfg(not(feature = "flate2"))]
+        assert!(!caps.compressed_grids);
+
+        #[cfg(feature = "logging")]
+        assert!(caps.logging);
+        #[cfg(not(feature = "logging"))]
+        assert!(!caps.logging);
+
+        #[cfg(feature = "wasm-strict")]
+        assert!(caps.wasm_strict);
+        #[cfg(not(feature = "wasm-strict"))]
+        assert!(!caps.wasm_strict);
+
+        assert!(caps.num_projections > 0);
+        assert!(find_projection("ortho").is_some());
+    }
+}
@@ -5,7 +5,6 @@ use crate::datums::DatumParamDefn;
 use crate::errors::{Error, Result};
 use crate::math::consts::SEC_TO_RAD;
 use crate::nadgrids::NadGrids;
-use crate::parse::FromStr;
 
 /// Datum parameters
 #[derive(Default, Clone, Debug, PartialEq)]
@@ -19,31 +18,20 @@ pub(crate) enum DatumParams {
 }
 
 impl DatumParams {
-    /// Create parameters from a 'towgs84 like string'
-    /// Values are expected to be in second of arcs
-    pub fn from_towgs84_str(towgs84: &str) -> Result<Self> {
-        let mut i = towgs84.split(',');
-
-        // XXX Use js_sys::parsefloat with Wasm
-        // It save about 20ko !
-        fn parse(v: Option<&str>) -> Result<f64> {
-            f64::from_str(v.unwrap_or("").trim()).map_err(|_| Error::InvalidToWGS84String)
-        }
-
-        match towgs84.split(',').count() {
-            3 => Ok(DatumParams::ToWGS84_3(
-                parse(i.next())?,
-                parse(i.next())?,
-                parse(i.next())?,
-            )),
-            7 => Ok(DatumParams::ToWGS84_7(
-                parse(i.next())?,
-                parse(i.next())?,
-                parse(i.next())?,
-                parse(i.next())? * SEC_TO_RAD,
-                parse(i.next())? * SEC_TO_RAD,
-                parse(i.next())? * SEC_TO_RAD,
-                parse(i.next())? / 1_000_000.0 + 1.,
+    /// Create parameters from 'towgs84 like' values
+    /// Rotations are expected to be in second of arcs
+    /// and scale in ppm.
+    pub fn from_towgs84(towgs84: &[f64]) -> Result<Self> {
+        match *towgs84 {
+            [dx, dy, dz] => Ok(DatumParams::ToWGS84_3(dx, dy, dz)),
+            [dx, dy, dz, rx, ry, rz, s] => Ok(DatumParams::ToWGS84_7(
+                dx,
+                dy,
+                dz,
+                rx * SEC_TO_RAD,
+                ry * SEC_TO_RAD,
+                rz * SEC_TO_RAD,
+                s / 1_000_000.0 + 1.,
             )),
             _ => Err(Error::InvalidToWGS84String),
         }
@@ -114,6 +114,33 @@ impl Datum {
         }
     }
 
+    /// Convert geodetic coordinates to the geocentric pivot
+    ///
+    /// This is the WGS84 geocentric hub, or geocentric coordinates
+    /// relative to the datum ellipsoid if the datum is unknown.
+    pub fn to_geocentric_pivot(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.no_datum() {
+            geodetic_to_geocentric(x, y, z, self.a, self.es)
+        } else {
+            self.towgs84(x, y, z)
+        }
+    }
+
+    /// Approximate accuracy, in meters, of the conversion to WGS84
+    ///
+    /// Return `None` if the datum is unknown.
+    pub fn accuracy(&self) -> Option<f64> {
+        match &self.params {
+            ToWGS84_0 => Some(0.),
+            ToWGS84_3(..) => Some(5.),
+            ToWGS84_7(..) => Some(1.),
+            // '@null' grids do not shift coordinates
+            NadGrids(grids) if grids.is_empty() => Some(0.),
+            NadGrids(_) => Some(0.05),
+            NoDatum => None,
+        }
+    }
+
     #[inline]
     pub fn no_datum(&self) -> bool {
         self.params.no_datum()
@@ -70,6 +70,7 @@ enum Shape {
     SP_b(f64),
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub struct Ellipsoid {
     // The linear parameters
@@ -176,6 +177,11 @@ impl Ellipsoid {
         Self::calc_ellipsoid_params(a, sp).and_then(|ellps| ellps.spherification(params))
     }
 
+    /// Create from a given semi major axis and eccentricity squared
+    pub(crate) fn try_from_es(a: f64, es: f64) -> Result<Self> {
+        Self::calc_ellipsoid_params(a, SP_es(es))
+    }
+
     fn find_shape_parameter(params: &ParamList) -> Option<Result<Shape>> {
         // Shape parameters tokens in order of precedence
         const SHAPE_TOKENS: &[&str] = &[TOK_rf, TOK_f, TOK_es, TOK_e, TOK_b];
@@ -0,0 +1,115 @@
+//!
+//! Compiled-in definitions of common EPSG codes
+//!
+//! This is a small subset of the EPSG registry: use the
+//! `crs-definitions` feature for the whole registry.
+//!
+//! Definitions are taken from [EPSG.io](https://epsg.io/), or from
+//! the [named definitions](crate::initdefs) when one exists.
+//!
+use crate::initdefs::find_init_defn;
+use std::borrow::Cow;
+
+/// Static EPSG definitions table
+#[rustfmt::skip]
+const EPSG_DEFS: [(u32, &str); 7] = [
+    // WGS 84
+    (4326,  "+proj=longlat +datum=WGS84 +no_defs"),
+    // ETRS89
+    (4258,  "+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +no_defs"),
+    // NAD83
+    (4269,  "+proj=longlat +datum=NAD83 +no_defs"),
+    // RGF93 v1
+    (4171,  "+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +no_defs"),
+    // WGS 84 / Pseudo-Mercator
+    (3857, concat!(
+        "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1",
+        " +units=m +nadgrids=@null +wktext +no_defs",
+    )),
+    // WGS 84 / World Mercator
+    (3395,  "+proj=merc +lon_0=0 +k=1 +x_0=0 +y_0=0 +datum=WGS84 +units=m +no_defs"),
+    // ETRS89-extended / LAEA Europe
+    (3035, concat!(
+        "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000",
+        " +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs",
+    )),
+];
+
+/// EPSG codes of the named definitions
+const EPSG_INITDEFS: [(u32, &str); 3] = [
+    // RGF93 v1 / Lambert-93
+    (2154, "lambert93"),
+    // OSGB36 / British National Grid
+    (27700, "bng"),
+    // Amersfoort / RD New
+    (28992, "rdnew"),
+];
+
+/// Return the proj string definition of the EPSG `code`
+pub(crate) fn find_epsg_defn(code: u32) -> Option<Cow<'static, str>> {
+    match code {
+        // WGS 84 / UTM zones
+        32601..=32660 => Some(Cow::Owned(format!(
+            "+proj=utm +zone={} +datum=WGS84 +units=m +no_defs",
+            code - 32600
+        ))),
+        32701..=32760 => Some(Cow::Owned(format!(
+            "+proj=utm +zone={} +south +datum=WGS84 +units=m +no_defs",
+            code - 32700
+        ))),
+        _ => EPSG_DEFS
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, defn)| Cow::Borrowed(*defn))
+            .or_else(|| {
+                EPSG_INITDEFS
+                    .iter()
+                    .find(|(c, _)| *c == code)
+                    .and_then(|(_, id)| find_init_defn(id))
+                    .map(|d| Cow::Borrowed(d.defn))
+            }),
+    }
+}
+
+/// Return the EPSG codes with a compiled-in definition
+pub(crate) fn epsg_codes() -> impl Iterator<Item = u32> {
+    EPSG_DEFS
+        .iter()
+        .chain(EPSG_INITDEFS.iter())
+        .map(|(code, _)| *code)
+        .chain(32601..=32660)
+        .chain(32701..=32760)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epsg_find() {
+        assert_eq!(
+            find_epsg_defn(4326).as_deref(),
+            Some("+proj=longlat +datum=WGS84 +no_defs")
+        );
+        assert_eq!(
+            find_epsg_defn(32631).as_deref(),
+            Some("+proj=utm +zone=31 +datum=WGS84 +units=m +no_defs")
+        );
+        assert_eq!(
+            find_epsg_defn(32760).as_deref(),
+            Some("+proj=utm +zone=60 +south +datum=WGS84 +units=m +no_defs")
+        );
+        assert_eq!(
+            find_epsg_defn(2154).as_deref(),
+            find_init_defn("lambert93").map(|d| d.defn)
+        );
+        assert_eq!(find_epsg_defn(32600), None);
+        assert_eq!(find_epsg_defn(0), None);
+    }
+
+    #[test]
+    fn epsg_codes_defined() {
+        assert_eq!(epsg_codes().count(), 130);
+        assert!(epsg_codes().all(|code| find_epsg_defn(code).is_some()));
+    }
+}
@@ -18,6 +18,8 @@ pub enum Error {
     InvalidEllipsoid,
     #[error("{0}")]
     InvalidParameterValue(&'static str),
+    #[error("Invalid number of values ({1}) for parameter {0}")]
+    InvalidParameterCount(String, usize),
     #[error("Invalid coordinate dimension")]
     InvalidCoordinateDimension,
     #[error("Latitude out of range")]
@@ -46,6 +48,8 @@ pub enum Error {
     InvalidNumberOfCoordinates,
     #[error("Projection not found")]
     ProjectionNotFound,
+    #[error("Init definition not found")]
+    InitDefnNotFound,
     #[error("No forward projection defined for dest projection")]
     NoForwardProjectionDefined,
     #[error("No inverse projection defined for src projection")]
@@ -56,6 +60,8 @@ pub enum Error {
     ToleranceConditionError,
     #[error("Non convergence of phi2 calculation")]
     NonInvPhi2Convergence,
+    #[error("Non convergence of phi1 calculation")]
+    NonInvPhi1Convergence,
     #[error("Failed no compute forward projection")]
     ForwardProjectionFailure,
     #[error("Failed no compute inverse projection")]
@@ -66,6 +72,8 @@ pub enum Error {
     EllipsoidRequired,
     #[error("Coordinate transform outside projection domain")]
     CoordTransOutsideProjectionDomain,
+    #[error("Point beyond the apex of the cone")]
+    PointBeyondConeApex,
     #[error("No convergence for inv. meridian distance")]
     InvMeridDistConvError,
     #[error("JS parse error")]
@@ -80,8 +88,104 @@ pub enum Error {
     GridFileNotFound(String),
     #[error("Unknown grid format")]
     UnknownGridFormat,
+    #[error("Compressed grid file requires the flate2 feature")]
+    CompressedGridNotSupported,
     #[error("Numerical argument too  large")]
     ArgumentTooLarge,
+    #[error("Height is required for this transformation")]
+    HeightRequired,
+}
+
+// Define the error codes: the match in `Error::code` is
+// exhaustive and the codes are checked for uniqueness in tests.
+macro_rules! error_codes {
+    ($($variant:ident $(($($args:tt)*))? => $code:literal,)+) => {
+        impl Error {
+            /// Return a stable numeric code for the error
+            ///
+            /// Codes are never reused nor renumbered: they may be used to map
+            /// errors to localized messages.
+            ///
+            /// ```rust
+            /// use proj4rs::errors::Error;
+            ///
+            /// assert_eq!(Error::ToleranceConditionError.code(), 27);
+            /// ```
+            pub fn code(&self) -> u32 {
+                match self {
+                    $(Self::$variant $(($($args)*))? => $code,)+
+                }
+            }
+        }
+
+        #[cfg(test)]
+        const ERROR_CODES: &[u32] = &[$($code),+];
+    };
+}
+
+error_codes! {
+    InputStringError(..) => 1,
+    NoValueParameter => 2,
+    ParameterValueError => 3,
+    MissingProjectionError => 4,
+    InvalidDatum => 5,
+    InvalidEllipsoid => 6,
+    InvalidParameterValue(..) => 7,
+    InvalidParameterCount(..) => 8,
+    InvalidCoordinateDimension => 9,
+    LatitudeOutOfRange => 10,
+    NadGridNotAvailable => 11,
+    NadGridParentNotFound => 12,
+    InverseGridShiftConvError => 13,
+    PointOutsideNadShiftArea => 14,
+    InvalidToWGS84String => 15,
+    InvalidAxis => 16,
+    UnrecognizedFormat => 17,
+    LatOrLongExceedLimit => 18,
+    NanCoordinateValue => 19,
+    CoordinateOutOfRange => 20,
+    InvalidNumberOfCoordinates => 21,
+    ProjectionNotFound => 22,
+    InitDefnNotFound => 23,
+    NoForwardProjectionDefined => 24,
+    NoInverseProjectionDefined => 25,
+    ProjErrConicLatEqual => 26,
+    ToleranceConditionError => 27,
+    NonInvPhi2Convergence => 28,
+    NonInvPhi1Convergence => 29,
+    ForwardProjectionFailure => 30,
+    InverseProjectionFailure => 31,
+    InvalidUtmZone => 32,
+    EllipsoidRequired => 33,
+    CoordTransOutsideProjectionDomain => 34,
+    PointBeyondConeApex => 35,
+    InvMeridDistConvError => 36,
+    JsParseError => 37,
+    InvalidNtv2GridFormat(..) => 38,
+    IoError(..) => 39,
+    Utf8Error(..) => 40,
+    GridFileNotFound(..) => 41,
+    UnknownGridFormat => 42,
+    CompressedGridNotSupported => 43,
+    ArgumentTooLarge => 44,
+    HeightRequired => 45,
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn error_codes_unique() {
+        let codes: HashSet<u32> = ERROR_CODES.iter().copied().collect();
+        assert_eq!(codes.len(), ERROR_CODES.len());
+
+        // Codes are stable
+        assert_eq!(Error::InputStringError("").code(), 1);
+        assert_eq!(Error::ToleranceConditionError.code(), 27);
+        assert_eq!(Error::HeightRequired.code(), 45);
+    }
+}
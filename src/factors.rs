@@ -0,0 +1,135 @@
+//!
+//! Projection scale factors and distortions
+//!
+//! ref: <https://proj.org/development/reference/functions.html#c.proj_factors>
+//!
+//! Factors are computed from the partial derivatives of the projection
+//! by numerical differentiation, except for projections that provide
+//! analytic formulas (i.e. the exact transverse mercator).
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::FRAC_PI_2;
+use crate::projections::ProjDelegate;
+
+/// Differentiation step, in radians
+const DELTA: f64 = 1.0e-5;
+
+/// Scale factors and distortions at a point
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Factors {
+    /// Scale factor along the meridian (h)
+    pub meridional_scale: f64,
+    /// Scale factor along the parallel (k)
+    pub parallel_scale: f64,
+    /// Areal scale factor (s)
+    pub areal_scale: f64,
+    /// Maximum angular distortion (ω), in radians
+    pub angular_distortion: f64,
+    /// Angle between the grid north and the true north, in radians.
+    /// Positive east of the central meridian in the northern hemisphere.
+    pub meridian_convergence: f64,
+    /// Semi-major axis of the Tissot indicatrix (a')
+    pub tissot_semimajor: f64,
+    /// Semi-minor axis of the Tissot indicatrix (b')
+    pub tissot_semiminor: f64,
+}
+
+impl Factors {
+    /// Factors of a conformal projection with point scale `k`
+    pub(crate) fn conformal(k: f64, meridian_convergence: f64) -> Self {
+        Self {
+            meridional_scale: k,
+            parallel_scale: k,
+            areal_scale: k * k,
+            angular_distortion: 0.,
+            meridian_convergence,
+            tissot_semimajor: k,
+            tissot_semiminor: k,
+        }
+    }
+}
+
+/// Compute the factors by numerical differentiation
+///
+/// `lam` and `phi` are the geographic coordinates, relative to the
+/// central meridian, of the point on the ellipsoid `ellps` used
+/// by the projection.
+pub(crate) fn numeric(
+    proj: &ProjDelegate,
+    ellps: &Ellipsoid,
+    lam: f64,
+    phi: f64,
+) -> Result<Factors> {
+    if phi.abs() > FRAC_PI_2 - DELTA {
+        return Err(Error::LatitudeOutOfRange);
+    }
+
+    let fwd = |lam, phi| proj.forward(lam, phi, 0.).map(|(x, y, _)| (x, y));
+
+    // Central differences
+    let (x_e, y_e) = fwd(lam + DELTA, phi)?;
+    let (x_w, y_w) = fwd(lam - DELTA, phi)?;
+    let (x_n, y_n) = fwd(lam, phi + DELTA)?;
+    let (x_s, y_s) = fwd(lam, phi - DELTA)?;
+
+    let x_l = (x_e - x_w) / (2. * DELTA);
+    let y_l = (y_e - y_w) / (2. * DELTA);
+    let x_p = (x_n - x_s) / (2. * DELTA);
+    let y_p = (y_n - y_s) / (2. * DELTA);
+
+    // Radii of curvature of the meridian and of the parallel
+    let (sinphi, cosphi) = phi.sin_cos();
+    let w = 1. - ellps.es * sinphi * sinphi;
+    let m = ellps.one_es / (w * w.sqrt());
+    let n = cosphi / w.sqrt();
+
+    let h = x_p.hypot(y_p) / m;
+    let k = x_l.hypot(y_l) / n;
+    let s = (y_p * x_l - x_p * y_l) / (m * n);
+
+    // Tissot indicatrix
+    let t = h * h + k * k;
+    let a_plus_b = (t + 2. * s).max(0.).sqrt();
+    let a_minus_b = (t - 2. * s).max(0.).sqrt();
+
+    Ok(Factors {
+        meridional_scale: h,
+        parallel_scale: k,
+        areal_scale: s,
+        angular_distortion: 2. * (a_minus_b / a_plus_b).asin(),
+        meridian_convergence: -x_p.atan2(y_p),
+        tissot_semimajor: 0.5 * (a_plus_b + a_minus_b),
+        tissot_semiminor: 0.5 * (a_plus_b - a_minus_b),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proj::Proj;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn factors_numeric_merc() {
+        // Mercator is conformal with k = sec(phi) on the sphere
+        let p = Proj::from_proj_string("+proj=merc +R=6400000").unwrap();
+        let phi = 60f64.to_radians();
+
+        let f = numeric(p.projection(), &p.data().ellps, 0.3, phi).unwrap();
+        assert_abs_diff_eq!(f.meridional_scale, 2., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(f.parallel_scale, 2., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(f.areal_scale, 4., epsilon = 1.0e-8);
+        assert_abs_diff_eq!(f.angular_distortion, 0., epsilon = 1.0e-6);
+        assert_abs_diff_eq!(f.meridian_convergence, 0., epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn factors_numeric_pole() {
+        let p = Proj::from_proj_string("+proj=merc +R=6400000").unwrap();
+        assert!(matches!(
+            numeric(p.projection(), &p.data().ellps, 0., FRAC_PI_2),
+            Err(Error::LatitudeOutOfRange)
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+//!
+//! Bundled named definitions
+//!
+//! Named definitions expand like proj `+init=` files: `+init=bng` is
+//! replaced by the definition of the British National Grid.
+//! Parameters given explicitly take precedence over the ones
+//! of the definition.
+//!
+//! Names are case insensitive.
+//!
+//! ```rust
+//! use proj4rs::Proj;
+//!
+//! let bng = Proj::from_proj_string("+init=bng").unwrap();
+//! assert_eq!(bng.projname(), "tmerc");
+//!
+//! let rd = Proj::from_user_string("rdnew").unwrap();
+//! assert_eq!(rd.projname(), "sterea");
+//! ```
+//!
+
+pub struct InitDefn {
+    pub id: &'static str,
+    pub defn: &'static str,
+    //pub comment: &'static str,
+}
+
+macro_rules! init {
+    ($id:expr, $defn:expr, $c:expr $(,)?) => {
+        InitDefn {
+            id: $id,
+            defn: $defn,
+            //comment: $c,
+        }
+    };
+}
+
+macro_rules! mga2020 {
+    ($zone:literal) => {
+        init!(
+            concat!("mga2020_", $zone),
+            concat!(
+                "+proj=utm +zone=",
+                $zone,
+                " +south +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m"
+            ),
+            "GDA2020 / MGA zone",
+        )
+    };
+}
+
+/// Static named definitions table
+#[rustfmt::skip]
+const INITDEFS: [InitDefn; 17] = [
+    init!(
+        "bng",
+        concat!(
+            "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000",
+            " +ellps=airy +towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489 +units=m",
+        ),
+        "OSGB36 / British National Grid (EPSG:27700)",
+    ),
+    init!(
+        "lambert93",
+        concat!(
+            "+proj=lcc +lat_0=46.5 +lon_0=3 +lat_1=49 +lat_2=44 +x_0=700000 +y_0=6600000",
+            " +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m",
+        ),
+        "RGF93 v1 / Lambert-93 (EPSG:2154)",
+    ),
+    init!(
+        "rdnew",
+        concat!(
+            "+proj=sterea +lat_0=52.1561605555556 +lon_0=5.38763888888889 +k=0.9999079",
+            " +x_0=155000 +y_0=463000 +ellps=bessel",
+            " +towgs84=565.4171,50.3319,465.5524,1.9342,-1.6677,9.1019,4.0725 +units=m",
+        ),
+        "Amersfoort / RD New (EPSG:28992)",
+    ),
+    // GDA2020 / MGA zones 46 to 59 (EPSG:7846 to EPSG:7859)
+    mga2020!(46), mga2020!(47), mga2020!(48), mga2020!(49), mga2020!(50),
+    mga2020!(51), mga2020!(52), mga2020!(53), mga2020!(54), mga2020!(55),
+    mga2020!(56), mga2020!(57), mga2020!(58), mga2020!(59),
+];
+
+/// Return the named definition
+pub fn find_init_defn(name: &str) -> Option<&InitDefn> {
+    INITDEFS.iter().find(|d| d.id.eq_ignore_ascii_case(name))
+}
+
+/// Iterate over the bundled named definitions
+pub fn init_defns() -> impl Iterator<Item = &'static InitDefn> {
+    INITDEFS.iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proj::Proj;
+    use crate::tests::utils::test_proj_forward;
+
+    #[test]
+    fn initdefs_parse() {
+        for defn in init_defns() {
+            Proj::from_proj_string(defn.defn).unwrap();
+        }
+        assert!(find_init_defn("Lambert93").is_some());
+        assert!(find_init_defn("mga2020_60").is_none());
+    }
+
+    #[test]
+    fn initdefs_bng() {
+        // Ordnance Survey example (Caister water tower)
+        let p = Proj::from_proj_string("+init=bng").unwrap();
+        let inputs = [(
+            (1.7179215833333334, 52.65757030555555, 0.),
+            (651409.903, 313177.270, 0.),
+        )];
+        test_proj_forward(&p, &inputs, 1.0e-2);
+    }
+
+    #[test]
+    fn initdefs_lambert93() {
+        let p = Proj::from_proj_string("+init=lambert93").unwrap();
+        let inputs = [
+            ((3., 46.5, 0.), (700000., 6600000., 0.)),
+            (
+                (2.3522, 48.8566, 0.),
+                (652469.022709136, 6862035.259420077, 0.),
+            ),
+            (
+                (5.3698, 43.2965, 0.),
+                (892390.2215663685, 6247035.256802095, 0.),
+            ),
+        ];
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    fn initdefs_rdnew() {
+        // EPSG guidance note 7-2 example
+        let p = Proj::from_proj_string("+init=rdnew").unwrap();
+        let inputs = [
+            (
+                (5.38763888888889, 52.1561605555556, 0.),
+                (155000., 463000., 0.),
+            ),
+            ((6., 53., 0.), (196105.283, 557057.739, 0.)),
+        ];
+        test_proj_forward(&p, &inputs, 1.0e-3);
+    }
+
+    #[test]
+    fn initdefs_mga2020() {
+        let p = Proj::from_proj_string("+init=mga2020_55").unwrap();
+        let inputs = [(
+            (144.9631, -37.8136, 0.),
+            (320704.4463188846, 5812911.699632986, 0.),
+        )];
+        test_proj_forward(&p, &inputs, 1.0e-6);
+
+        let p = Proj::from_proj_string("+init=MGA2020_56").unwrap();
+        let inputs = [(
+            (151.2093, -33.8688, 0.),
+            (334368.63364725077, 6250948.345488557, 0.),
+        )];
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    fn initdefs_user_string() {
+        let p = Proj::from_user_string("RDNew").unwrap();
+        assert_eq!(p.projname(), "sterea");
+        assert!(matches!(
+            Proj::from_proj_string("+init=foo"),
+            Err(crate::errors::Error::InitDefnNotFound)
+        ));
+    }
+
+    #[test]
+    fn initdefs_override() {
+        // Explicit parameters take precedence
+        let p = Proj::from_proj_string("+init=lambert93 +x_0=0 +y_0=0").unwrap();
+        let inputs = [((3., 46.5, 0.), (0., 0., 0.))];
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+}
@@ -57,13 +57,14 @@
 //!
 //! ## Optional features
 //!
+//! * **epsg**: compiled-in definitions of common EPSG codes, see [`Proj::from_epsg`].
 //! * **geo-types**: [geo-types](<https://docs.rs/geo-types/latest/geo_types/>) support
 //! * **logging**: support for logging with [log](https://docs.rs/log/latest/log/) crate.
 //!   If activated for WASM, it will use the [console-log](https://docs.rs/console_log/latest/console_log/)
 //!   adaptor.
 //! * **wasm-strict**: used with WASM; Transformation operation will return exception as soon as we
-//! have invalid coordinates or that the reprojection failed.
-//! The default is to use a relaxed-mode that return NaN in case of projection failure: this is expected
+//!   have invalid coordinates or that the reprojection failed.
+//!   The default is to use a relaxed-mode that return NaN in case of projection failure: this is expected
 //!   mostly from js app (at least with OpenLayer).
 //! * **multi-thread**: Support for multi-thread with NAD Grid processing, this is activated by
 //!   default and disabled when compiling for WASM.
@@ -85,11 +86,15 @@
 //! Currently, only Ntv2 multi grids are supported for native build and WASM.
 //!
 
+#[cfg(any(feature = "epsg", feature = "crs-definitions"))]
+mod areas;
 mod datum_params;
 mod datum_transform;
 mod datums;
 mod ellipsoids;
 mod ellps;
+#[cfg(feature = "epsg")]
+mod epsg;
 mod geocent;
 mod math;
 mod parameters;
@@ -99,13 +104,18 @@ mod projstring;
 mod units;
 
 pub mod adaptors;
+pub mod capabilities;
 pub mod errors;
+pub mod factors;
+pub mod initdefs;
 pub mod nadgrids;
 pub mod proj;
 pub mod projections;
 pub mod transform;
 
 // Reexport
+pub use capabilities::{capabilities, version, Capabilities};
+pub use math::normalize_longitude;
 pub use proj::Proj;
 
 // Include wasm entry point for wasm32-unknown-unknown
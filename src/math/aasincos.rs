@@ -20,6 +20,20 @@ pub(crate) fn aasin(v: f64) -> Result<f64> {
     }
 }
 
+/// asin clamped to ±π/2 when `|v|` is within `tol` of 1
+pub(crate) fn aasin_tol(v: f64, tol: f64) -> Result<f64> {
+    let av = v.abs();
+    if av >= 1. {
+        if av > tol {
+            Err(Error::CoordTransOutsideProjectionDomain)
+        } else {
+            Ok(FRAC_PI_2.copysign(v))
+        }
+    } else {
+        Ok(v.asin())
+    }
+}
+
 pub(crate) fn aacos(v: f64) -> Result<f64> {
     let av = v.abs();
     if av >= 1. {
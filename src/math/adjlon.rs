@@ -15,3 +15,36 @@ pub(crate) fn adjlon(mut lon: f64) -> f64 {
     }
     lon
 }
+
+/// Reduce the longitude `lam`, in radians, to the range [-π, π]
+///
+/// This is the reduction applied to longitudes before
+/// projection.
+///
+/// ```rust
+/// use proj4rs::normalize_longitude;
+///
+/// let lam = normalize_longitude(270f64.to_radians());
+/// assert!((lam - (-90f64).to_radians()).abs() < 1.0e-12);
+/// ```
+pub fn normalize_longitude(lam: f64) -> f64 {
+    adjlon(lam)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_longitude_reduce() {
+        let lam = normalize_longitude(270f64.to_radians());
+        assert!((lam - (-90f64).to_radians()).abs() < 1.0e-12);
+
+        let lam = normalize_longitude(-540f64.to_radians());
+        assert!((lam.abs() - PI).abs() < 1.0e-12);
+
+        // Values in range are left untouched
+        assert_eq!(normalize_longitude(1.5), 1.5);
+        assert_eq!(normalize_longitude(-PI), -PI);
+    }
+}
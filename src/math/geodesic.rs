@@ -0,0 +1,225 @@
+//! Geodesic distance on the ellipsoid
+//!
+//! Solve the direct and inverse geodesic problems with the Vincenty formulae.
+//!
+//! ref: T. Vincenty, "Direct and Inverse Solutions of Geodesics on the
+//! Ellipsoid with application of nested equations", Survey Review XXIII, 1975
+//!
+//! Accuracy is better than 1 mm on the Earth ellipsoid, iterations may not
+//! converge for nearly antipodal points.
+//!
+use crate::errors::{Error, Result};
+
+const MAX_ITER: usize = 200;
+const TOL: f64 = 1.0e-12;
+
+/// Return the geodesic distance between (lam1, phi1) and (lam2, phi2)
+/// on the ellipsoid of semi-major axis 1 and flattening f
+pub(crate) fn geodesic_distance(f: f64, lam1: f64, phi1: f64, lam2: f64, phi2: f64) -> Result<f64> {
+    geodesic_inverse(f, lam1, phi1, lam2, phi2).map(|(s12, _)| s12)
+}
+
+/// Solve the inverse problem: return the distance and the forward
+/// azimuth (clockwise from north) at (lam1, phi1) of the geodesic
+/// to (lam2, phi2)
+pub(crate) fn geodesic_inverse(
+    f: f64,
+    lam1: f64,
+    phi1: f64,
+    lam2: f64,
+    phi2: f64,
+) -> Result<(f64, f64)> {
+    let b = 1. - f;
+    let l = lam2 - lam1;
+
+    let u1 = (b * phi1.tan()).atan();
+    let u2 = (b * phi2.tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    let mut i = MAX_ITER;
+    let (sin_sigma, cos_sigma, sigma, cos_sq_alpha, cos_2sigma_m, sin_lambda, cos_lambda) = loop {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = (cos_u2 * sin_lambda).hypot(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        if sin_sigma == 0. {
+            // Coincident points
+            return Ok((0., 0.));
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1. - sin_alpha * sin_alpha;
+        // Equatorial line
+        let cos_2sigma_m = if cos_sq_alpha != 0. {
+            cos_sigma - 2. * sin_u1 * sin_u2 / cos_sq_alpha
+        } else {
+            0.
+        };
+        let c = f / 16. * cos_sq_alpha * (4. + f * (4. - 3. * cos_sq_alpha));
+        let prev = lambda;
+        lambda = l
+            + (1. - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma * (-1. + 2. * cos_2sigma_m * cos_2sigma_m)));
+        if (lambda - prev).abs() < TOL {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            break (
+                sin_sigma,
+                cos_sigma,
+                sigma,
+                cos_sq_alpha,
+                cos_2sigma_m,
+                sin_lambda,
+                cos_lambda,
+            );
+        }
+        i -= 1;
+        if i == 0 {
+            return Err(Error::ToleranceConditionError);
+        }
+    };
+
+    let u_sq = cos_sq_alpha * (1. - b * b) / (b * b);
+    let a = 1. + u_sq / 16384. * (4096. + u_sq * (-768. + u_sq * (320. - 175. * u_sq)));
+    let bb = u_sq / 1024. * (256. + u_sq * (-128. + u_sq * (74. - 47. * u_sq)));
+    let c2sm2 = cos_2sigma_m * cos_2sigma_m;
+    let delta_sigma = bb
+        * sin_sigma
+        * (cos_2sigma_m
+            + bb / 4.
+                * (cos_sigma * (-1. + 2. * c2sm2)
+                    - bb / 6.
+                        * cos_2sigma_m
+                        * (-3. + 4. * sin_sigma * sin_sigma)
+                        * (-3. + 4. * c2sm2)));
+
+    let azi1 = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+
+    Ok((b * a * (sigma - delta_sigma), azi1))
+}
+
+/// Solve the direct problem: return the end point (lam2, phi2) of the
+/// geodesic of length s12 starting at (lam1, phi1) with azimuth azi1
+pub(crate) fn geodesic_direct(
+    f: f64,
+    lam1: f64,
+    phi1: f64,
+    azi1: f64,
+    s12: f64,
+) -> Result<(f64, f64)> {
+    let b = 1. - f;
+
+    let (sin_alpha1, cos_alpha1) = azi1.sin_cos();
+    let u1 = (b * phi1.tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+    let sin_alpha = cos_u1 * sin_alpha1;
+    let cos_sq_alpha = 1. - sin_alpha * sin_alpha;
+
+    let u_sq = cos_sq_alpha * (1. - b * b) / (b * b);
+    let a = 1. + u_sq / 16384. * (4096. + u_sq * (-768. + u_sq * (320. - 175. * u_sq)));
+    let bb = u_sq / 1024. * (256. + u_sq * (-128. + u_sq * (74. - 47. * u_sq)));
+
+    let s0 = s12 / (b * a);
+    let mut sigma = s0;
+    let mut i = MAX_ITER;
+    let (sin_sigma, cos_sigma, cos_2sigma_m) = loop {
+        let cos_2sigma_m = (2. * sigma1 + sigma).cos();
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let c2sm2 = cos_2sigma_m * cos_2sigma_m;
+        let delta_sigma = bb
+            * sin_sigma
+            * (cos_2sigma_m
+                + bb / 4.
+                    * (cos_sigma * (-1. + 2. * c2sm2)
+                        - bb / 6.
+                            * cos_2sigma_m
+                            * (-3. + 4. * sin_sigma * sin_sigma)
+                            * (-3. + 4. * c2sm2)));
+        let prev = sigma;
+        sigma = s0 + delta_sigma;
+        if (sigma - prev).abs() < TOL {
+            let (sin_sigma, cos_sigma) = sigma.sin_cos();
+            break (sin_sigma, cos_sigma, (2. * sigma1 + sigma).cos());
+        }
+        i -= 1;
+        if i == 0 {
+            return Err(Error::ToleranceConditionError);
+        }
+    };
+
+    let tmp = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+    let phi2 =
+        (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1).atan2(b * sin_alpha.hypot(tmp));
+    let lambda =
+        (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+    let c = f / 16. * cos_sq_alpha * (4. + f * (4. - 3. * cos_sq_alpha));
+    let l = lambda
+        - (1. - c)
+            * f
+            * sin_alpha
+            * (sigma
+                + c * sin_sigma
+                    * (cos_2sigma_m + c * cos_sigma * (-1. + 2. * cos_2sigma_m * cos_2sigma_m)));
+
+    Ok((lam1 + l, phi2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proj::Proj;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn geodesic_distance_wgs84() {
+        let p = Proj::from_proj_string("+proj=longlat +ellps=WGS84").unwrap();
+        let ellps = &p.data().ellps;
+        let d = |lon1: f64, lat1: f64, lon2: f64, lat2: f64| {
+            ellps.a
+                * geodesic_distance(
+                    ellps.f,
+                    lon1.to_radians(),
+                    lat1.to_radians(),
+                    lon2.to_radians(),
+                    lat2.to_radians(),
+                )
+                .unwrap()
+        };
+
+        // JFK to LHR (GeographicLib)
+        assert_abs_diff_eq!(d(-73.8, 40.6, -0.5, 51.6), 5551759.400319, epsilon = 1.0e-3);
+        // Along the equator
+        assert_abs_diff_eq!(
+            d(0., 0., 90., 0.),
+            ellps.a * std::f64::consts::FRAC_PI_2,
+            epsilon = 1.0e-5
+        );
+        assert_eq!(d(10., 20., 10., 20.), 0.);
+    }
+
+    #[test]
+    fn geodesic_direct_round_trip() {
+        let p = Proj::from_proj_string("+proj=longlat +ellps=WGS84").unwrap();
+        let f = p.data().ellps.f;
+        let (lam1, phi1) = (-73.8f64.to_radians(), 40.6f64.to_radians());
+        let (lam2, phi2) = (-0.5f64.to_radians(), 51.6f64.to_radians());
+
+        let (s12, azi1) = geodesic_inverse(f, lam1, phi1, lam2, phi2).unwrap();
+        let (lam, phi) = geodesic_direct(f, lam1, phi1, azi1, s12).unwrap();
+        assert_abs_diff_eq!(lam, lam2, epsilon = 1.0e-11);
+        assert_abs_diff_eq!(phi, phi2, epsilon = 1.0e-11);
+
+        // Due north along the meridian
+        let (s12, azi1) = geodesic_inverse(f, 0., 0., 0., 1.).unwrap();
+        assert_abs_diff_eq!(azi1, 0., epsilon = 1.0e-12);
+        let (lam, phi) = geodesic_direct(f, 0., 0., 0., s12).unwrap();
+        assert_abs_diff_eq!(lam, 0., epsilon = 1.0e-12);
+        assert_abs_diff_eq!(phi, 1., epsilon = 1.0e-11);
+    }
+}
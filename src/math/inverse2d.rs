@@ -0,0 +1,84 @@
+//! Generic inverse of a 2D projection
+//!
+//! Newton-Raphson iterations on the forward projection, with a
+//! jacobian computed by finite differences.
+//!
+//! Used by projections with no closed-form inverse.
+//!
+use super::consts::{FRAC_PI_2, PI};
+use crate::errors::{Error, Result};
+
+const MAX_ITER: usize = 15;
+const DELTA: f64 = 1.0e-6;
+const MAX_STEP: f64 = 0.3;
+
+/// Find `(lam, phi)` such that `fwd(lam, phi) == (x, y)`
+/// within `tol`, starting from `(lam0, phi0)`.
+pub(crate) fn inverse_2d<F>(
+    fwd: F,
+    x: f64,
+    y: f64,
+    (lam0, phi0): (f64, f64),
+    tol: f64,
+) -> Result<(f64, f64)>
+where
+    F: Fn(f64, f64) -> Result<(f64, f64)>,
+{
+    let mut lam = lam0.clamp(-PI, PI);
+    let mut phi = phi0.clamp(-FRAC_PI_2, FRAC_PI_2);
+    let (mut d_lam_x, mut d_lam_y, mut d_phi_x, mut d_phi_y) = (0., 0., 0., 0.);
+
+    for i in 0..MAX_ITER {
+        let (xa, ya) = fwd(lam, phi)?;
+        let dx = xa - x;
+        let dy = ya - y;
+        if dx.abs() < tol && dy.abs() < tol {
+            return Ok((lam, phi));
+        }
+
+        if i == 0 || dx.abs() > DELTA || dy.abs() > DELTA {
+            // Update the jacobian, stepping toward the origin
+            let h_lam = if lam > 0. { -DELTA } else { DELTA };
+            let h_phi = if phi > 0. { -DELTA } else { DELTA };
+
+            let (x_l, y_l) = fwd(lam + h_lam, phi)?;
+            let (x_p, y_p) = fwd(lam, phi + h_phi)?;
+
+            let d_x_lam = (x_l - xa) / h_lam;
+            let d_y_lam = (y_l - ya) / h_lam;
+            let d_x_phi = (x_p - xa) / h_phi;
+            let d_y_phi = (y_p - ya) / h_phi;
+
+            let det = d_x_lam * d_y_phi - d_x_phi * d_y_lam;
+            if det == 0. {
+                break;
+            }
+            d_lam_x = d_y_phi / det;
+            d_lam_y = -d_x_phi / det;
+            d_phi_x = -d_y_lam / det;
+            d_phi_y = d_x_lam / det;
+        }
+
+        lam -= (dx * d_lam_x + dy * d_lam_y).clamp(-MAX_STEP, MAX_STEP);
+        phi -= (dx * d_phi_x + dy * d_phi_y).clamp(-MAX_STEP, MAX_STEP);
+        lam = lam.clamp(-PI, PI);
+        phi = phi.clamp(-FRAC_PI_2, FRAC_PI_2);
+    }
+
+    Err(Error::CoordTransOutsideProjectionDomain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn inverse_2d_sinusoidal() {
+        let fwd = |lam: f64, phi: f64| Ok((lam * phi.cos(), phi));
+        let (x, y) = fwd(1.2, 0.7).unwrap();
+        let (lam, phi) = inverse_2d(fwd, x, y, (x, y), 1.0e-12).unwrap();
+        assert_abs_diff_eq!(lam, 1.2, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(phi, 0.7, epsilon = 1.0e-10);
+    }
+}
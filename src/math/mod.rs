@@ -95,18 +95,25 @@ mod aasincos;
 mod adjlon;
 mod auth;
 mod gauss;
+mod geodesic;
+mod inverse2d;
 mod mlfn;
 mod msfn;
+mod newton;
 mod phi2;
 mod qsfn;
 mod tsfn;
 
-pub(crate) use aasincos::aasin;
+pub(crate) use aasincos::{aacos, aasin, aasin_tol, aatan2, asqrt};
 pub(crate) use adjlon::adjlon;
+pub use adjlon::normalize_longitude;
 pub(crate) use auth::{authlat, authset};
 pub(crate) use gauss::{gauss, gauss_ini, inv_gauss, Gauss};
+pub(crate) use geodesic::{geodesic_direct, geodesic_distance, geodesic_inverse};
+pub(crate) use inverse2d::inverse_2d;
 pub(crate) use mlfn::{enfn, inv_mlfn, mlfn, Enfn};
 pub(crate) use msfn::msfn;
+pub(crate) use newton::newton;
 pub(crate) use phi2::phi2;
 pub(crate) use qsfn::qsfn;
 pub(crate) use tsfn::tsfn;
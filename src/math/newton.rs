@@ -0,0 +1,41 @@
+//! Newton-Raphson iteration in one dimension
+//!
+//! Used by projections solving an equation in the latitude
+//! or in an auxiliary angle.
+//!
+
+/// Solve `f(x) = 0` starting from `x`, where `step(x)`
+/// returns `f(x) / f'(x)`.
+///
+/// Iterate until the step is less than `tol`, at most `max_iter`
+/// times. On failure, the last approximation is returned as error:
+/// like in proj, some projections use it anyway.
+pub(crate) fn newton<F>(mut x: f64, tol: f64, max_iter: usize, step: F) -> Result<f64, f64>
+where
+    F: Fn(f64) -> f64,
+{
+    for _ in 0..max_iter {
+        let v = step(x);
+        x -= v;
+        if v.abs() < tol {
+            return Ok(x);
+        }
+    }
+    Err(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn newton_sqrt() {
+        let x = newton(1., 1.0e-12, 10, |x| (x * x - 2.) / (2. * x)).unwrap();
+        assert_abs_diff_eq!(x, 2f64.sqrt(), epsilon = 1.0e-15);
+
+        // No convergence: return the last approximation
+        let x = newton(1., 1.0e-12, 2, |x| (x * x - 2.) / (2. * x)).unwrap_err();
+        assert_abs_diff_eq!(x, 17. / 12., epsilon = 1.0e-15);
+    }
+}
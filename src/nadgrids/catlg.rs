@@ -41,7 +41,7 @@ mod implem {
         /// Convert raw ptr to static reference
         pub(super) fn get(&self) -> Option<&'static Node> {
             let p = self.0.load(Ordering::Relaxed);
-            (!p.is_null()).then_some(unsafe { &*p })
+            (!p.is_null()).then(|| unsafe { &*p })
         }
         pub(super) fn insert(&self, node: Node) -> &'static Node {
             node.next
@@ -51,6 +51,19 @@ mod implem {
             self.0.store(p, Ordering::Relaxed);
             unsafe { &*p }
         }
+        /// Detach the chain of nodes
+        ///
+        /// Nodes are never deallocated: the chain may be linked
+        /// to another catalog.
+        pub(super) fn take(&self) -> Option<&'static Node> {
+            let p = self.0.swap(null_mut(), Ordering::Relaxed);
+            (!p.is_null()).then(|| unsafe { &*p })
+        }
+        /// Link an existing chain of nodes
+        pub(super) fn link(&self, node: &'static Node) {
+            self.0
+                .store(node as *const Node as *mut Node, Ordering::Relaxed);
+        }
     }
 }
 
@@ -169,6 +182,22 @@ impl Catalog {
         self.add_node(Node::new(name, grid, parent));
         Ok(())
     }
+
+    /// Move the grids of a private catalog at the end
+    /// of the catalog
+    ///
+    /// Nodes are linked as is, so that grid references
+    /// obtained from `other` remain valid.
+    #[cfg(feature = "multi-thread")]
+    fn append(&self, other: Catalog) {
+        if let Some(first) = other.first.take() {
+            self.iter()
+                .last()
+                .map(|n| &n.next)
+                .unwrap_or(&self.first)
+                .link(first);
+        }
+    }
 }
 
 #[cfg(feature = "multi-thread")]
@@ -207,6 +236,93 @@ pub mod catalog {
     pub fn set_builder(builder: GridBuilder) -> Option<GridBuilder> {
         CATALOG.lock().unwrap().builder.replace(builder)
     }
+
+    pub fn unset_builder() -> Option<GridBuilder> {
+        CATALOG.lock().unwrap().builder.take()
+    }
+
+    /// Load the grids `names` in the catalog using the grid builder
+    ///
+    /// Grids not already in the catalog are loaded in parallel, using
+    /// at most one thread per available cpu, so that later transformations
+    /// do not have to wait for the grids to be loaded.
+    ///
+    /// If some grids cannot be loaded, the grids that were loaded
+    /// successfully are still added to the catalog and the first
+    /// error is returned.
+    pub fn preload_grids(names: &[&str]) -> Result<(), Error> {
+        let (builder, missing) = {
+            let cat = CATALOG.lock().unwrap();
+            let missing: Vec<&str> = names
+                .iter()
+                .copied()
+                .filter(|name| cat.find(name).is_none())
+                .collect();
+            (cat.builder, missing)
+        };
+
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        let builder = builder.ok_or(Error::NadGridNotAvailable)?;
+
+        let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = (missing.len() + workers - 1) / workers;
+
+        // Each grid is loaded in its own private catalog
+        let loaded = std::thread::scope(|s| {
+            missing
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    s.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|name| {
+                                let cat = Catalog::default();
+                                builder(&cat, name).map(|_| cat)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .zip(missing.chunks(chunk_size))
+                .flat_map(|(h, chunk)| {
+                    h.join().unwrap_or_else(|_| {
+                        chunk
+                            .iter()
+                            .map(|_| Err(Error::NadGridNotAvailable))
+                            .collect()
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let cat = CATALOG.lock().unwrap();
+        let mut result = Ok(());
+        for (name, grids) in missing.iter().zip(loaded) {
+            match grids {
+                // May have been loaded concurrently
+                Ok(grids) if cat.find(name).is_none() => cat.append(grids),
+                Ok(_) => (),
+                Err(err) => {
+                    error!("Error loading grid shift {}", name);
+                    if result.is_ok() {
+                        result = Err(err);
+                    }
+                }
+            }
+        }
+
+        result.and_then(|_| {
+            if missing.iter().all(|name| cat.find(name).is_some()) {
+                Ok(())
+            } else {
+                Err(Error::NadGridNotAvailable)
+            }
+        })
+    }
 }
 #[cfg(not(feature = "multi-thread"))]
 pub mod catalog {
@@ -242,4 +358,95 @@ pub mod catalog {
     pub fn set_builder(builder: GridBuilder) -> Option<GridBuilder> {
         CATALOG.with(|cat| cat.builder.borrow_mut().replace(builder))
     }
+
+    pub fn unset_builder() -> Option<GridBuilder> {
+        CATALOG.with(|cat| cat.builder.borrow_mut().take())
+    }
+
+    /// Load the grids `names` in the catalog using the grid builder
+    ///
+    /// If some grids cannot be loaded, the grids that were loaded
+    /// successfully are still added to the catalog and the first
+    /// error is returned.
+    pub fn preload_grids(names: &[&str]) -> Result<(), Error> {
+        CATALOG.with(|cat| {
+            let mut result = Ok(());
+            for name in names.iter().filter(|name| cat.find(name).is_none()) {
+                let loaded = cat
+                    .builder
+                    .borrow()
+                    .ok_or(Error::NadGridNotAvailable)
+                    .and_then(|builder| builder(cat, name))
+                    .and_then(|_| cat.find(name).map(|_| ()).ok_or(Error::NadGridNotAvailable));
+                if let Err(err) = loaded {
+                    error!("Error loading grid shift {}", name);
+                    if result.is_ok() {
+                        result = Err(err);
+                    }
+                }
+            }
+            result
+        })
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::proj::Proj;
+    use crate::tests::setup;
+    use crate::transform::transform;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const PRELOAD_GRIDS: [&str; 2] = ["preload_a.gsb", "preload_b.gsb"];
+
+    static LOADS: AtomicUsize = AtomicUsize::new(0);
+
+    // Mock loader: count the number of loads of the test grids,
+    // other grids are not available
+    fn mock_builder(cat: &Catalog, key: &str) -> Result<(), Error> {
+        if !key.starts_with("preload_") {
+            return Err(Error::NadGridNotAvailable);
+        }
+        LOADS.fetch_add(1, Ordering::SeqCst);
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/100800401.gsb");
+        crate::nadgrids::files::read(cat, key, &mut BufReader::new(File::open(path)?))
+    }
+
+    #[test]
+    fn catalog_preload_grids() {
+        setup();
+
+        let prev_builder = catalog::set_builder(mock_builder);
+
+        catalog::preload_grids(&PRELOAD_GRIDS).unwrap();
+        let loads = LOADS.load(Ordering::SeqCst);
+        assert_eq!(loads, 2);
+
+        // Already loaded
+        catalog::preload_grids(&PRELOAD_GRIDS).unwrap();
+        assert_eq!(LOADS.load(Ordering::SeqCst), loads);
+
+        // Use the grid: no more loads
+        let src =
+            Proj::from_proj_string("+proj=latlong +ellps=intl +nadgrids=preload_a.gsb").unwrap();
+        let dst = Proj::from_proj_string("+proj=latlong +ellps=GRS80 +towgs84=0,0,0").unwrap();
+        let mut pt = (2.17f64.to_radians(), 41.39f64.to_radians(), 0.);
+        transform(&src, &dst, &mut pt).unwrap();
+        assert_eq!(LOADS.load(Ordering::SeqCst), loads);
+
+        // Grids loaded before a failure are kept
+        catalog::preload_grids(&["preload_c.gsb", "unknown.gsb"]).unwrap_err();
+        let loads = LOADS.load(Ordering::SeqCst);
+        assert_eq!(loads, 3);
+        catalog::preload_grids(&["preload_c.gsb"]).unwrap();
+        assert_eq!(LOADS.load(Ordering::SeqCst), loads);
+
+        match prev_builder {
+            Some(builder) => catalog::set_builder(builder),
+            None => catalog::unset_builder(),
+        };
+    }
 }
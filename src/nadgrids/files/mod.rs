@@ -3,7 +3,7 @@
 //!
 use std::env;
 use std::fs::File;
-use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 
 use crate::errors::{Error, Result};
@@ -55,7 +55,11 @@ pub(crate) fn recognize<R: Read + Seek>(key: &str, read: &mut R) -> Result<FileT
             FileType::Ntv1
         } else if size >= 48 + 7 && header.cmp_str(0, "NUM_OREC") && header.cmp_str(48, "GS_TYPE") {
             FileType::Ntv2
-        } else if key.ends_with("gtx") || key.ends_with("GTX") {
+        } else if key.ends_with("gtx")
+            || key.ends_with("GTX")
+            || key.ends_with("gtx.gz")
+            || key.ends_with("GTX.GZ")
+        {
             FileType::Gtx
         } else if size >= 9 && header.cmp_str(0, "CTABLE V2") {
             FileType::Ctable2
@@ -70,16 +74,49 @@ pub(crate) fn recognize<R: Read + Seek>(key: &str, read: &mut R) -> Result<FileT
     rv
 }
 
+/// Gzip magic bytes
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
 /// Grid builder that read from a file
+///
+/// Gzip compressed files are detected from their magic bytes
+/// and decompressed transparently if the `flate2` feature is enabled.
 pub fn read_from_file(catalog: &Catalog, key: &str) -> Result<()> {
     // Use a BufReader for efficiency
-    read(
+    read_maybe_compressed(
         catalog,
         key,
         &mut BufReader::new(File::open(default_file_finder(key)?)?),
     )
 }
 
+/// Read a grid, decompressing it first if it is gzipped
+pub(crate) fn read_maybe_compressed<R: BufRead + Seek>(
+    catalog: &Catalog,
+    key: &str,
+    read: &mut R,
+) -> Result<()> {
+    if read.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        read_gzip(catalog, key, read)
+    } else {
+        self::read(catalog, key, read)
+    }
+}
+
+#[cfg(feature = "flate2")]
+fn read_gzip<R: Read>(catalog: &Catalog, key: &str, read: &mut R) -> Result<()> {
+    // Grid readers need to seek in the data, so the whole
+    // file is decompressed in memory.
+    let mut data = Vec::new();
+    flate2::read::GzDecoder::new(read).read_to_end(&mut data)?;
+    self::read(catalog, key, &mut std::io::Cursor::new(data))
+}
+
+#[cfg(not(feature = "flate2"))]
+fn read_gzip<R: Read>(_catalog: &Catalog, _key: &str, _read: &mut R) -> Result<()> {
+    Err(Error::CompressedGridNotSupported)
+}
+
 /// Read a grid from a file given by `key`
 pub(crate) fn read<R: Read + Seek>(catalog: &Catalog, key: &str, read: &mut R) -> Result<()> {
     // Guess the file
@@ -88,3 +125,60 @@ pub(crate) fn read<R: Read + Seek>(catalog: &Catalog, key: &str, read: &mut R) -
         _ => Err(Error::UnknownGridFormat),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::setup;
+    use std::io::Cursor;
+
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/100800401.gsb");
+
+    #[test]
+    #[cfg(feature = "flate2")]
+    fn read_gzip_grid() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        setup();
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&std::fs::read(FIXTURE).unwrap()).unwrap();
+        let data = encoder.finish().unwrap();
+        assert!(data.starts_with(&GZIP_MAGIC));
+
+        let catalog = Catalog::default();
+        read_maybe_compressed(&catalog, "100800401.gsb.gz", &mut Cursor::new(data)).unwrap();
+
+        let grids = catalog
+            .find("100800401.gsb.gz")
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(grids.len(), 1);
+        assert_eq!(grids[0].id.as_str(), "0INT2GRS");
+        assert_eq!(grids[0].cvs.len(), 1591);
+    }
+
+    #[test]
+    #[cfg(not(feature = "flate2"))]
+    fn read_gzip_grid_unsupported() {
+        setup();
+
+        let catalog = Catalog::default();
+        let data = [0x1f, 0x8b, 0x08, 0x00];
+        assert!(matches!(
+            read_maybe_compressed(&catalog, "foo.gsb.gz", &mut Cursor::new(data)),
+            Err(Error::CompressedGridNotSupported)
+        ));
+    }
+
+    #[test]
+    fn read_uncompressed_grid() {
+        setup();
+
+        let catalog = Catalog::default();
+        let data = std::fs::read(FIXTURE).unwrap();
+        read_maybe_compressed(&catalog, "100800401.gsb", &mut Cursor::new(data)).unwrap();
+        assert_eq!(catalog.find("100800401.gsb").unwrap().count(), 1);
+    }
+}
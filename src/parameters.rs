@@ -56,6 +56,15 @@ impl<'a> Parameter<'a> {
         self.try_value::<f64>().map(|v| v.to_radians())
     }
 
+    /// Parse a comma separated list of numbers
+    pub fn try_f64_list(&self) -> Result<Vec<f64>> {
+        self.value
+            .ok_or(Error::NoValueParameter)?
+            .split(',')
+            .map(|v| f64::from_str(v.trim()).map_err(|_err| Error::ParameterValueError))
+            .collect()
+    }
+
     /// Check the token as a boolean flag
     ///
     /// Return true if the token is present alone (no value), false
@@ -78,6 +87,11 @@ impl<'a> ParamList<'a> {
         self.0.iter().find(|p| p.name == name)
     }
 
+    /// Iterate over the parameters, in definition order
+    pub fn iter(&self) -> impl Iterator<Item = &Parameter<'a>> {
+        self.0.iter()
+    }
+
     pub fn check_option(&self, name: &str) -> Result<bool> {
         self.get(name)
             .map(|p| p.check_option())
@@ -94,6 +108,36 @@ impl<'a> ParamList<'a> {
     pub fn try_angular_value(&self, name: &str) -> Result<Option<f64>> {
         self.get(name).map(|p| p.try_angular_value()).transpose()
     }
+
+    /// Append the parameters of `defaults` that are not
+    /// already in the list
+    pub fn with_defaults(mut self, defaults: ParamList<'a>) -> Self {
+        defaults.0.into_iter().for_each(|p| {
+            if self.get(p.name).is_none() {
+                self.0.push(p);
+            }
+        });
+        self
+    }
+
+    /// Return the comma separated list of numbers for
+    /// the parameter `name`.
+    ///
+    /// If `counts` is not empty, the number of values must be one of
+    /// `counts`, otherwise [`Error::InvalidParameterCount`] is returned.
+    pub fn get_f64_list(&self, name: &str, counts: &[usize]) -> Result<Option<Vec<f64>>> {
+        self.get(name)
+            .map(|p| {
+                p.try_f64_list().and_then(|v| {
+                    if counts.is_empty() || counts.contains(&v.len()) {
+                        Ok(v)
+                    } else {
+                        Err(Error::InvalidParameterCount(name.into(), v.len()))
+                    }
+                })
+            })
+            .transpose()
+    }
 }
 
 // Create from Parameter iterator
@@ -105,16 +149,17 @@ impl<'a> FromIterator<Parameter<'a>> for ParamList<'a> {
 
 #[cfg(test)]
 mod tests {
+    use crate::errors::Error;
     use crate::projstring::parse;
 
     #[test]
     fn param_check_options() {
         let params = parse("+foo +bar=true +baz=false +bad=foobar").unwrap();
 
-        assert_eq!(params.check_option("foo").unwrap(), true);
-        assert_eq!(params.check_option("bar").unwrap(), true);
-        assert_eq!(params.check_option("baz").unwrap(), false);
-        assert_eq!(params.check_option("foobar").unwrap(), false);
+        assert!(params.check_option("foo").unwrap());
+        assert!(params.check_option("bar").unwrap());
+        assert!(!params.check_option("baz").unwrap());
+        assert!(!params.check_option("foobar").unwrap());
 
         assert!(params.check_option("bad").is_err());
     }
@@ -135,4 +180,32 @@ mod tests {
         assert_eq!(params.try_value::<f64>("foo").unwrap().unwrap_or(0.), 1234.);
         assert_eq!(params.try_value::<f64>("bar").unwrap().unwrap_or(0.), 0.);
     }
+
+    #[test]
+    fn param_f64_list() {
+        let params = parse("+towgs84=1,2,3,4,5,6,7 +foo=1,2.5,-3 +bar=1,x,3 +baz").unwrap();
+
+        assert_eq!(
+            params.get_f64_list("towgs84", &[3, 7]).unwrap().unwrap(),
+            vec![1., 2., 3., 4., 5., 6., 7.]
+        );
+        assert_eq!(
+            params.get_f64_list("foo", &[]).unwrap().unwrap(),
+            vec![1., 2.5, -3.]
+        );
+        assert!(params.get_f64_list("missing", &[3]).unwrap().is_none());
+
+        assert!(matches!(
+            params.get_f64_list("towgs84", &[3]),
+            Err(Error::InvalidParameterCount(name, 7)) if name == "towgs84"
+        ));
+        assert!(matches!(
+            params.get_f64_list("bar", &[]),
+            Err(Error::ParameterValueError)
+        ));
+        assert!(matches!(
+            params.get_f64_list("baz", &[]),
+            Err(Error::NoValueParameter)
+        ));
+    }
 }
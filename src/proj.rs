@@ -5,13 +5,21 @@
 //! for parameter's descriptions.
 //!
 
+#[cfg(any(feature = "epsg", feature = "crs-definitions"))]
+use crate::areas::find_area_of_use;
 use crate::datum_params::DatumParams;
 use crate::datum_transform::Datum;
 use crate::datums::{self, DatumDefn};
 use crate::ellps::Ellipsoid;
+#[cfg(feature = "epsg")]
+use crate::epsg::{epsg_codes, find_epsg_defn};
 use crate::errors::{Error, Result};
-use crate::parameters::ParamList;
+use crate::factors::{self, Factors};
+use crate::initdefs::find_init_defn;
+use crate::math::adjlon;
+use crate::parameters::{ParamList, Parameter};
 use crate::projections::{find_projection, ProjDelegate};
+use crate::transform::{transform, Transform};
 use crate::{ellipsoids, prime_meridians, projstring, units};
 
 use std::fmt;
@@ -27,6 +35,70 @@ pub enum ProjType {
     Other,
 }
 
+/// Grid system a projection was defined from
+///
+/// UTM and UPS are expanded to their underlying projections
+/// (`etmerc` and `stere`), the original definition is kept so
+/// that the projection can be written back in its canonical form.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::proj::GridSystem;
+///
+/// let p = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+/// assert_eq!(p.grid_system(), Some(GridSystem::Utm { zone: 31, south: false }));
+/// assert_eq!(p.grid_system().unwrap().to_string(), "+proj=utm +zone=31 +north");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GridSystem {
+    Utm { zone: u8, south: bool },
+    Ups { south: bool },
+}
+
+impl fmt::Display for GridSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hemisphere = |south: bool| if south { "+south" } else { "+north" };
+        match self {
+            Self::Utm { zone, south } => {
+                write!(f, "+proj=utm +zone={zone} {}", hemisphere(*south))
+            }
+            Self::Ups { south } => write!(f, "+proj=ups {}", hemisphere(*south)),
+        }
+    }
+}
+
+/// Vertical coordinate system of a projection
+///
+/// Heights (the `z` component) are expressed in their own units,
+/// given by `+vunits` (or `+vto_meter`), independently of the
+/// horizontal units. Heights are ellipsoidal: vertical datums
+/// (i.e geoid models) are not supported.
+///
+/// ```rust
+/// use proj4rs::Proj;
+///
+/// let p = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84 +vunits=ft").unwrap();
+/// assert_eq!(p.units(), "m");
+/// assert_eq!(p.vertical_crs().units(), "ft");
+/// assert_eq!(p.vertical_crs().to_meter(), 0.3048);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VerticalCrs {
+    units: &'static str,
+    to_meter: f64,
+}
+
+impl VerticalCrs {
+    /// Return the name of the vertical units
+    pub fn units(&self) -> &'static str {
+        self.units
+    }
+    /// Return the conversion factor from vertical units to meters
+    pub fn to_meter(&self) -> f64 {
+        self.to_meter
+    }
+}
+
 /// A Proj object hold informations and parameters
 /// for a projection
 #[derive(Debug, Clone)]
@@ -42,6 +114,7 @@ pub(crate) struct ProjData {
     pub(crate) k0: f64,
     pub(crate) lam0: f64,
     pub(crate) phi0: f64,
+    pub(crate) grid: Option<GridSystem>,
 }
 
 ///
@@ -74,6 +147,11 @@ pub struct Proj {
     projdata: ProjData,
     projname: &'static str,
     projection: ProjDelegate,
+    // Parameters of the definition
+    params: Vec<(String, Option<String>)>,
+    // Set when created from an EPSG code
+    #[cfg(any(feature = "epsg", feature = "crs-definitions"))]
+    epsg: Option<u16>,
 }
 
 //----------------------
@@ -99,6 +177,50 @@ impl Proj {
     pub fn has_forward(&self) -> bool {
         self.projection.has_forward()
     }
+    /// Check if the projected coordinates `(x, y)` can be inverted
+    ///
+    /// Coordinates are expressed in projection units, in the
+    /// normalized axis order (easting, northing).
+    ///
+    /// This is a cheap test that allow bulk processing to skip
+    /// points outside of the mapped region without attempting the
+    /// inverse projection: returning `true` does not guarantee that
+    /// the inverse will succeed.
+    pub fn inverse_valid_domain(&self, x: f64, y: f64) -> bool {
+        if !self.has_inverse() {
+            return false;
+        }
+        match self.projection_type() {
+            ProjType::Other => {
+                let d = &self.projdata;
+                self.projection.inverse_valid_domain(
+                    (x * d.to_meter - d.x0) * d.ellps.ra,
+                    (y * d.to_meter - d.y0) * d.ellps.ra,
+                )
+            }
+            _ => true,
+        }
+    }
+
+    /// Compute the false easting and northing (`x_0`, `y_0`), in meters,
+    /// such that the natural origin (`lon_0`, `lat_0`) is projected
+    /// to `(x, y)`, in projection units.
+    ///
+    /// Return an error for geographic or geocentric coordinates.
+    pub fn natural_origin_offset(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        if self.projection_type() != ProjType::Other {
+            return Err(Error::InvalidParameterValue(
+                "No natural origin for geographic or geocentric coordinates",
+            ));
+        }
+        let d = &self.projdata;
+        let (x_o, y_o, _) = self.projection.forward(0., d.phi0, 0.)?;
+        Ok((
+            x * d.to_meter - d.ellps.a * x_o,
+            y * d.to_meter - d.ellps.a * y_o,
+        ))
+    }
+
     #[inline]
     pub(crate) fn data(&self) -> &ProjData {
         &self.projdata
@@ -120,6 +242,44 @@ impl Proj {
     pub(crate) fn ellipsoid(&self) -> &Ellipsoid {
         &self.projdata.ellps
     }
+    /// Return the semi-major axis of the ellipsoid, in meters
+    #[inline]
+    pub fn semimajor(&self) -> f64 {
+        self.projdata.ellps.a
+    }
+    /// Return the semi-minor axis of the ellipsoid, in meters
+    #[inline]
+    pub fn semiminor(&self) -> f64 {
+        self.projdata.ellps.b
+    }
+    /// Return the parameters of the definition, in definition order
+    ///
+    /// Parameters of named definitions (`+init=`) are included.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=lcc +lat_1=0.5 +lat_2=2 +no_defs").unwrap();
+    /// for (name, value) in p.params() {
+    ///     println!("{name}: {value:?}");
+    /// }
+    /// assert!(p.params().any(|p| p == ("lat_2", Some("2"))));
+    /// ```
+    pub fn params(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_deref()))
+    }
+    /// Return the grid system (UTM or UPS) the projection was defined from
+    #[inline]
+    pub fn grid_system(&self) -> Option<GridSystem> {
+        self.projdata.grid
+    }
+    /// Return true if the ellipsoid is a sphere
+    #[inline]
+    pub fn is_sphere(&self) -> bool {
+        self.projdata.ellps.is_sphere()
+    }
     #[inline]
     pub fn vto_meter(&self) -> f64 {
         self.projdata.vto_meter
@@ -141,6 +301,16 @@ impl Proj {
     pub fn is_latlong(&self) -> bool {
         self.projdata.proj_type == ProjType::Latlong
     }
+    /// Return true if the coordinates are angular (radians)
+    ///
+    /// This is the case for geographic coordinates (`latlong`)
+    /// only: projected coordinates, even from a cylindrical
+    /// projection like `eqc`, and geocentric coordinates are
+    /// linear, in projection units.
+    #[inline]
+    pub fn output_is_angular(&self) -> bool {
+        self.is_latlong()
+    }
     #[inline]
     pub fn is_geocent(&self) -> bool {
         self.projdata.proj_type == ProjType::Geocentric
@@ -167,6 +337,14 @@ impl Proj {
     pub fn vunits(&self) -> &'static str {
         self.vunits
     }
+
+    /// Return the vertical coordinate system
+    pub fn vertical_crs(&self) -> VerticalCrs {
+        VerticalCrs {
+            units: self.vunits,
+            to_meter: self.projdata.vto_meter,
+        }
+    }
 }
 
 //-------------------------
@@ -210,9 +388,9 @@ impl Proj {
         if let Some(p) = params.get("nadgrids") {
             // Nadgrids
             DatumParams::from_nadgrid_str(p.try_into()?)
-        } else if let Some(p) = params.get("towgs84") {
-            DatumParams::from_towgs84_str(p.try_into()?)
+        } else if let Some(towgs84) = params.get_f64_list("towgs84", &[3, 7])? {
             // ToWGS84
+            DatumParams::from_towgs84(&towgs84)
         } else if let Some(p) = defn {
             DatumParams::try_from(&p.params)
         } else {
@@ -308,6 +486,16 @@ impl Proj {
     /// Consume a ParamList and create a Proj object
     ///
     pub fn init(params: ParamList) -> Result<Self> {
+        // Expand named definition
+        let params = match params.get("init") {
+            Some(p) => {
+                let name: &str = p.try_into()?;
+                let defn = find_init_defn(name).ok_or(Error::InitDefnNotFound)?;
+                params.with_defaults(projstring::parse(defn.defn)?)
+            }
+            None => params,
+        };
+
         // Find projection
         let proj_init = params
             .get("proj")
@@ -352,11 +540,12 @@ impl Proj {
             x0: params.try_value("x_0")?.unwrap_or(0.),
             y0: params.try_value("y_0")?.unwrap_or(0.),
             // Proj4 compatibility
-            k0: match params.get("k0") {
+            k0: match params.get("k_0").or_else(|| params.get("k0")) {
                 Some(p) => Some(p.try_into()).transpose(),
                 None => params.try_value("k"),
             }?
             .unwrap_or(1.),
+            grid: None,
         };
 
         let project = proj_init.init(&mut projdata, &params)?;
@@ -371,6 +560,12 @@ impl Proj {
             projdata,
             projname: proj_init.name(),
             projection: project,
+            params: params
+                .iter()
+                .map(|p| (p.name.to_string(), p.value.map(String::from)))
+                .collect(),
+            #[cfg(any(feature = "epsg", feature = "crs-definitions"))]
+            epsg: None,
         })
     }
 
@@ -379,6 +574,33 @@ impl Proj {
         Self::init(projstring::parse(s)?)
     }
 
+    /// Create from a key/value map of parameters
+    ///
+    /// Keys are parameter names, with or without the leading `+`.
+    /// An empty value denotes a flag parameter (i.e `no_defs`).
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let params = [("proj", "lcc"), ("lat_1", "49"), ("lat_2", "44"), ("ellps", "GRS80")];
+    /// let proj = Proj::from_params(
+    ///     params.iter().map(|(k, v)| (k.to_string(), v.to_string()))
+    /// ).unwrap();
+    /// assert_eq!(proj.projname(), "lcc");
+    /// ```
+    pub fn from_params<I: IntoIterator<Item = (String, String)>>(params: I) -> Result<Self> {
+        let params: Vec<(String, String)> = params.into_iter().collect();
+        Self::init(
+            params
+                .iter()
+                .map(|(name, value)| Parameter {
+                    name: name.trim().trim_start_matches('+'),
+                    value: Some(value.trim()).filter(|v| !v.is_empty()),
+                })
+                .collect(),
+        )
+    }
+
     /// Create projection from user string
     pub fn from_user_string(s: &str) -> Result<Self> {
         let s = s.trim();
@@ -386,11 +608,44 @@ impl Proj {
             Self::from_proj_string(s)
         } else if s.eq_ignore_ascii_case("WGS84") {
             Self::from_proj_string("+proj=longlat +ellps=WGS84")
+        } else if let Some(defn) = find_init_defn(s) {
+            Self::from_proj_string(defn.defn)
         } else {
             Err(Error::UnrecognizedFormat)
         }
     }
 
+    /// Create projection from a common EPSG code
+    ///
+    /// Definitions are looked up in a small compiled-in table: the
+    /// geographic CRS 4326 (WGS 84), 4258 (ETRS89), 4269 (NAD83), 4171 (RGF93),
+    /// and the projected CRS 3857, 3395, 2154, 3035, 27700, 28992 and the
+    /// WGS 84 UTM zones (326xx and 327xx).
+    ///
+    /// Return [`Error::ProjectionNotFound`] for other codes. See also
+    /// `from_epsg_code` with the `crs-definitions` feature for the whole
+    /// EPSG registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use proj4rs::Proj;
+    ///
+    /// let proj = Proj::from_epsg(2154).unwrap();
+    ///
+    /// assert_eq!(proj.projname(), "lcc");
+    /// ```
+    #[cfg(feature = "epsg")]
+    pub fn from_epsg(code: u32) -> Result<Self> {
+        find_epsg_defn(code)
+            .ok_or(Error::ProjectionNotFound)
+            .and_then(|defn| Self::from_proj_string(&defn))
+            .map(|p| Self {
+                epsg: u16::try_from(code).ok(),
+                ..p
+            })
+    }
+
     /// Create projection from user string
     ///
     /// # Examples
@@ -410,6 +665,352 @@ impl Proj {
         crs_definitions::from_code(code)
             .ok_or(Error::ProjectionNotFound)
             .and_then(|def| Self::from_proj_string(def.proj4))
+            .map(|p| Self {
+                epsg: Some(code),
+                ..p
+            })
+    }
+
+    /// Return the area of use of the projection
+    ///
+    /// The bounding box is expressed in degrees as
+    /// `(west, south, east, north)`. When `west` is greater than
+    /// `east`, the area crosses the antimeridian.
+    ///
+    /// Return `None` if the projection was not created from an
+    /// EPSG code or if the area of use of the code is not known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use proj4rs::Proj;
+    ///
+    /// # #[cfg(feature = "epsg")]
+    /// # {
+    /// let proj = Proj::from_epsg(2154).unwrap();
+    ///
+    /// assert_eq!(proj.area_of_use(), Some((-9.86, 41.15, 10.38, 51.56)));
+    /// # }
+    /// ```
+    #[cfg(any(feature = "epsg", feature = "crs-definitions"))]
+    pub fn area_of_use(&self) -> Option<(f64, f64, f64, f64)> {
+        self.epsg.and_then(find_area_of_use)
+    }
+
+    /// Best-effort reverse lookup of the EPSG code
+    ///
+    /// Return the EPSG code the projection was created from, if any.
+    /// Otherwise, compare the projection against the compiled-in
+    /// definitions (with the `epsg` feature), then against the
+    /// definitions of the embedded registry (with the `crs-definitions`
+    /// feature), and return the code of the first equivalent definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use proj4rs::Proj;
+    ///
+    /// let proj = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    ///
+    /// assert_eq!(proj.equivalent_epsg(), Some(32631));
+    /// ```
+    #[cfg(any(feature = "epsg", feature = "crs-definitions"))]
+    pub fn equivalent_epsg(&self) -> Option<u32> {
+        if let Some(code) = self.epsg {
+            return Some(code.into());
+        }
+
+        let is_equivalent = |defn: &str| {
+            Self::from_proj_string(defn)
+                .map(|other| self.is_equivalent_to(&other))
+                .unwrap_or(false)
+        };
+
+        #[cfg(feature = "epsg")]
+        if let Some(code) = epsg_codes()
+            .find(|code| find_epsg_defn(*code).map_or(false, |defn| is_equivalent(&defn)))
+        {
+            return Some(code);
+        }
+
+        #[cfg(feature = "crs-definitions")]
+        if let Some(def) = crs_definitions::DEFINITIONS
+            .iter()
+            .find(|def| is_equivalent(def.proj4))
+        {
+            return Some(def.code as u32);
+        }
+
+        None
+    }
+}
+
+/// Parse a projection from a projstring
+///
+/// ```rust
+/// use proj4rs::Proj;
+///
+/// let p: Proj = "+proj=utm +ellps=GRS80 +zone=30".parse().unwrap();
+/// ```
+impl std::str::FromStr for Proj {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_proj_string(s)
+    }
+}
+
+/// Create a projection from a projstring
+impl TryFrom<&str> for Proj {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Self::from_proj_string(s)
+    }
+}
+
+/// Create a projection from an EPSG code
+///
+/// ```rust
+/// use proj4rs::Proj;
+///
+/// let p = Proj::try_from(4326u32).unwrap();
+/// assert_eq!(p.projname(), "longlat");
+/// ```
+#[cfg(feature = "crs-definitions")]
+impl TryFrom<u32> for Proj {
+    type Error = Error;
+
+    fn try_from(code: u32) -> Result<Self> {
+        u16::try_from(code)
+            .map_err(|_| Error::ProjectionNotFound)
+            .and_then(Self::from_epsg_code)
+    }
+}
+
+//-------------------------
+// Transformation
+//------------------------
+impl Proj {
+    /// Transform `points` from `self` to `dst` CRS, with geographic
+    /// coordinates in degrees
+    ///
+    /// Input coordinates are converted from degrees to radians if `self`
+    /// is geographic, and output coordinates are converted back to degrees
+    /// if `dst` is geographic. Projected coordinates are left untouched.
+    ///
+    /// As with [`transform`], the content of `points` is unspecified when
+    /// an error is returned: the input coordinates may have been converted
+    /// to radians and partially transformed.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let src = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    /// let dst = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=31").unwrap();
+    ///
+    /// let mut pt = (3.0, 45.0, 0.0);
+    /// src.transform_degrees_in_place(&dst, &mut pt).unwrap();
+    /// assert!((pt.0 - 500000.0).abs() < 1.0e-6);
+    /// ```
+    pub fn transform_degrees_in_place<P>(&self, dst: &Proj, points: &mut P) -> Result<()>
+    where
+        P: Transform + ?Sized,
+    {
+        if self.is_latlong() {
+            points.transform_coordinates(&mut |x: f64, y: f64, z| {
+                Ok((x.to_radians(), y.to_radians(), z))
+            })?;
+        }
+        transform(self, dst, points)?;
+        if dst.is_latlong() {
+            points.transform_coordinates(&mut |x: f64, y: f64, z| {
+                Ok((x.to_degrees(), y.to_degrees(), z))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+//-------------------------
+// Diagnostics
+//------------------------
+impl Proj {
+    /// Return the round-trip residual, in meters, of the point `(lon, lat)`
+    ///
+    /// The point, in the coordinates of `self` (radians for geographic
+    /// coordinates), is transformed to `other` and then back to `self`.
+    ///
+    /// For geographic coordinates, the residual is computed as a distance
+    /// on the sphere of radius equal to the semi-major axis,
+    /// which is accurate enough for small residuals.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    /// let utm = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
+    ///
+    /// let err = geo.roundtrip_error(&utm, -3f64.to_radians(), 45f64.to_radians()).unwrap();
+    /// assert!(err < 1.0e-6);
+    /// ```
+    pub fn roundtrip_error(&self, other: &Proj, lon: f64, lat: f64) -> Result<f64> {
+        let mut pt = (lon, lat, 0.);
+        transform(self, other, &mut pt)?;
+        transform(other, self, &mut pt)?;
+
+        let (dx, dy) = (pt.0 - lon, pt.1 - lat);
+        if self.is_latlong() {
+            Ok(self.projdata.ellps.a * (adjlon(dx) * lat.cos()).hypot(dy))
+        } else {
+            Ok(dx.hypot(dy) * self.projdata.to_meter)
+        }
+    }
+
+    /// Return the scale factors and distortions of the projection
+    /// at the geographic point `(lon, lat)`, in radians.
+    ///
+    /// Analytic formulas are used when available (i.e. for the exact
+    /// transverse mercator), otherwise the factors are computed by
+    /// numerical differentiation.
+    ///
+    /// Return an error for geographic or geocentric coordinates.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let utm = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
+    ///
+    /// let f = utm.factors(-3f64.to_radians(), 45f64.to_radians()).unwrap();
+    /// assert!((f.parallel_scale - 0.9996).abs() < 1.0e-12);
+    /// ```
+    pub fn factors(&self, lon: f64, lat: f64) -> Result<Factors> {
+        if self.projection_type() != ProjType::Other {
+            return Err(Error::InvalidParameterValue(
+                "No factors for geographic or geocentric coordinates",
+            ));
+        }
+        let d = &self.projdata;
+        let lam = adjlon(lon - d.lam0);
+        self.projection
+            .factors(lam, lat)
+            .unwrap_or_else(|| factors::numeric(&self.projection, &d.ellps, lam, lat))
+    }
+}
+
+//-------------------------
+// Comparison
+//------------------------
+impl Proj {
+    // Test points used for comparing projections parameters,
+    // relative to the central meridian and the latitude of origin.
+    const TEST_POINTS: [(f64, f64); 3] = [(0., 0.), (0.01, 0.01), (-0.02, -0.01)];
+
+    /// Return true if both projections are equivalent within tolerance
+    ///
+    /// Parameters that are not stored in the projection data (i.e
+    /// projection specific parameters) are compared by projecting
+    /// a few test points.
+    pub fn is_equivalent_to(&self, other: &Self) -> bool {
+        const TOL: f64 = 1.0e-10;
+
+        fn eq(a: f64, b: f64, tol: f64) -> bool {
+            a == b || (a - b).abs() <= tol
+        }
+
+        let (d1, d2) = (&self.projdata, &other.projdata);
+
+        self.projection.is_same_projection(&other.projection)
+            && self.geoc == other.geoc
+            && self.over == other.over
+            && self.datum.is_identical_to(&other.datum)
+            && d1.proj_type == d2.proj_type
+            && d1.axis == d2.axis
+            && eq(d1.ellps.a, d2.ellps.a, 1.0e-6)
+            && eq(d1.ellps.es, d2.ellps.es, TOL)
+            && eq(d1.from_greenwich, d2.from_greenwich, TOL)
+            && eq(d1.to_meter, d2.to_meter, TOL)
+            && eq(d1.vto_meter, d2.vto_meter, TOL)
+            && eq(d1.x0, d2.x0, 1.0e-6)
+            && eq(d1.y0, d2.y0, 1.0e-6)
+            && eq(d1.k0, d2.k0, TOL)
+            && eq(d1.lam0, d2.lam0, TOL)
+            && eq(d1.phi0, d2.phi0, TOL)
+            && Self::TEST_POINTS.iter().all(|(lam, phi)| {
+                let phi = phi + d1.phi0;
+                match (
+                    self.projection.forward(*lam, phi, 0.),
+                    other.projection.forward(*lam, phi, 0.),
+                ) {
+                    (Ok((x1, y1, _)), Ok((x2, y2, _))) => eq(x1, x2, TOL) && eq(y1, y2, TOL),
+                    (Err(_), Err(_)) => true,
+                    _ => false,
+                }
+            })
+    }
+}
+
+//-------------------------
+// Serialization
+//------------------------
+impl Proj {
+    /// Return a normalized projstring of the projection
+    ///
+    /// The projstring is rebuilt from the parameters of the definition:
+    /// named definitions (`+init=`) are expanded, UTM and UPS are written
+    /// in their canonical form (see [`GridSystem`]), the default ellipsoid
+    /// is made explicit and the parameters without effect (`+no_defs`,
+    /// `+type=crs`, `+wktext`) are removed. Values with whitespaces
+    /// are quoted.
+    ///
+    /// Parsing the projstring gives an equivalent projection.
+    ///
+    /// ```rust
+    /// use proj4rs::Proj;
+    ///
+    /// let p = Proj::from_proj_string("+proj=utm +zone=31 +south +no_defs").unwrap();
+    /// assert_eq!(p.to_proj_string(), "+proj=utm +zone=31 +south +ellps=WGS84");
+    ///
+    /// let p2 = Proj::from_proj_string(&p.to_proj_string()).unwrap();
+    /// assert!(p.is_equivalent_to(&p2));
+    /// ```
+    pub fn to_proj_string(&self) -> String {
+        const NO_EFFECT: [&str; 3] = ["no_defs", "type", "wktext"];
+        const GRID: [&str; 3] = ["zone", "south", "north"];
+        const ELLPS: [&str; 4] = ["ellps", "datum", "R", "a"];
+
+        let mut s = match self.projdata.grid {
+            Some(grid) => grid.to_string(),
+            None => format!("+proj={}", self.projname),
+        };
+
+        self.params
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.as_str();
+                !(name == "proj"
+                    || name == "init"
+                    || NO_EFFECT.contains(&name)
+                    || (self.projdata.grid.is_some() && GRID.contains(&name)))
+            })
+            .for_each(|(name, value)| match value {
+                // Quote values with whitespaces, i.e grid paths
+                Some(value) if value.contains(char::is_whitespace) => {
+                    s.push_str(&format!(" +{name}=\"{value}\""))
+                }
+                Some(value) => s.push_str(&format!(" +{name}={value}")),
+                None => s.push_str(&format!(" +{name}")),
+            });
+
+        if !self
+            .params
+            .iter()
+            .any(|(name, _)| ELLPS.contains(&name.as_str()))
+        {
+            s.push_str(" +ellps=WGS84");
+        }
+
+        s
     }
 }
 
@@ -436,6 +1037,446 @@ mod tests {
 
     const INVALID_ELLPS: &str = "+proj=latlong +lon_0=5.937 +lat_ts=45.027 +ellps=foo";
 
+    #[test]
+    fn proj_from_params() {
+        use crate::transform::transform;
+
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let from_str = Proj::from_proj_string(concat!(
+            "+proj=lcc +lat_1=49 +lat_2=44 +lat_0=46.5 +lon_0=3 ",
+            "+x_0=700000 +y_0=6600000 +ellps=GRS80 +no_defs"
+        ))
+        .unwrap();
+
+        let params = [
+            ("proj", "lcc"),
+            ("lat_1", "49"),
+            ("lat_2", "44"),
+            ("lat_0", "46.5"),
+            ("+lon_0", "3"),
+            ("x_0", "700000"),
+            ("y_0", "6600000"),
+            ("ellps", "GRS80"),
+            ("no_defs", ""),
+        ];
+        let from_map =
+            Proj::from_params(params.iter().map(|(k, v)| (k.to_string(), v.to_string()))).unwrap();
+
+        assert_eq!(from_map.projname(), "lcc");
+
+        let mut pt1 = (2f64.to_radians(), 48f64.to_radians(), 0.);
+        let mut pt2 = pt1;
+        transform(&geo, &from_str, &mut pt1).unwrap();
+        transform(&geo, &from_map, &mut pt2).unwrap();
+        assert_eq!(pt1, pt2);
+
+        // Missing projection
+        assert!(matches!(
+            Proj::from_params([("ellps".to_string(), "GRS80".to_string())]),
+            Err(Error::MissingProjectionError)
+        ));
+    }
+
+    #[test]
+    fn proj_output_is_angular() {
+        use crate::transform::transform;
+
+        let geo = Proj::from_proj_string("+proj=longlat +R=6371000").unwrap();
+        let eqc = Proj::from_proj_string("+proj=eqc +R=6371000").unwrap();
+        let cart = Proj::from_proj_string("+proj=geocent +R=6371000").unwrap();
+
+        assert!(geo.output_is_angular());
+        assert!(!eqc.output_is_angular());
+        assert!(!cart.output_is_angular());
+        assert_eq!(eqc.units(), "m");
+
+        // eqc output is the longlat output (radians) scaled by the radius
+        let (lam, phi) = (12f64.to_radians(), 47f64.to_radians());
+        let mut pt = (lam, phi, 0.);
+        transform(&geo, &eqc, &mut pt).unwrap();
+        assert!((pt.0 - 6371000. * lam).abs() < 1.0e-6);
+        assert!((pt.1 - 6371000. * phi).abs() < 1.0e-6);
+
+        transform(&eqc, &geo, &mut pt).unwrap();
+        assert!((pt.0 - lam).abs() < 1.0e-12);
+        assert!((pt.1 - phi).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn proj_is_sphere() {
+        let p = Proj::from_proj_string("+proj=latlong +R=6371000").unwrap();
+        assert!(p.is_sphere());
+        assert_eq!(p.semimajor(), 6371000.);
+        assert_eq!(p.semiminor(), 6371000.);
+
+        let p = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+        assert!(!p.is_sphere());
+        assert_eq!(p.semimajor(), 6378137.);
+        assert!((p.semiminor() - 6356752.314245).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn proj_natural_origin_offset() {
+        use crate::transform::transform;
+
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=airy").unwrap();
+
+        // The natural origin of tmerc is projected to (0, 0)
+        let p =
+            Proj::from_proj_string("+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +ellps=airy")
+                .unwrap();
+        let (x_0, y_0) = p.natural_origin_offset(400000., -100000.).unwrap();
+        assert_eq!((x_0, y_0), (400000., -100000.));
+
+        let p = Proj::from_proj_string(&format!(
+            "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +ellps=airy +x_0={x_0} +y_0={y_0}"
+        ))
+        .unwrap();
+        let mut pt = ((-2f64).to_radians(), 49f64.to_radians(), 0.);
+        transform(&geo, &p, &mut pt).unwrap();
+        assert!((pt.0 - 400000.).abs() < 1.0e-6 && (pt.1 + 100000.).abs() < 1.0e-6);
+
+        // Mercator does not use lat_0
+        let p =
+            Proj::from_proj_string("+proj=merc +lat_0=30 +lon_0=10 +ellps=airy +units=km").unwrap();
+        let (x_0, y_0) = p.natural_origin_offset(100., 200.).unwrap();
+        assert!((x_0 - 100000.).abs() < 1.0e-6);
+
+        let p = Proj::from_proj_string(&format!(
+            "+proj=merc +lat_0=30 +lon_0=10 +ellps=airy +units=km +x_0={x_0} +y_0={y_0}"
+        ))
+        .unwrap();
+        let mut pt = (10f64.to_radians(), 30f64.to_radians(), 0.);
+        transform(&geo, &p, &mut pt).unwrap();
+        assert!((pt.0 - 100.).abs() < 1.0e-9 && (pt.1 - 200.).abs() < 1.0e-9);
+
+        // No natural origin for geographic coordinates
+        assert!(geo.natural_origin_offset(0., 0.).is_err());
+    }
+
+    #[test]
+    fn proj_from_str() {
+        let p: Proj = "+proj=lcc +lat_1=33 +lat_2=45 +ellps=GRS80"
+            .parse()
+            .unwrap();
+        assert_eq!(p.projname(), "lcc");
+
+        assert!(matches!(
+            "+proj=foo".parse::<Proj>(),
+            Err(Error::ProjectionNotFound)
+        ));
+    }
+
+    #[test]
+    fn proj_try_from_str() {
+        let p = Proj::try_from("+proj=utm +zone=31 +ellps=GRS80").unwrap();
+        assert_eq!(p.projname(), "utm");
+
+        assert!(matches!(
+            Proj::try_from("+proj=utm +zone=61 +ellps=GRS80"),
+            Err(Error::InvalidUtmZone)
+        ));
+        assert!(matches!(
+            Proj::try_from(INVALID_ELLPS),
+            Err(Error::InvalidEllipsoid)
+        ));
+    }
+
+    #[cfg(feature = "crs-definitions")]
+    #[test]
+    fn proj_try_from_epsg() {
+        let p = Proj::try_from(32631u32).unwrap();
+        assert_eq!(p.projname(), "utm");
+
+        assert!(matches!(
+            Proj::try_from(0u32),
+            Err(Error::ProjectionNotFound)
+        ));
+        assert!(matches!(
+            Proj::try_from(100_000u32),
+            Err(Error::ProjectionNotFound)
+        ));
+    }
+
+    #[test]
+    fn proj_roundtrip_error() {
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+
+        // Well behaved projections
+        for projstr in [
+            "+proj=utm +ellps=GRS80 +zone=31",
+            "+proj=lcc +lat_1=44 +lat_2=49 +lat_0=46.5 +lon_0=3 +ellps=GRS80",
+            "+proj=merc +ellps=GRS80",
+            "+proj=laea +lat_0=52 +lon_0=10 +R=6400000",
+        ] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let err = geo
+                .roundtrip_error(&p, 3f64.to_radians(), 47f64.to_radians())
+                .unwrap();
+            assert!(err < 1.0e-6, "{projstr}: {err}");
+
+            // From projected coordinates
+            let err = p.roundtrip_error(&geo, 500000., 5200000.).unwrap();
+            assert!(err < 1.0e-6, "{projstr} inverse: {err}");
+        }
+
+        // Ellipsoidal laea is limited by the accuracy of the
+        // authalic latitude series
+        let p = Proj::from_proj_string("+proj=laea +lat_0=52 +lon_0=10 +ellps=GRS80").unwrap();
+        let err = geo
+            .roundtrip_error(&p, 3f64.to_radians(), 47f64.to_radians())
+            .unwrap();
+        assert!(err < 1.0e-3, "laea: {err}");
+
+        // The approximate transverse mercator algorithm is not
+        // accurate far from the central meridian.
+        let p = Proj::from_proj_string("+proj=tmerc +approx +ellps=GRS80").unwrap();
+        let err = geo
+            .roundtrip_error(&p, 40f64.to_radians(), 30f64.to_radians())
+            .unwrap();
+        assert!(err > 1.0e-3, "tmerc approx: {err}");
+    }
+
+    #[test]
+    fn proj_is_equivalent_to() {
+        let p1 = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+        let p2 =
+            Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84 +units=m +no_defs +type=crs")
+                .unwrap();
+        let p3 = Proj::from_proj_string("+proj=utm +zone=32 +datum=WGS84").unwrap();
+        let p4 = Proj::from_proj_string("+proj=lcc +lat_1=49 +lat_2=44 +datum=WGS84").unwrap();
+        let p5 = Proj::from_proj_string("+proj=lcc +lat_1=49 +lat_2=45 +datum=WGS84").unwrap();
+
+        assert!(p1.is_equivalent_to(&p2));
+        assert!(!p1.is_equivalent_to(&p3));
+        assert!(!p1.is_equivalent_to(&p4));
+        assert!(p4.is_equivalent_to(&p4.clone()));
+        // Projection specific parameters
+        assert!(!p4.is_equivalent_to(&p5));
+    }
+
+    #[cfg(feature = "crs-definitions")]
+    #[test]
+    fn proj_equivalent_epsg() {
+        let p = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+        assert_eq!(p.equivalent_epsg(), Some(32631));
+    }
+
+    #[cfg(feature = "epsg")]
+    #[test]
+    fn proj_equivalent_epsg_compiled_in() {
+        // The code the projection was created from
+        let p = Proj::from_epsg(3857).unwrap();
+        assert_eq!(p.equivalent_epsg(), Some(3857));
+
+        let p = Proj::from_proj_string("+proj=utm +zone=33 +south +datum=WGS84").unwrap();
+        assert_eq!(p.equivalent_epsg(), Some(32733));
+        let p = Proj::from_proj_string(concat!(
+            "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000",
+            " +ellps=GRS80 +towgs84=0,0,0,0,0,0,0",
+        ))
+        .unwrap();
+        assert_eq!(p.equivalent_epsg(), Some(3035));
+
+        #[cfg(not(feature = "crs-definitions"))]
+        {
+            let p = Proj::from_proj_string("+proj=utm +zone=31 +ellps=intl").unwrap();
+            assert_eq!(p.equivalent_epsg(), None);
+        }
+    }
+
+    #[test]
+    fn proj_params() {
+        let p = Proj::from_proj_string("+proj=lcc +lat_1=0.5 +lat_2=2 +no_defs").unwrap();
+        let params: Vec<_> = p.params().collect();
+        assert_eq!(
+            params,
+            [
+                ("proj", Some("lcc")),
+                ("lat_1", Some("0.5")),
+                ("lat_2", Some("2")),
+                ("no_defs", None),
+            ]
+        );
+
+        // Parameters of named definitions
+        let p = Proj::from_proj_string("+init=bng +x_0=0").unwrap();
+        assert!(p.params().any(|p| p == ("ellps", Some("airy"))));
+        assert_eq!(
+            p.params().find(|(name, _)| *name == "x_0"),
+            Some(("x_0", Some("0")))
+        );
+    }
+
+    #[test]
+    fn proj_to_proj_string() {
+        use crate::transform::transform;
+
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+
+        for projstr in [
+            concat!(
+                "+proj=lcc +lat_1=49 +lat_2=44 +lat_0=46.5 +lon_0=3 ",
+                "+x_0=700000 +y_0=6600000 +ellps=GRS80 +towgs84=0,0,0 +units=m +no_defs",
+            ),
+            "+proj=utm +zone=58 +south +datum=WGS84 +type=crs",
+            "+proj=tmerc +lon_0=9 +k=0.9996 +x_0=500000 +a=6378388 +rf=297 +units=us-ft",
+            "+proj=cea +lat_ts=30 +R=6400000",
+            "+proj=krovak +czech",
+            "+proj=ups +south",
+            "+init=bng",
+        ] {
+            let p1 = Proj::from_proj_string(projstr).unwrap();
+            let s = p1.to_proj_string();
+            assert!(
+                !s.contains("+init") && !s.contains("+no_defs") && !s.contains("+type"),
+                "{projstr} -> {s}"
+            );
+
+            let p2 = Proj::from_proj_string(&s).unwrap();
+            assert!(p1.is_equivalent_to(&p2), "{projstr} -> {s}");
+
+            let mut pt1 = (2f64.to_radians(), 48f64.to_radians(), 0.);
+            if p1.grid_system() == Some(GridSystem::Ups { south: true }) {
+                pt1.1 = -pt1.1;
+            }
+            let mut pt2 = pt1;
+            transform(&geo, &p1, &mut pt1).unwrap();
+            transform(&geo, &p2, &mut pt2).unwrap();
+            assert_eq!(pt1, pt2);
+
+            // Serialization is stable
+            assert_eq!(p2.to_proj_string(), s);
+        }
+
+        let p = Proj::from_proj_string("+proj=utm +zone=31").unwrap();
+        assert_eq!(p.to_proj_string(), "+proj=utm +zone=31 +north +ellps=WGS84");
+
+        // Quoted values
+        let p = Proj::from_proj_string(
+            "+proj=latlong +ellps=GRS80 +nadgrids=\"@path with spaces.gsb,@null\"",
+        )
+        .unwrap();
+        let s = p.to_proj_string();
+        assert_eq!(
+            s,
+            "+proj=latlong +ellps=GRS80 +nadgrids=\"@path with spaces.gsb,@null\""
+        );
+        assert_eq!(Proj::from_proj_string(&s).unwrap().to_proj_string(), s);
+    }
+
+    #[cfg(feature = "epsg")]
+    #[test]
+    fn proj_from_epsg() {
+        use crate::transform::transform;
+
+        let geo = Proj::from_epsg(4326).unwrap();
+        let webmerc = Proj::from_proj_string("+proj=webmerc +datum=WGS84").unwrap();
+        let epsg = Proj::from_epsg(3857).unwrap();
+
+        for (lon, lat) in [(2f64, 48f64), (-120., -60.), (179., 85.)] {
+            let mut expected = (lon.to_radians(), lat.to_radians(), 0.);
+            transform(&geo, &webmerc, &mut expected).unwrap();
+            let mut pt = (lon.to_radians(), lat.to_radians(), 0.);
+            transform(&geo, &epsg, &mut pt).unwrap();
+            approx::assert_abs_diff_eq!(pt.0, expected.0, epsilon = 1.0e-8);
+            approx::assert_abs_diff_eq!(pt.1, expected.1, epsilon = 1.0e-8);
+        }
+
+        for code in [
+            4258, 4269, 4171, 2154, 3395, 3035, 27700, 28992, 32631, 32760,
+        ] {
+            assert!(Proj::from_epsg(code).is_ok(), "EPSG:{code}");
+        }
+        let p = Proj::from_epsg(32631).unwrap();
+        assert_eq!(
+            p.grid_system().unwrap().to_string(),
+            "+proj=utm +zone=31 +north"
+        );
+        assert_eq!(p.area_of_use(), Some((0., 0., 6., 84.)));
+
+        for code in [0, 3034, 32600, 100_000] {
+            assert!(matches!(
+                Proj::from_epsg(code),
+                Err(Error::ProjectionNotFound)
+            ));
+        }
+    }
+
+    #[cfg(feature = "crs-definitions")]
+    #[test]
+    fn proj_area_of_use() {
+        // Metropolitan France
+        let p = Proj::from_epsg_code(2154).unwrap();
+        assert_eq!(p.area_of_use(), Some((-9.86, 41.15, 10.38, 51.56)));
+
+        // Not created from an EPSG code
+        let p = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+        assert_eq!(p.area_of_use(), None);
+    }
+
+    #[test]
+    fn proj_grid_system() {
+        let p = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+        assert_eq!(
+            p.grid_system().unwrap().to_string(),
+            "+proj=utm +zone=31 +north"
+        );
+
+        let p = Proj::from_proj_string("+proj=utm +zone=58 +south +ellps=GRS80").unwrap();
+        assert_eq!(
+            p.grid_system(),
+            Some(GridSystem::Utm {
+                zone: 58,
+                south: true
+            })
+        );
+
+        // Zone derived from lon_0
+        let p = Proj::from_proj_string("+proj=utm +lon_0=3 +datum=WGS84").unwrap();
+        assert_eq!(
+            p.grid_system().unwrap().to_string(),
+            "+proj=utm +zone=31 +north"
+        );
+
+        let p = Proj::from_proj_string("+proj=ups +south +datum=WGS84").unwrap();
+        assert_eq!(p.grid_system().unwrap().to_string(), "+proj=ups +south");
+
+        // The expanded definition is not a grid system
+        let p = Proj::from_proj_string("+proj=tmerc +lon_0=3 +k=0.9996 +x_0=500000 +datum=WGS84")
+            .unwrap();
+        assert_eq!(p.grid_system(), None);
+    }
+
+    #[test]
+    fn proj_ellps_from_datum() {
+        use crate::tests::utils::test_proj_forward;
+
+        // The ellipsoid is inferred from the datum
+        let p = Proj::from_proj_string("+proj=utm +zone=10 +datum=NAD83").unwrap();
+        let grs80 = Proj::from_proj_string("+proj=utm +zone=10 +ellps=GRS80").unwrap();
+        let wgs84 = Proj::from_proj_string("+proj=utm +zone=10 +ellps=WGS84").unwrap();
+
+        assert_eq!(p.semimajor(), grs80.semimajor());
+        assert_eq!(p.semiminor(), grs80.semiminor());
+        assert_ne!(p.semiminor(), wgs84.semiminor());
+
+        // Same as proj for `+proj=utm +ellps=GRS80 +zone=30`,
+        // 5 degrees east of the central meridian
+        test_proj_forward(
+            &p,
+            &[(
+                (-118., 1., 0.),
+                (1057002.4054912976, 110955.14117594929, 0.),
+            )],
+            1.0e-6,
+        );
+
+        // An explicit ellipsoid has precedence over the datum
+        let p = Proj::from_proj_string("+proj=utm +zone=10 +datum=NAD83 +ellps=WGS84").unwrap();
+        assert_eq!(p.semiminor(), wgs84.semiminor());
+    }
+
     #[test]
     fn proj_invalid_ellps_param() {
         let p: Result<Proj> = Proj::from_proj_string(INVALID_ELLPS);
@@ -9,20 +9,27 @@
 //
 use crate::errors::{Error, Result};
 use crate::math::{
-    consts::{EPS_10, EPS_7, FRAC_PI_2},
+    consts::{EPS_10, EPS_7, FRAC_PI_2, PI},
     msfn, qsfn,
 };
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
 // Projection stub
-super::projection! { aea, leac }
+super::projection! { aea, leac; inverse_valid_domain }
 
 const PHI_NITER: usize = 15;
 
 // determine latitude angle phi1
+//
+// Iterations are capped to PHI_NITER: return an error
+// if the computation does not converge.
 #[inline]
 fn phi1_inv(qs: f64, e: f64, one_es: f64) -> Result<f64> {
+    // Out of domain: this would lead to NaN values
+    if qs.is_nan() || (0.5 * qs).abs() > 1. {
+        return Err(Error::NonInvPhi1Convergence);
+    }
     let mut phi = (0.5 * qs).asin();
     if e < EPS_7 {
         Ok(phi)
@@ -43,8 +50,8 @@ fn phi1_inv(qs: f64, e: f64, one_es: f64) -> Result<f64> {
 
             i -= 1;
         }
-        if i == 0 {
-            Err(Error::ToleranceConditionError)
+        if i == 0 || phi.is_nan() {
+            Err(Error::NonInvPhi1Convergence)
         } else {
             Ok(phi)
         }
@@ -162,6 +169,10 @@ impl Projection {
                 xx = -xx;
                 yy = -yy;
             }
+            let theta = xx.atan2(yy);
+            if theta.abs() > self.n.abs() * PI + EPS_10 {
+                return Err(Error::PointBeyondConeApex);
+            }
             let mut phi = rho / self.dd;
             if self.is_ellipse() {
                 phi = (self.c - phi * phi) / self.n;
@@ -182,12 +193,36 @@ impl Projection {
                     FRAC_PI_2
                 }
             }
-            Ok((xx.atan2(yy) / self.n, phi, z))
+            Ok((theta / self.n, phi, z))
         } else {
             Ok((0., if self.n > 0. { FRAC_PI_2 } else { -FRAC_PI_2 }, z))
         }
     }
 
+    /// Check that (x, y) is in the image of the cone: a point
+    /// on the wrong side of the apex or beyond the poles
+    /// cannot be inverted.
+    pub fn inverse_valid_domain(&self, x: f64, y: f64) -> bool {
+        let (mut x, mut y) = (x, self.rho0 - y);
+        let rho = x.hypot(y);
+        if rho == 0. {
+            return true;
+        }
+        if self.n < 0. {
+            x = -x;
+            y = -y;
+        }
+        if x.atan2(y).abs() > self.n.abs() * PI + EPS_10 {
+            return false;
+        }
+        let phi = rho / self.dd;
+        if self.is_ellipse() {
+            ((self.c - phi * phi) / self.n).abs() <= self.ec + EPS_7
+        } else {
+            ((self.c - phi * phi) / self.n2).abs() <= 1. + EPS_10
+        }
+    }
+
     pub const fn has_inverse() -> bool {
         true
     }
@@ -225,9 +260,11 @@ impl Projection {
 
 #[cfg(test)]
 mod tests {
-    use crate::math::consts::EPS_10;
+    use crate::errors::Error;
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use approx::assert_abs_diff_eq;
 
     #[test]
     fn proj_aea_aea_ellipsoidal() {
@@ -237,11 +274,11 @@ mod tests {
 
         let inputs = [
             ((2., 1., 0.), (222571.60875710563, 110653.32674302977, 0.)),
-            ((2., -1., 0.), (222706.30650839131, -110484.26714439997, 0.)),
+            ((2., -1., 0.), (222706.3065083913, -110484.26714439997, 0.)),
             ((-2., 1., 0.), (-222571.60875710563, 110653.32674302977, 0.)),
             (
                 (-2., -1., 0.),
-                (-222706.30650839131, -110484.26714439997, 0.),
+                (-222706.3065083913, -110484.26714439997, 0.),
             ),
         ];
 
@@ -249,6 +286,62 @@ mod tests {
         test_proj_inverse(&p, &inputs, EPS_10);
     }
 
+    #[test]
+    fn proj_aea_conus() {
+        // EPSG:5070 - NAD83 / Conus Albers
+        let p = Proj::from_proj_string(concat!(
+            "+proj=aea +lat_0=23 +lon_0=-96 +lat_1=29.5 +lat_2=45.5 ",
+            "+x_0=0 +y_0=0 +ellps=GRS80"
+        ))
+        .unwrap();
+
+        let inputs = [
+            ((-96., 23., 0.), (0., 0., 0.)),
+            (
+                (-77., 38.9, 0.),
+                (1621622.6721282322, 1926349.557437066, 0.),
+            ),
+            (
+                (-122.4, 37.8, 0.),
+                (-2273032.7875102814, 1958176.3912927485, 0.),
+            ),
+            (
+                (-80.2, 25.8, 0.),
+                (1592562.790432701, 438476.4996621219, 0.),
+            ),
+            ((-68., 47., 0.), (2108589.831937593, 2980588.533907833, 0.)),
+            (
+                (-110., 40., 0.),
+                (-1180436.3071451888, 1974084.287263782, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_aea_inverse_convergence() {
+        let p = Proj::from_proj_string("+proj=aea +ellps=GRS80 +lat_1=29.5 +lat_2=45.5").unwrap();
+
+        let pp = p.projection();
+
+        // South pole
+        let (_, y_s, _) = pp.forward(0., -FRAC_PI_2, 0.).unwrap();
+        let (_, phi, _) = pp.inverse(0., y_s, 0.).unwrap();
+        assert_abs_diff_eq!(phi, -FRAC_PI_2, epsilon = 1.0e-6);
+
+        // Beyond the south pole
+        assert!(matches!(
+            pp.inverse(0., 2. * y_s, 0.),
+            Err(Error::NonInvPhi1Convergence)
+        ));
+        assert!(matches!(
+            pp.inverse(1.0e-3, 2. * y_s, 0.),
+            Err(Error::NonInvPhi1Convergence)
+        ));
+    }
+
     #[test]
     fn proj_aea_aea_spherical() {
         let p = Proj::from_proj_string("+proj=aea +R=6400000 +lat_1=0 +lat_2=2").unwrap();
@@ -277,11 +370,11 @@ mod tests {
 
         let inputs = [
             ((2., 1., 0.), (220685.14054297868, 112983.50088939646, 0.)),
-            ((2., -1., 0.), (224553.31227982609, -108128.63674487274, 0.)),
+            ((2., -1., 0.), (224553.3122798261, -108128.63674487274, 0.)),
             ((-2., 1., 0.), (-220685.14054297868, 112983.50088939646, 0.)),
             (
                 (-2., -1., 0.),
-                (-224553.31227982609, -108128.63674487274, 0.),
+                (-224553.3122798261, -108128.63674487274, 0.),
             ),
         ];
 
@@ -309,4 +402,27 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_aea_beyond_apex() {
+        for projstr in [
+            "+proj=aea +lat_1=29.5 +lat_2=45.5 +ellps=GRS80",
+            "+proj=aea +lat_1=29.5 +lat_2=45.5 +R=6400000",
+        ] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let (_, y_pole, _) = p.projection().forward(0., FRAC_PI_2, 0.).unwrap();
+
+            // On the cone
+            assert!(p.projection().inverse(0.1, y_pole - 0.1, 0.).is_ok());
+            // Beyond the apex
+            assert!(matches!(
+                p.projection().inverse(0., 10., 0.),
+                Err(Error::PointBeyondConeApex)
+            ));
+            assert!(matches!(
+                p.projection().inverse(0.5, 10., 0.),
+                Err(Error::PointBeyondConeApex)
+            ));
+        }
+    }
 }
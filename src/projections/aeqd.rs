@@ -0,0 +1,396 @@
+//!
+//! Azimuthal Equidistant
+//!
+//! ref: <https://proj.org/operations/projections/aeqd.html>
+//!
+//! aeqd: "Azimuthal Equidistant" "\n\tAzi, Sph&Ell\n\tlat_0 guam"
+//!
+//! The `+guam` flag selects the approximate ellipsoidal formulation
+//! used for the Guam 1963 datum (EPSG:3993).
+//!
+use crate::errors::{Error, Result};
+use crate::math::{
+    aasin,
+    consts::{EPS_10, FRAC_PI_2, PI},
+    enfn, geodesic_direct, geodesic_inverse, inv_mlfn, mlfn, Enfn,
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { aeqd }
+
+const TOL: f64 = 1.0e-14;
+
+#[allow(non_camel_case_types)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    N_POLE,
+    S_POLE,
+    EQUIT,
+    OBLIQ,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    phi0: f64,
+    sinph0: f64,
+    cosph0: f64,
+    mode: Mode,
+    guam: bool,
+    // Ellipsoid
+    e: f64,
+    es: f64,
+    f: f64,
+    en: Enfn,
+    m1: f64,
+    mp: f64,
+}
+
+impl Projection {
+    pub fn aeqd(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let phi0 = p.phi0;
+
+        let (mode, sinph0, cosph0) = if (phi0.abs() - FRAC_PI_2).abs() < EPS_10 {
+            if phi0 < 0. {
+                (Mode::S_POLE, -1., 0.)
+            } else {
+                (Mode::N_POLE, 1., 0.)
+            }
+        } else if phi0.abs() < EPS_10 {
+            (Mode::EQUIT, 0., 1.)
+        } else {
+            let (sinph0, cosph0) = phi0.sin_cos();
+            (Mode::OBLIQ, sinph0, cosph0)
+        };
+
+        let es = p.ellps.es;
+        let en = enfn(es);
+        let guam = es != 0. && params.check_option("guam")?;
+
+        let (m1, mp) = if guam {
+            (mlfn(phi0, sinph0, cosph0, en), 0.)
+        } else {
+            match mode {
+                Mode::N_POLE => (0., mlfn(FRAC_PI_2, 1., 0., en)),
+                Mode::S_POLE => (0., mlfn(-FRAC_PI_2, -1., 0., en)),
+                Mode::EQUIT | Mode::OBLIQ => (0., 0.),
+            }
+        };
+
+        Ok(Self {
+            phi0,
+            sinph0,
+            cosph0,
+            mode,
+            guam,
+            e: p.ellps.e,
+            es,
+            f: p.ellps.f,
+            en,
+            m1,
+            mp,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.es == 0. {
+            self.s_forward(lam, phi, z)
+        } else if self.guam {
+            self.guam_forward(lam, phi, z)
+        } else {
+            self.e_forward(lam, phi, z)
+        }
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.es == 0. {
+            self.s_inverse(x, y, z)
+        } else if self.guam {
+            self.guam_inverse(x, y, z)
+        } else {
+            self.e_inverse(x, y, z)
+        }
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+
+    //
+    // Sphere
+    //
+
+    fn s_forward(&self, lam: f64, mut phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, mut coslam) = lam.sin_cos();
+
+        match self.mode {
+            Mode::EQUIT | Mode::OBLIQ => {
+                let cosc = if self.mode == Mode::EQUIT {
+                    cosphi * coslam
+                } else {
+                    self.sinph0 * sinphi + self.cosph0 * cosphi * coslam
+                };
+                if (cosc.abs() - 1.).abs() < TOL {
+                    if cosc < 0. {
+                        // Antipode of the center
+                        return Err(Error::ToleranceConditionError);
+                    }
+                    return Ok((0., 0., z));
+                }
+                let c = cosc.acos();
+                let k = c / c.sin();
+                let y = if self.mode == Mode::EQUIT {
+                    sinphi
+                } else {
+                    self.cosph0 * sinphi - self.sinph0 * cosphi * coslam
+                };
+                Ok((k * cosphi * sinlam, k * y, z))
+            }
+            Mode::N_POLE | Mode::S_POLE => {
+                if self.mode == Mode::N_POLE {
+                    phi = -phi;
+                    coslam = -coslam;
+                }
+                if (phi - FRAC_PI_2).abs() < EPS_10 {
+                    return Err(Error::ToleranceConditionError);
+                }
+                let rho = FRAC_PI_2 + phi;
+                Ok((rho * sinlam, rho * coslam, z))
+            }
+        }
+    }
+
+    fn s_inverse(&self, mut x: f64, mut y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let mut c_rh = x.hypot(y);
+        if c_rh > PI {
+            if c_rh - EPS_10 > PI {
+                return Err(Error::ToleranceConditionError);
+            }
+            c_rh = PI;
+        } else if c_rh < EPS_10 {
+            return Ok((0., self.phi0, z));
+        }
+
+        match self.mode {
+            Mode::EQUIT | Mode::OBLIQ => {
+                let (sinc, cosc) = c_rh.sin_cos();
+                let phi = if self.mode == Mode::EQUIT {
+                    let phi = aasin(y * sinc / c_rh)?;
+                    x *= sinc;
+                    y = cosc * c_rh;
+                    phi
+                } else {
+                    let phi = aasin(cosc * self.sinph0 + y * sinc * self.cosph0 / c_rh)?;
+                    y = (cosc - self.sinph0 * phi.sin()) * c_rh;
+                    x *= sinc * self.cosph0;
+                    phi
+                };
+                let lam = if y == 0. { 0. } else { x.atan2(y) };
+                Ok((lam, phi, z))
+            }
+            Mode::N_POLE => Ok((x.atan2(-y), FRAC_PI_2 - c_rh, z)),
+            Mode::S_POLE => Ok((x.atan2(y), c_rh - FRAC_PI_2, z)),
+        }
+    }
+
+    //
+    // Ellipsoid
+    //
+
+    fn e_forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        match self.mode {
+            Mode::N_POLE | Mode::S_POLE => {
+                let (sinphi, cosphi) = phi.sin_cos();
+                let (sinlam, mut coslam) = lam.sin_cos();
+                if self.mode == Mode::N_POLE {
+                    coslam = -coslam;
+                }
+                let rho = (self.mp - mlfn(phi, sinphi, cosphi, self.en)).abs();
+                Ok((rho * sinlam, rho * coslam, z))
+            }
+            Mode::EQUIT | Mode::OBLIQ => {
+                if lam.abs() < EPS_10 && (phi - self.phi0).abs() < EPS_10 {
+                    return Ok((0., 0., z));
+                }
+                // Fail on non-convergence near the antipode
+                let (s12, azi1) = geodesic_inverse(self.f, 0., self.phi0, lam, phi)?;
+                let (sinaz, cosaz) = azi1.sin_cos();
+                Ok((s12 * sinaz, s12 * cosaz, z))
+            }
+        }
+    }
+
+    fn e_inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let c = x.hypot(y);
+        if c < EPS_10 {
+            return Ok((0., self.phi0, z));
+        }
+        // Beyond the antipode
+        if c - EPS_10 > PI {
+            return Err(Error::ToleranceConditionError);
+        }
+
+        match self.mode {
+            Mode::EQUIT | Mode::OBLIQ => {
+                let (lam, phi) = geodesic_direct(self.f, 0., self.phi0, x.atan2(y), c)?;
+                Ok((lam, phi, z))
+            }
+            Mode::N_POLE => Ok((x.atan2(-y), inv_mlfn(self.mp - c, self.es, self.en)?, z)),
+            Mode::S_POLE => Ok((x.atan2(y), inv_mlfn(self.mp + c, self.es, self.en)?, z)),
+        }
+    }
+
+    //
+    // Guam
+    //
+
+    fn guam_forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let t = 1. / (1. - self.es * sinphi * sinphi).sqrt();
+        Ok((
+            lam * cosphi * t,
+            mlfn(phi, sinphi, cosphi, self.en) - self.m1 + 0.5 * lam * lam * cosphi * sinphi * t,
+            z,
+        ))
+    }
+
+    fn guam_inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let x2 = 0.5 * x * x;
+        let mut phi = self.phi0;
+        let mut t = 0.;
+        for _ in 0..3 {
+            t = self.e * phi.sin();
+            t = (1. - t * t).sqrt();
+            phi = inv_mlfn(self.m1 + y - x2 * phi.tan() * t, self.es, self.en)?;
+        }
+        Ok((x * t / phi.cos(), phi, z))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_aeqd_el() {
+        let p = Proj::from_proj_string("+proj=aeqd +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222616.52219005165, 110596.9965495502, 0.)),
+            ((2., -1., 0.), (222616.52219005165, -110596.99654955021, 0.)),
+            ((-2., 1., 0.), (-222616.52219005165, 110596.9965495502, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222616.52219005165, -110596.99654955021, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+    }
+
+    #[test]
+    fn proj_aeqd_sp() {
+        let p = Proj::from_proj_string("+proj=aeqd +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223379.456047271, 111723.757570854, 0.)),
+            ((2., -1., 0.), (223379.456047271, -111723.757570854, 0.)),
+            ((-2., 1., 0.), (-223379.456047271, 111723.757570854, 0.)),
+            ((-2., -1., 0.), (-223379.456047271, -111723.757570854, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_aeqd_pole() {
+        let p = Proj::from_proj_string("+proj=aeqd +lat_0=90 +ellps=WGS84").unwrap();
+
+        let inputs = [((0., 80., 0.), (0., -1116825.85737585, 0.))];
+
+        // Meridian arc from 80°N to the pole
+        test_proj_forward(&p, &inputs, 1.0e-3);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_aeqd_guam() {
+        let p = Proj::from_proj_string(concat!(
+            "+proj=aeqd +lat_0=13.47246635277778 +lon_0=144.7487507055556",
+            " +x_0=50000 +y_0=50000 +ellps=clrk66 +guam"
+        ))
+        .unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (144.7487507055556, 13.47246635277778, 0.),
+                (50000., 50000., 0.),
+            ),
+            // EPSG guidance note 7-2, §3.3.6 (Guam projection)
+            (
+                (144.635331291667, 13.33903846111111, 0.),
+                (37712.48, 35242.00, 0.),
+            ),
+        ];
+
+        // Published values are rounded to the centimeter
+        test_proj_forward(&p, &inputs, 1.0e-2);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    fn proj_aeqd_antipode() {
+        let p = Proj::from_proj_string("+proj=aeqd +R=6400000").unwrap();
+        assert!(matches!(
+            p.projection().forward(180f64.to_radians(), 0., 0.),
+            Err(Error::ToleranceConditionError)
+        ));
+    }
+
+    #[test]
+    fn proj_aeqd_100km_north() {
+        let p = Proj::from_proj_string("+proj=aeqd +lat_0=45 +lon_0=10 +datum=WGS84").unwrap();
+
+        // Meridian arc of 100 km from 45°N
+        let inputs = [((10., 45.8997614533616, 0.), (0., 100000., 0.))];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_aeqd_el_antipode() {
+        let p = Proj::from_proj_string("+proj=aeqd +ellps=WGS84").unwrap();
+        let pp = p.projection();
+
+        // Vincenty iterations do not converge
+        assert!(matches!(
+            pp.forward(180f64.to_radians(), 0., 0.),
+            Err(Error::ToleranceConditionError)
+        ));
+        assert!(matches!(
+            pp.inverse(3.2, 0., 0.),
+            Err(Error::ToleranceConditionError)
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+//!
+//! Apian Globular I, Ortelius Oval and Bacon Globular
+//!
+//! ref: <https://proj.org/operations/projections/apian.html>
+//!
+//! apian: "Apian Globular I" "\n\tMisc Sph, no inv"
+//! ortel: "Ortelius Oval" "\n\tMisc Sph, no inv"
+//! bacon: "Bacon Globular" "\n\tMisc Sph, no inv"
+//!
+//! Meridians are circular arcs. Ortelius and Bacon use straight
+//! parallels beyond 90° of longitude from the central meridian.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::FRAC_PI_2;
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { bacon, apian, ortel }
+
+/// (π/2)²
+const HLFPI2: f64 = FRAC_PI_2 * FRAC_PI_2;
+const EPS: f64 = 1.0e-10;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    bacn: bool,
+    ortl: bool,
+}
+
+impl Projection {
+    fn init(p: &mut ProjData, bacn: bool, ortl: bool) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self { bacn, ortl })
+    }
+
+    pub fn bacon(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::init(p, true, true)
+    }
+
+    pub fn apian(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::init(p, false, false)
+    }
+
+    pub fn ortel(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::init(p, false, true)
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let y = if self.bacn {
+            FRAC_PI_2 * phi.sin()
+        } else {
+            phi
+        };
+        let ax = lam.abs();
+        let x = if ax < EPS {
+            0.
+        } else if self.ortl && ax >= FRAC_PI_2 {
+            (HLFPI2 - phi * phi + EPS).sqrt() + ax - FRAC_PI_2
+        } else {
+            let f = 0.5 * (HLFPI2 / ax + ax);
+            ax - f + (f * f - y * y).sqrt()
+        };
+        Ok((x.copysign(lam), y, z))
+    }
+
+    pub fn inverse(&self, _: f64, _: f64, _: f64) -> Result<(f64, f64, f64)> {
+        Err(Error::NoInverseProjectionDefined)
+    }
+
+    pub const fn has_inverse() -> bool {
+        false
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::test_proj_forward;
+
+    #[test]
+    fn proj_apian() {
+        let p = Proj::from_proj_string("+proj=apian +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223374.57735524187, 111701.07212763709, 0.)),
+            ((2., -1., 0.), (223374.57735524187, -111701.07212763709, 0.)),
+            ((-2., 1., 0.), (-223374.57735524187, 111701.07212763709, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223374.57735524187, -111701.07212763709, 0.),
+            ),
+            ((30., 45., 0.), (2579276.076111765, 5026548.24574367, 0.)),
+            (
+                (-120., -60., 0.),
+                (-10978556.369087188, -6702064.327658226, 0.),
+            ),
+            ((0., 30., 0.), (0., 3351032.163829113, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    fn proj_ortel() {
+        let p = Proj::from_proj_string("+proj=ortel +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223374.57735524187, 111701.07212763709, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223374.57735524187, -111701.07212763709, 0.),
+            ),
+            ((30., 45., 0.), (2579276.076111765, 5026548.24574367, 0.)),
+            (
+                (-120., -60., 0.),
+                (-10844167.877212537, -6702064.327658226, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    fn proj_bacon() {
+        let p = Proj::from_proj_string("+proj=bacon +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223334.13255599551, 175450.7259226659, 0.)),
+            ((2., -1., 0.), (223334.13255599551, -175450.7259226659, 0.)),
+            ((-2., 1., 0.), (-223334.13255599551, 175450.7259226659, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223334.13255599551, -175450.7259226659, 0.),
+            ),
+            ((30., 45., 0.), (1768314.771562857, 7108612.701053386, 0.)),
+            (
+                (-120., -60., 0.),
+                (-10844167.877212537, -8706236.948324246, 0.),
+            ),
+            ((0., 30., 0.), (0., 5026548.24574367, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        assert!(p.projection().inverse(0., 0., 0.).is_err());
+    }
+}
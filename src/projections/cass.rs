@@ -0,0 +1,224 @@
+//!
+//! Cassini-Soldner
+//!
+//! ref: <https://proj.org/operations/projections/cass.html>
+//!
+//! cass: "Cassini" "\n\tCyl, Sph&Ell"
+//!
+//! The ellipsoidal formulation is a series expansion, accurate
+//! within a few degrees of the central meridian.
+//!
+//! Points at 90° from the central meridian are mapped to the
+//! same location whatever their latitude: the inverse projection
+//! is ambiguous there and returns [`Error::CoordinateOutOfRange`].
+//!
+use crate::errors::{Error, Result};
+use crate::math::{
+    consts::{EPS_10, FRAC_PI_2},
+    enfn, inv_mlfn, mlfn, Enfn,
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { cass }
+
+const C1: f64 = 1. / 6.;
+const C2: f64 = 1. / 120.;
+const C3: f64 = 1. / 24.;
+const C4: f64 = 1. / 3.;
+const C5: f64 = 1. / 15.;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Projection {
+    Ell { es: f64, en: Enfn, m0: f64 },
+    Sph { phi0: f64 },
+}
+
+use Projection::*;
+
+impl Projection {
+    pub fn cass(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        let es = p.ellps.es;
+        if es != 0. {
+            let en = enfn(es);
+            Ok(Ell {
+                es,
+                en,
+                m0: mlfn(p.phi0, p.phi0.sin(), p.phi0.cos(), en),
+            })
+        } else {
+            Ok(Sph { phi0: p.phi0 })
+        }
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        match self {
+            Ell { es, en, m0 } => {
+                let (sinphi, cosphi) = phi.sin_cos();
+                let n = 1. / (1. - es * sinphi * sinphi).sqrt();
+                let tn = phi.tan();
+                let t = tn * tn;
+                let a1 = lam * cosphi;
+                let c = es * cosphi * cosphi / (1. - es);
+                let a2 = a1 * a1;
+                Ok((
+                    n * a1 * (1. - a2 * t * (C1 + (8. - t + 8. * c) * a2 * C2)),
+                    mlfn(phi, sinphi, cosphi, *en) - m0
+                        + n * tn * a2 * (0.5 + (5. - t + 6. * c) * a2 * C3),
+                    z,
+                ))
+            }
+            Sph { phi0 } => Ok((
+                (phi.cos() * lam.sin()).asin(),
+                phi.tan().atan2(lam.cos()) - phi0,
+                z,
+            )),
+        }
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (lam, phi) = match self {
+            Ell { es, en, m0 } => {
+                let ph1 = inv_mlfn(m0 + y, *es, *en)?;
+                let tn = ph1.tan();
+                let t = tn * tn;
+                let sinph1 = ph1.sin();
+                let r = 1. / (1. - es * sinph1 * sinph1);
+                let n = r.sqrt();
+                let r = r * (1. - es) * n;
+                let dd = x / n;
+                let d2 = dd * dd;
+                let lam = dd * (1. + t * d2 * (-C4 + (1. + 3. * t) * d2 * C5)) / ph1.cos();
+                // The series does not tell apart the hemispheres
+                // beyond 90° from the central meridian
+                if lam.abs() >= FRAC_PI_2 {
+                    return Err(Error::CoordinateOutOfRange);
+                }
+                (
+                    lam,
+                    ph1 - (n * tn / r) * d2 * (0.5 - (1. + 3. * t) * d2 * C3),
+                )
+            }
+            Sph { phi0 } => {
+                // The meridian at 90° from the central meridian
+                // is mapped to a single point
+                if x.abs() > FRAC_PI_2 - EPS_10 {
+                    return Err(Error::CoordinateOutOfRange);
+                }
+                let dd = y + phi0;
+                (x.tan().atan2(dd.cos()), (dd.sin() * x.cos()).asin())
+            }
+        };
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_cass_el() {
+        let p = Proj::from_proj_string("+proj=cass +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222605.28576348338, 110642.2292539994, 0.)),
+            ((2., -1., 0.), (222605.28576348338, -110642.2292539994, 0.)),
+            ((-2., 1., 0.), (-222605.28576348338, 110642.2292539994, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222605.28576348338, -110642.2292539994, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+
+        let p = Proj::from_proj_string("+proj=cass +ellps=GRS80 +lat_0=50").unwrap();
+        let inputs = [
+            ((0., 50., 0.), (0., 0., 0.)),
+            ((5., 52., 0.), (343119.18889741314, 234313.03712623342, 0.)),
+        ];
+
+        // The difference of meridional distances is accurate to 1e-4 m.
+        // The inverse series is truncated: at 5° from the central
+        // meridian, it is off by about 1.5e-6° in latitude.
+        test_proj_forward(&p, &inputs, 1.0e-4);
+        test_proj_inverse(&p, &inputs, 1.0e-5);
+    }
+
+    #[test]
+    fn proj_cass_sp() {
+        let p = Proj::from_proj_string("+proj=cass +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223368.10520348378, 111769.1450405858, 0.)),
+            ((2., -1., 0.), (223368.10520348378, -111769.1450405858, 0.)),
+            ((-2., 1., 0.), (-223368.10520348378, 111769.1450405858, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223368.10520348378, -111769.1450405858, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_cass_trinidad() {
+        // EPSG Guidance Note 7-2, example for Cassini-Soldner (EPSG:9806),
+        // in Clarke's links
+        let p = Proj::from_proj_string(concat!(
+            "+proj=cass +lat_0=10.441666666666666 +lon_0=-61.333333333333336",
+            " +x_0=430000 +y_0=325000 +a=31706587.88 +rf=294.2606764",
+        ))
+        .unwrap();
+
+        let inputs = [((-62., 10., 0.), (66644.94, 82536.22, 0.))];
+
+        // Published values are rounded to the centimeter
+        test_proj_forward(&p, &inputs, 1.0e-2);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    fn proj_cass_hemisphere() {
+        // On the sphere, points beyond 90° from the central meridian
+        // are still invertible
+        let p = Proj::from_proj_string("+proj=cass +R=6400000").unwrap();
+        let inputs = [((100., 30., 0.), (6537310.955402738, 11922925.248260181, 0.))];
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        // But the meridians at 90° are mapped to a single point
+        for projstr in ["+proj=cass +R=6400000", "+proj=cass +ellps=GRS80"] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            assert!(
+                matches!(
+                    p.projection().inverse(FRAC_PI_2, 0.1, 0.),
+                    Err(Error::CoordinateOutOfRange)
+                ),
+                "{projstr}"
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//!
+//! Equal Area Cylindrical
+//!
+//! ref: <https://proj.org/operations/projections/cea.html>
+//!
+//! cea: "Equal Area Cylindrical" "\n\tCyl, Sph&Ell\n\tlat_ts="
+//!
+//! The standard parallel may be given either by `lat_ts` or by the
+//! equivalent scale factor `k_0` along it, but not both.
+//!
+//! As in proj, `lat_0` is ignored: the northing origin is the equator.
+//!
+use crate::errors::{Error, Result};
+use crate::math::{
+    aasin, authlat, authset,
+    consts::{EPS_10, FRAC_PI_2},
+    msfn, qsfn,
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { cea }
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    k0: f64,
+    e: f64,
+    one_es: f64,
+    qp: f64,
+    apa: (f64, f64, f64),
+    is_ellps: bool,
+}
+
+impl Projection {
+    pub fn cea(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        if let Some(phits) = params.try_angular_value("lat_ts")? {
+            if ["k_0", "k0", "k"].iter().any(|k| params.get(k).is_some()) {
+                return Err(Error::InvalidParameterValue(
+                    "lat_ts and k_0 are mutually exclusive",
+                ));
+            }
+            if phits.abs() > FRAC_PI_2 {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for lat_ts: |lat_ts| should be <= 90°",
+                ));
+            }
+            p.k0 = msfn(phits.sin(), phits.cos(), p.ellps.es);
+        }
+
+        let el = &p.ellps;
+        Ok(Self {
+            k0: p.k0,
+            e: el.e,
+            one_es: el.one_es,
+            qp: qsfn(1., el.e, el.one_es),
+            apa: authset(el.es),
+            is_ellps: el.is_ellipsoid(),
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let y = if self.is_ellps {
+            0.5 * qsfn(phi.sin(), self.e, self.one_es)
+        } else {
+            phi.sin()
+        };
+        Ok((self.k0 * lam, y / self.k0, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let phi = if self.is_ellps {
+            authlat(aasin(2. * y * self.k0 / self.qp)?, self.apa)
+        } else {
+            let y = y * self.k0;
+            let t = y.abs();
+            if t - EPS_10 > 1. {
+                return Err(Error::CoordinateOutOfRange);
+            }
+            if t >= 1. {
+                FRAC_PI_2.copysign(y)
+            } else {
+                y.asin()
+            }
+        };
+        Ok((x / self.k0, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_cea_el() {
+        let p = Proj::from_proj_string("+proj=cea +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222638.981586547, 110568.812396267, 0.)),
+            ((2., -1., 0.), (222638.981586547, -110568.812396267, 0.)),
+            ((-2., 1., 0.), (-222638.981586547, 110568.812396267, 0.)),
+            ((-2., -1., 0.), (-222638.981586547, -110568.812396267, 0.)),
+        ];
+
+        // The authalic latitude series is accurate to about 1e-9 degrees
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_cea_sp() {
+        let p = Proj::from_proj_string("+proj=cea +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223402.144255274, 111695.401198614, 0.)),
+            ((2., -1., 0.), (223402.144255274, -111695.401198614, 0.)),
+            ((-2., 1., 0.), (-223402.144255274, 111695.401198614, 0.)),
+            ((-2., -1., 0.), (-223402.144255274, -111695.401198614, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_cea_lat_ts() {
+        let inputs = [((2., 1., 0.), (192972.5605025846, 127566.98529068484, 0.))];
+
+        // k_0 = cos(lat_ts) / sqrt(1 - es sin²(lat_ts))
+        for projstr in [
+            "+proj=cea +ellps=GRS80 +lat_ts=30",
+            "+proj=cea +ellps=GRS80 +k_0=0.86675100257575394",
+        ] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            test_proj_forward(&p, &inputs, 1.0e-6);
+            test_proj_inverse(&p, &inputs, 1.0e-8);
+        }
+
+        for projstr in [
+            "+proj=cea +ellps=GRS80 +lat_ts=30 +k_0=0.86675100257575394",
+            "+proj=cea +ellps=GRS80 +lat_ts=30 +k=1",
+            "+proj=cea +ellps=GRS80 +lat_ts=91",
+        ] {
+            assert!(
+                matches!(
+                    Proj::from_proj_string(projstr),
+                    Err(Error::InvalidParameterValue(_))
+                ),
+                "{projstr}"
+            );
+        }
+    }
+}
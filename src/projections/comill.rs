@@ -0,0 +1,117 @@
+//!
+//! Compact Miller
+//!
+//! ref: <https://proj.org/operations/projections/comill.html>
+//!
+//! comill: "Compact Miller" "\n\tCyl., Sph."
+//!
+//! A cylindrical projection with a reduced north-south extent
+//! compared to Miller, designed by Tom Patterson, Bojan Savric and
+//! Bernhard Jenny.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{consts::PI, newton};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { comill }
+
+const K1: f64 = 0.9902;
+const K2: f64 = 0.1604;
+const K3: f64 = -0.03054;
+const C1: f64 = K1;
+const C2: f64 = 3. * K2;
+const C3: f64 = 5. * K3;
+
+const EPS: f64 = 1e-11;
+const MAX_Y: f64 = 0.6000207669862655 * PI;
+const MAX_ITER: usize = 100;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn comill(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let phi2 = phi * phi;
+        Ok((lam, phi * (K1 + phi2 * (K2 + K3 * phi2)), z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // make sure y is inside valid range
+        let y = y.clamp(-MAX_Y, MAX_Y);
+
+        // Newton-Raphson
+        let yc = newton(y, EPS, MAX_ITER, |yc| {
+            let y2 = yc * yc;
+            let f = yc * (K1 + y2 * (K2 + K3 * y2)) - y;
+            let fder = C1 + y2 * (C2 + C3 * y2);
+            f / fder
+        })
+        .map_err(|_| Error::InverseProjectionFailure)?;
+
+        Ok((x, yc, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_comill() {
+        let p = Proj::from_proj_string("+proj=comill +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223402.14425527418, 110611.85908945855, 0.)),
+            ((2., -1., 0.), (223402.14425527418, -110611.85908945855, 0.)),
+            ((-2., 1., 0.), (-223402.14425527418, 110611.85908945855, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223402.14425527418, -110611.85908945855, 0.),
+            ),
+            ((30., 45., 0.), (3351032.163829113, 5416217.243554, 0.)),
+            (
+                (120., -70., 0.),
+                (13404128.655316452, -9082450.78791611, 0.),
+            ),
+            (
+                (-179., 89., 0.),
+                (-19994491.91084704, 11923931.45927215, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_comill_inverse_pole() {
+        let p = Proj::from_proj_string("+proj=comill").unwrap();
+
+        // y beyond the pole is clamped
+        let (_, phi, _) = p.projection().inverse(0., 3., 0.).unwrap();
+        assert!((phi - FRAC_PI_2).abs() < 1.0e-9);
+    }
+}
@@ -0,0 +1,115 @@
+//!
+//! Equidistant Cylindrical (Plate Carrée)
+//!
+//! ref: <https://proj.org/operations/projections/eqc.html>
+//!
+//! eqc: "Equidistant Cylindrical (Plate Carree)" "\n\tCyl, Sph\n\tlat_ts=[, lat_0=0]"
+//!
+//! Unlike `latlong`, the output of eqc is in meters (resp. projection units).
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { eqc }
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    rc: f64,
+    phi0: f64,
+}
+
+impl Projection {
+    pub fn eqc(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let rc = params.try_angular_value("lat_ts")?.unwrap_or(0.).cos();
+        if rc <= 0. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_ts: |lat_ts| should be <= 90°",
+            ));
+        }
+
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        Ok(Self { rc, phi0: p.phi0 })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        Ok((self.rc * lam, phi - self.phi0, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        Ok((x / self.rc, y + self.phi0, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_eqc() {
+        let p = Proj::from_proj_string("+proj=eqc +a=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223402.14425527418, 111701.07212763709, 0.)),
+            ((2., -1., 0.), (223402.14425527418, -111701.07212763709, 0.)),
+            ((-2., 1., 0.), (-223402.14425527418, 111701.07212763709, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223402.14425527418, -111701.07212763709, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_eqc_lat_ts() {
+        let p = Proj::from_proj_string(
+            "+proj=eqc +a=6400000 +lat_ts=30 +lat_0=10 +lon_0=5 +x_0=1000 +y_0=2000",
+        )
+        .unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (-289207.89827747486, -1003309.6491487338, 0.)),
+            (
+                (-20., 45., 0.),
+                (-2417399.1523122904, 3911537.524467298, 0.),
+            ),
+            (
+                (150., -60., 0.),
+                (14027715.083411286, -7817075.048934596, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_eqc_invalid() {
+        assert!(matches!(
+            Proj::from_proj_string("+proj=eqc +lat_ts=100"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+}
@@ -14,6 +14,7 @@ use crate::math::{
 };
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
+use crate::projections::etmerc::max_dlon;
 
 #[derive(Debug, Clone)]
 pub(crate) struct Ell {
@@ -22,6 +23,7 @@ pub(crate) struct Ell {
     esp: f64,
     ml0: f64,
     en: Enfn,
+    max_dlon: f64,
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +31,7 @@ pub(crate) struct Sph {
     phi0: f64,
     esp: f64,
     ml0: f64,
+    max_dlon: f64,
 }
 
 #[derive(Debug, Clone)]
@@ -50,7 +53,8 @@ const FC7: f64 = 0.023_809_523_809_523_808;
 const FC8: f64 = 0.017_857_142_857_142_856;
 
 impl Projection {
-    pub fn estmerc(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+    pub fn estmerc(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let max_dlon = max_dlon(params)?;
         if p.ellps.is_ellipsoid() {
             let es = p.ellps.es;
             let en = enfn(es);
@@ -60,18 +64,27 @@ impl Projection {
                 es,
                 esp: es / (1. - es),
                 ml0: mlfn(p.phi0, p.phi0.sin(), p.phi0.cos(), en),
+                max_dlon,
             }))
         } else {
             Ok(Sph(Sph {
                 phi0: p.phi0,
                 esp: p.k0,
                 ml0: 0.5 * p.k0,
+                max_dlon,
             }))
         }
     }
 
     #[inline(always)]
     pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let max_dlon = match self {
+            Ell(e) => e.max_dlon,
+            Sph(s) => s.max_dlon,
+        };
+        if lam.abs() > max_dlon {
+            return Err(Error::CoordinateOutOfRange);
+        }
         match self {
             Ell(e) => e.forward(lam, phi, z),
             Sph(s) => s.forward(lam, phi, z),
@@ -6,23 +6,61 @@
 //!
 //! This algorithm is used as a base for UTM projections.
 //!
+//! The optional `order` parameter select the number of terms (1 to 6) used in the
+//! trigonometric series. The default is the 6th order, as in proj.
+//!
+//! The optional `max_dlon` parameter (in degrees) rejects points farther from the
+//! central meridian with [`Error::CoordinateOutOfRange`]. As in proj, there is
+//! no limit by default.
+//!
 //! Reference: <https://proj.org/operations/projections/tmerc.html>
 //!
 //! etmerc: "Extended Transverse Mercator" "\n\tCyl, Sph\n\tlat_ts=(0)\nlat_0=(0)"
 //! utm: "Universal Transverse Mercator (UTM)" "\n\tCyl, Sph\n\tzone= south"
 //!
+//! UTM zones are in the northern hemisphere (no false northing) unless
+//! `south` is set: `north` is accepted for explicitness and has no effect.
+//!
 #![allow(non_snake_case)]
 
 // Projection stub
-super::projection! { etmerc, utm }
+super::projection! { etmerc, utm; factors }
 
 use crate::errors::{Error, Result};
+use crate::factors::Factors;
 use crate::math::{adjlon, asinh, consts::PI};
 use crate::parameters::ParamList;
-use crate::proj::ProjData;
+use crate::proj::{GridSystem, ProjData};
 
 const ETMERC_ORDER: usize = 6;
 
+const MAX_DLON_PARAM: &str = "max_dlon";
+
+/// Return the maximum longitude difference to the central meridian
+/// from the `max_dlon` parameter, in radians
+///
+/// Return infinity if the parameter is not set.
+pub(crate) fn max_dlon(params: &ParamList) -> Result<f64> {
+    match params.try_angular_value(MAX_DLON_PARAM)? {
+        Some(dlon) if dlon > 0. && dlon <= PI => Ok(dlon),
+        Some(_) => Err(Error::InvalidParameterValue(MAX_DLON_PARAM)),
+        None => Ok(f64::INFINITY),
+    }
+}
+
+/// Return the UTM zone (1 to 60) containing the longitude `lon`,
+/// in radians
+///
+/// ```rust
+/// use proj4rs::projections::etmerc::utm_zone;
+///
+/// assert_eq!(utm_zone(2f64.to_radians()), 31);
+/// assert_eq!(utm_zone(-180f64.to_radians()), 1);
+/// ```
+pub fn utm_zone(lon: f64) -> i32 {
+    (((adjlon(lon).to_degrees() + 180.) / 6.).floor() as i32 + 1).clamp(1, 60)
+}
+
 type Coeffs = [f64; ETMERC_ORDER];
 
 #[inline]
@@ -106,11 +144,22 @@ pub(crate) struct Projection {
     cbg: Coeffs, // Constants for Geo lat -> Gauss
     utg: Coeffs, // Constants for transv. merc. -> geo
     gtu: Coeffs, // Constants for geo -> transv. merc.
+    es: f64,     // Eccentricity squared
+    max_dlon: f64,
+}
+
+// Truncate series to the given order
+#[inline]
+fn truncate(c: &mut Coeffs, order: usize) {
+    // Coefficients are reversed: higher orders come first
+    c[..ETMERC_ORDER - order].fill(0.);
 }
 
 #[rustfmt::skip]
 impl Projection {
-    pub fn etmerc(p: &mut ProjData, _params: &ParamList) -> Result<Self> {
+    const ORDER_PARAM: &'static str = "order";
+
+    pub fn etmerc(p: &mut ProjData, params: &ParamList) -> Result<Self> {
 
         // We have flattening computed, use it !
         let f = p.ellps.f;
@@ -119,6 +168,12 @@ impl Projection {
             return Err(Error::EllipsoidRequired)
         }
 
+        let order = match params.try_value::<i32>(Self::ORDER_PARAM)? {
+            Some(order) if (1..=ETMERC_ORDER as i32).contains(&order) => order as usize,
+            Some(_) => return Err(Error::InvalidParameterValue(Self::ORDER_PARAM)),
+            None => ETMERC_ORDER,
+        };
+
         // third flattening
         let n = f / (2. - f);
 
@@ -149,6 +204,8 @@ impl Projection {
         // Coefficients are used backward so reverse them now 
         cgb.reverse();
         cbg.reverse();
+        truncate(&mut cgb, order);
+        truncate(&mut cbg, order);
 
         // Constants of the projections 
         // Transverse Mercator (UTM, ITM, etc)
@@ -180,6 +237,8 @@ impl Projection {
         // Coefficients are used backward so reverse them now 
         utg.reverse();
         gtu.reverse();
+        truncate(&mut utg, order);
+        truncate(&mut gtu, order);
 
         // Gaussian latitude value of the origin latitude
         let z = gatg(&cbg, p.phi0);
@@ -195,11 +254,17 @@ impl Projection {
             cbg,
             utg,
             gtu,
+            es: p.ellps.es,
+            max_dlon: max_dlon(params)?,
         })
     }
 
     pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
 
+        if lam.abs() > self.max_dlon {
+            return Err(Error::CoordinateOutOfRange);
+        }
+
         let (mut Cn, mut Ce) = (phi, lam);
 
         // ell. LAT, LNG -> Gaussian LAT, LNG
@@ -259,6 +324,43 @@ impl Projection {
         }
     }
 
+    /// Analytic point scale factor and meridian convergence
+    ///
+    /// Differentiate the Krüger series, see C. Karney,
+    /// "Transverse Mercator with an accuracy of a few nanometers",
+    /// J. Geodesy 85(8), 475-485 (Aug. 2011), eqs. (25) to (28).
+    pub fn factors(&self, lam: f64, phi: f64) -> Result<Factors> {
+
+        // ell. LAT -> Gaussian (conformal) LAT
+        let chi = gatg(&self.cbg, phi);
+
+        let (sin_chi, cos_chi) = chi.sin_cos();
+        let (sin_lam, cos_lam) = lam.sin_cos();
+
+        // compl. sph. N, E
+        let xi = sin_chi.atan2(cos_lam*cos_chi);
+        let eta = asinh((sin_lam*cos_chi).atan2(sin_chi.hypot(cos_chi*cos_lam)).tan());
+
+        // Derivative of the series: d(xi + i.eta)/d(xi' + i.eta') = p - i.q
+        let (p, q) = self.gtu.iter().rev().enumerate().fold((1., 0.), |(p, q), (j, a)| {
+            let j2 = 2. * (j + 1) as f64;
+            (
+                p + j2 * a * (j2 * xi).cos() * (j2 * eta).cosh(),
+                q + j2 * a * (j2 * xi).sin() * (j2 * eta).sinh(),
+            )
+        });
+
+        // Scale of the conformal sphere
+        let sin_phi = phi.sin();
+        let k_sph = (1. - self.es * sin_phi * sin_phi).sqrt() * cos_chi
+            / (phi.cos() * sin_chi.hypot(cos_chi * cos_lam));
+
+        Ok(Factors::conformal(
+            self.Qn * k_sph * p.hypot(q),
+            (sin_chi * lam.tan()).atan() + q.atan2(p),
+        ))
+    }
+
     pub const fn has_inverse() -> bool {
         true
     }
@@ -272,11 +374,10 @@ impl Projection {
     //------------------
     pub fn utm(p: &mut ProjData, params: &ParamList) -> Result<Self> {
         p.x0 = 500_000.;
-        p.y0 = if params.check_option("south")? {
-            10_000_000.
-        } else {
-            0.
-        };
+        let south = params.check_option("south")?;
+        // Northern hemisphere by default: `north` is only validated
+        params.check_option("north")?;
+        p.y0 = if south { 10_000_000. } else { 0. };
 
         let zone = params.try_value::<i32>("zone").and_then(|zone| match zone {
             Some(zone) => {
@@ -286,17 +387,14 @@ impl Projection {
                     Err(Error::InvalidUtmZone)
                 }
             }
-            None => {
-                // nearest central meridian input
-                let zone = ((adjlon(p.lam0) + PI) * 30. / PI).floor().round();
-                if (1. ..=60.).contains(&zone) {
-                    Ok(zone)
-                } else {
-                    Err(Error::InvalidUtmZone)
-                }
-            }
+            // nearest central meridian input
+            None => Ok(utm_zone(p.lam0) as f64),
         })?;
 
+        p.grid = Some(GridSystem::Utm {
+            zone: zone as u8,
+            south,
+        });
         p.lam0 = ((zone - 1.) + 0.5) * PI / 30. - PI;
         p.k0 = 0.9996;
         p.phi0 = 0.;
@@ -319,12 +417,12 @@ mod tests {
         println!("{:#?}", p.projection());
 
         let inputs = [
-            ((2., 1., 0.), (222650.79679758527, 110642.22941193319, 0.)),
-            ((2., -1., 0.), (222650.79679758527, -110642.22941193319, 0.)),
-            ((-2., 1., 0.), (-222650.79679758527, 110642.22941193319, 0.)),
+            ((2., 1., 0.), (222650.79679758527, 110642.2294119332, 0.)),
+            ((2., -1., 0.), (222650.79679758527, -110642.2294119332, 0.)),
+            ((-2., 1., 0.), (-222650.79679758527, 110642.2294119332, 0.)),
             (
                 (-2., -1., 0.),
-                (-222650.79679758527, -110642.22941193319, 0.),
+                (-222650.79679758527, -110642.2294119332, 0.),
             ),
         ];
 
@@ -332,6 +430,38 @@ mod tests {
         test_proj_inverse(&p, &inputs, EPS_10);
     }
 
+    #[test]
+    fn proj_etmerc_order() {
+        // Default order match proj: proj gie reference values
+        let inputs = [
+            ((2., 1., 0.), (222650.796797586, 110642.229411933, 0.)),
+            ((2., -1., 0.), (222650.796797586, -110642.229411933, 0.)),
+            ((-2., 1., 0.), (-222650.796797586, 110642.229411933, 0.)),
+            ((-2., -1., 0.), (-222650.796797586, -110642.229411933, 0.)),
+        ];
+
+        let p = Proj::from_proj_string("+proj=etmerc +ellps=GRS80").unwrap();
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+
+        let p = Proj::from_proj_string("+proj=etmerc +ellps=GRS80 +order=6").unwrap();
+        test_proj_forward(&p, &inputs, 1.0e-8);
+
+        // Lower orders degrade accuracy
+        let p = Proj::from_proj_string("+proj=etmerc +ellps=GRS80 +order=2").unwrap();
+        let (x, y, _) = p
+            .projection()
+            .forward(2f64.to_radians(), 1f64.to_radians(), 0.)
+            .unwrap();
+        let (x, y) = (x * p.data().ellps.a, y * p.data().ellps.a);
+        assert!((x - 222650.796797586).abs() > 1.0e-8 || (y - 110642.229411933).abs() > 1.0e-8);
+        assert!((x - 222650.796797586).abs() < 1.0e-1 && (y - 110642.229411933).abs() < 1.0e-1);
+
+        // Invalid orders
+        assert!(Proj::from_proj_string("+proj=etmerc +ellps=GRS80 +order=0").is_err());
+        assert!(Proj::from_proj_string("+proj=etmerc +ellps=GRS80 +order=7").is_err());
+    }
+
     #[test]
     fn proj_etmerc_utm() {
         let p = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30").unwrap();
@@ -342,11 +472,118 @@ mod tests {
         let inputs = [
             ((2., 1., 0.), (1057002.4054912976, 110955.14117594929, 0.)),
             ((2., -1., 0.), (1057002.4054912976, -110955.1411759492, 0.)),
-            ((-2., 1., 0.), (611263.8122789060, 110547.10569680421, 0.)),
-            ((-2., -1., 0.), (611263.8122789060, -110547.10569680421, 0.)),
+            ((-2., 1., 0.), (611263.812278906, 110547.10569680421, 0.)),
+            ((-2., -1., 0.), (611263.812278906, -110547.10569680421, 0.)),
         ];
 
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_etmerc_lat_0() {
+        // EPSG guidance note 7-2, Transverse Mercator:
+        // OSGB 1936 / British National Grid (EPSG:27700)
+        for name in ["etmerc", "tmerc"] {
+            let p = Proj::from_proj_string(&format!(
+                "+proj={name} +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +ellps=airy"
+            ))
+            .unwrap();
+
+            let inputs = [
+                ((0.5, 50.5, 0.), (577274.99, 69740.50, 0.)),
+                // The natural origin
+                ((-2., 49., 0.), (400000., -100000., 0.)),
+            ];
+
+            // Published values are rounded to the centimeter
+            test_proj_forward(&p, &inputs, 1.0e-2);
+            test_proj_inverse(&p, &inputs, 1.0e-7);
+        }
+    }
+
+    #[test]
+    fn proj_etmerc_utm_hemisphere() {
+        use crate::transform::transform;
+
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let project = |projstr: &str| {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let mut pt = (2f64.to_radians(), -30f64.to_radians(), 0.);
+            transform(&geo, &p, &mut pt).unwrap();
+            pt
+        };
+
+        let default = project("+proj=utm +ellps=GRS80 +zone=31");
+        assert_eq!(project("+proj=utm +ellps=GRS80 +zone=31 +north"), default);
+        assert_eq!(
+            project("+proj=utm +ellps=GRS80 +zone=31 +south=false"),
+            default
+        );
+
+        let south = project("+proj=utm +ellps=GRS80 +zone=31 +south");
+        assert_eq!(south.0, default.0);
+        assert!((south.1 - default.1 - 10_000_000.).abs() < 1.0e-8);
+
+        assert!(Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=31 +north=foo").is_err());
+    }
+
+    #[test]
+    fn proj_etmerc_utm_zone() {
+        use super::utm_zone;
+
+        assert_eq!(utm_zone(-180f64.to_radians()), 1);
+        assert_eq!(utm_zone(-177f64.to_radians()), 1);
+        assert_eq!(utm_zone(-3f64.to_radians()), 30);
+        assert_eq!(utm_zone(0.), 31);
+        assert_eq!(utm_zone(179.9f64.to_radians()), 60);
+        assert_eq!(utm_zone(180f64.to_radians()), 60);
+        // Longitudes are normalized
+        assert_eq!(utm_zone(357f64.to_radians()), 30);
+
+        // The zone is derived from lon_0 if not given
+        let p = Proj::from_proj_string("+proj=utm +ellps=GRS80 +lon_0=-4").unwrap();
+        assert!((p.data().lam0 - -3f64.to_radians()).abs() < 1.0e-15);
+
+        let p = Proj::from_proj_string("+proj=utm +ellps=GRS80 +zone=30 +south").unwrap();
+        assert!((p.data().lam0 - -3f64.to_radians()).abs() < 1.0e-15);
+        assert_eq!((p.data().x0, p.data().y0), (500_000., 10_000_000.));
+    }
+
+    #[test]
+    fn proj_etmerc_factors() {
+        use crate::factors::numeric;
+        use approx::assert_abs_diff_eq;
+
+        let p = Proj::from_proj_string("+proj=etmerc +ellps=GRS80 +k=0.9996").unwrap();
+
+        for (lon, lat) in [(0f64, 0f64), (3., 45.), (-5., 30.), (10., -60.), (20., 80.)] {
+            let (lam, phi) = (lon.to_radians(), lat.to_radians());
+
+            let analytic = p.projection().factors(lam, phi).unwrap().unwrap();
+            let numeric = numeric(p.projection(), &p.data().ellps, lam, phi).unwrap();
+
+            assert_abs_diff_eq!(
+                analytic.parallel_scale,
+                numeric.parallel_scale,
+                epsilon = 1.0e-9
+            );
+            assert_abs_diff_eq!(
+                analytic.meridional_scale,
+                numeric.meridional_scale,
+                epsilon = 1.0e-9
+            );
+            assert_abs_diff_eq!(analytic.areal_scale, numeric.areal_scale, epsilon = 1.0e-9);
+            assert_abs_diff_eq!(
+                analytic.meridian_convergence,
+                numeric.meridian_convergence,
+                epsilon = 1.0e-9
+            );
+        }
+
+        // Scale is k0 on the central meridian
+        let f = p.projection().factors(0., 0.7).unwrap().unwrap();
+        assert_abs_diff_eq!(f.parallel_scale, 0.9996, epsilon = 1.0e-15);
+        assert_eq!(f.meridian_convergence, 0.);
+    }
 }
@@ -0,0 +1,389 @@
+//!
+//! Simple conics
+//!
+//! ref: <https://proj.org/operations/projections/euler.html>
+//!
+//! euler: "Euler" "\n\tConic, Sph\n\tlat_1= and lat_2="
+//! murd1: "Murdoch I" "\n\tConic, Sph\n\tlat_1= and lat_2="
+//! murd2: "Murdoch II" "\n\tConic, Sph\n\tlat_1= and lat_2="
+//! murd3: "Murdoch III" "\n\tConic, Sph\n\tlat_1= and lat_2="
+//! pconic: "Perspective Conic" "\n\tConic, Sph\n\tlat_1= and lat_2="
+//! tissot: "Tissot" "\n\tConic, Sph\n\tlat_1= and lat_2="
+//! vitk1: "Vitkovsky I" "\n\tConic, Sph\n\tlat_1= and lat_2="
+//!
+//! As in proj, the Tissot radius at the origin is computed with the
+//! Tissot formula while other points use the linear radius shared by
+//! the other simple conics.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{EPS_10, FRAC_PI_2};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { euler, murd1, murd2, murd3, pconic, tissot, vitk1 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Type {
+    Euler,
+    Murd1,
+    Murd2,
+    Murd3,
+    PConic,
+    Tissot,
+    Vitk1,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    kind: Type,
+    n: f64,
+    rho_c: f64,
+    rho_0: f64,
+    sig: f64,
+    c1: f64,
+    c2: f64,
+}
+
+impl Projection {
+    pub fn euler(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::setup(p, params, Type::Euler)
+    }
+
+    pub fn murd1(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::setup(p, params, Type::Murd1)
+    }
+
+    pub fn murd2(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::setup(p, params, Type::Murd2)
+    }
+
+    pub fn murd3(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::setup(p, params, Type::Murd3)
+    }
+
+    pub fn pconic(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::setup(p, params, Type::PConic)
+    }
+
+    pub fn tissot(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::setup(p, params, Type::Tissot)
+    }
+
+    pub fn vitk1(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::setup(p, params, Type::Vitk1)
+    }
+
+    /// Return the half difference and the mean of the
+    /// standard parallels
+    fn phi12(params: &ParamList) -> Result<(f64, f64)> {
+        match (
+            params.try_angular_value("lat_1")?,
+            params.try_angular_value("lat_2")?,
+        ) {
+            (Some(p1), Some(p2)) => {
+                let (del, sig) = (0.5 * (p2 - p1), 0.5 * (p2 + p1));
+                if del.abs() < EPS_10 || sig.abs() < EPS_10 {
+                    Err(Error::InvalidParameterValue(
+                        "Invalid value for lat_1 and lat_2: |lat_1 + lat_2| and |lat_1 - lat_2| should be > 0",
+                    ))
+                } else {
+                    Ok((del, sig))
+                }
+            }
+            _ => Err(Error::InvalidParameterValue(
+                "Missing parameter lat_1 or lat_2",
+            )),
+        }
+    }
+
+    fn setup(p: &mut ProjData, params: &ParamList, kind: Type) -> Result<Self> {
+        let (mut del, sig) = Self::phi12(params)?;
+        let phi0 = p.phi0;
+
+        // (n, rho_c, rho_0, c1, c2)
+        let (n, rho_c, rho_0, c1, c2) = match kind {
+            Type::Tissot => {
+                let n = sig.sin();
+                let cs = del.cos();
+                let rho_c = n / cs + cs / n;
+                (n, rho_c, ((rho_c - 2. * phi0.sin()) / n).sqrt(), 0., 0.)
+            }
+            Type::Murd1 => {
+                let rho_c = del.sin() / (del * sig.tan()) + sig;
+                (sig.sin(), rho_c, rho_c - phi0, 0., 0.)
+            }
+            Type::Murd2 => {
+                let cs = del.cos().sqrt();
+                let rho_c = cs / sig.tan();
+                (sig.sin() * cs, rho_c, rho_c + (sig - phi0).tan(), 0., 0.)
+            }
+            Type::Murd3 => {
+                let rho_c = del / (sig.tan() * del.tan()) + sig;
+                let n = sig.sin() * del.sin() * del.tan() / (del * del);
+                (n, rho_c, rho_c - phi0, 0., 0.)
+            }
+            Type::Euler => {
+                let n = sig.sin() * del.sin() / del;
+                del *= 0.5;
+                let rho_c = del / (del.tan() * sig.tan()) + sig;
+                (n, rho_c, rho_c - phi0, 0., 0.)
+            }
+            Type::Vitk1 => {
+                let cs = del.tan();
+                let rho_c = del / (cs * sig.tan()) + sig;
+                (cs * sig.sin() / del, rho_c, rho_c - phi0, 0., 0.)
+            }
+            Type::PConic => {
+                let c2 = del.cos();
+                let c1 = 1. / sig.tan();
+                del = phi0 - sig;
+                if del.abs() - EPS_10 >= FRAC_PI_2 {
+                    return Err(Error::InvalidParameterValue(
+                        "Invalid value for lat_0: |lat_0 - 0.5 * (lat_1 + lat_2)| should be < 90°",
+                    ));
+                }
+                (sig.sin(), 0., c2 * (c1 - del.tan()), c1, c2)
+            }
+        };
+
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        Ok(Self {
+            kind,
+            n,
+            rho_c,
+            rho_0,
+            sig,
+            c1,
+            c2,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let rho = match self.kind {
+            Type::Murd2 => self.rho_c + (self.sig - phi).tan(),
+            Type::PConic => self.c2 * (self.c1 - (phi - self.sig).tan()),
+            _ => self.rho_c - phi,
+        };
+        let (sin_lam, cos_lam) = (lam * self.n).sin_cos();
+        Ok((rho * sin_lam, self.rho_0 - rho * cos_lam, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (mut x, mut y) = (x, self.rho_0 - y);
+        let mut rho = x.hypot(y);
+        if self.n < 0. {
+            rho = -rho;
+            x = -x;
+            y = -y;
+        }
+        let phi = match self.kind {
+            Type::PConic => (self.c1 - rho / self.c2).atan() + self.sig,
+            Type::Murd2 => self.sig - (rho - self.rho_c).atan(),
+            _ => self.rho_c - rho,
+        };
+        Ok((x.atan2(y) / self.n, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_euler() {
+        let p = Proj::from_proj_string("+proj=euler +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222597.63465910763, 111404.24054989989, 0.)),
+            ((2., -1., 0.), (222767.16563187627, -111234.67649099875, 0.)),
+            ((-2., 1., 0.), (-222597.63465910763, 111404.24054989989, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222767.16563187627, -111234.67649099875, 0.),
+            ),
+            ((30., 45., 0.), (3282948.232659093, 5028126.0770495655, 0.)),
+            (
+                (-60., 20., 0.),
+                (-6629036.767449553, 2302109.4423062773, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_murd1() {
+        let p = Proj::from_proj_string("+proj=murd1 +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222600.8134735542, 111404.24418052901, 0.)),
+            ((2., -1., 0.), (222770.3492878644, -111234.67285668294, 0.)),
+            ((-2., 1., 0.), (-222600.8134735542, 111404.24418052901, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222770.3492878644, -111234.67285668294, 0.),
+            ),
+            ((30., 45., 0.), (3282994.3121103356, 5028126.8756717155, 0.)),
+            ((-60., 20., 0.), (-6629130.710982449, 2302112.678022754, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_murd2() {
+        let p = Proj::from_proj_string("+proj=murd2 +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222588.09975123018, 111426.14002743427, 0.)),
+            ((2., -1., 0.), (222757.7262670182, -111341.43131754329, 0.)),
+            ((-2., 1., 0.), (-222588.09975123018, 111426.14002743427, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222757.7262670182, -111341.43131754329, 0.),
+            ),
+            ((30., 45., 0.), (3268694.6200581077, 6263576.647566888, 0.)),
+            ((-60., 20., 0.), (-6626973.341334759, 2379952.188788621, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_murd3() {
+        let p = Proj::from_proj_string("+proj=murd3 +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222600.81407757694, 111404.24660135084, 0.)),
+            ((2., -1., 0.), (222770.3547338988, -111234.67043217394, 0.)),
+            ((-2., 1., 0.), (-222600.81407757694, 111404.24660135084, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222770.3547338988, -111234.67043217394, 0.),
+            ),
+            ((30., 45., 0.), (3282992.7192815356, 5028127.402087877, 0.)),
+            ((-60., 20., 0.), (-6629129.31634771, 2302114.824955972, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_pconic() {
+        let p = Proj::from_proj_string("+proj=pconic +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222588.09884161688, 111416.60477004023, 0.)),
+            ((2., -1., 0.), (222757.7180910903, -111331.88153113483, 0.)),
+            ((-2., 1., 0.), (-222588.09884161688, 111416.60477004023, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222757.7180910903, -111331.88153113483, 0.),
+            ),
+            ((30., 45., 0.), (3268697.6012907103, 6263042.447767396, 0.)),
+            (
+                (-60., 20., 0.),
+                (-6626975.4104129365, 2379758.0440941816, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_tissot() {
+        let p = Proj::from_proj_string("+proj=tissot +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222641.07869963092, 54347.82848723243, 0.)),
+            ((2., -1., 0.), (222810.6145139411, -168291.08854997953, 0.)),
+            ((-2., 1., 0.), (-222641.07869963092, 54347.82848723243, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222810.6145139411, -168291.08854997953, 0.),
+            ),
+            ((30., 45., 0.), (3283598.277426659, 4971073.8940058155, 0.)),
+            ((-60., 20., 0.), (-6630338.562817971, 2245070.043980955, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_vitk1() {
+        let p = Proj::from_proj_string("+proj=vitk1 +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222607.17121145775, 111404.25144240943, 0.)),
+            ((2., -1., 0.), (222776.71670959773, -111234.66558742823, 0.)),
+            ((-2., 1., 0.), (-222607.17121145775, 111404.25144240943, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222776.71670959773, -111234.66558742823, 0.),
+            ),
+            ((30., 45., 0.), (3283086.4724002266, 5028128.473051621, 0.)),
+            (
+                (-60., 20., 0.),
+                (-6629318.601094796, 2302119.1500079855, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_sconics_invalid() {
+        for projstr in [
+            "+proj=euler +lat_1=10",
+            "+proj=murd1 +lat_2=10",
+            "+proj=murd2 +lat_1=10 +lat_2=10",
+            "+proj=murd3 +lat_1=-10 +lat_2=10",
+            "+proj=pconic +lat_1=10 +lat_2=20 +lat_0=-80",
+            "+proj=tissot +lat_1=10",
+            "+proj=vitk1 +lat_2=10",
+        ] {
+            assert!(matches!(
+                Proj::from_proj_string(projstr),
+                Err(Error::InvalidParameterValue(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//!
+//! Geostationary Satellite View
+//!
+//! ref: <https://proj.org/operations/projections/geos.html>
+//!
+//! geos: "Geostationary Satellite View" "\n\tAzi, Sph&Ell\n\th="
+//!
+//! The `sweep` parameter define the sweep angle axis of the
+//! viewing instrument: GOES use `x`, Meteosat use `y` (the default).
+//!
+use crate::errors::{Error, Result};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { geos }
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    radius_p: f64,
+    radius_p2: f64,
+    radius_p_inv2: f64,
+    radius_g: f64,
+    radius_g_1: f64,
+    c: f64,
+    flip_axis: bool,
+}
+
+impl Projection {
+    const SWEEP_PARAM: &'static str = "sweep";
+
+    pub fn geos(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let h: f64 = params.try_value("h")?.unwrap_or(0.);
+
+        let flip_axis = match params.try_value(Self::SWEEP_PARAM)? {
+            Some("x") => true,
+            Some("y") | None => false,
+            Some(_) => {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for sweep: it should be equal to x or y.",
+                ))
+            }
+        };
+
+        let radius_g_1 = h / p.ellps.a;
+        if radius_g_1 <= 0. || radius_g_1 > 1e10 {
+            return Err(Error::InvalidParameterValue("Invalid value for h."));
+        }
+
+        let radius_g = 1. + radius_g_1;
+        let c = radius_g * radius_g - 1.0;
+
+        let (radius_p, radius_p2, radius_p_inv2) = if p.ellps.is_ellipsoid() {
+            (p.ellps.one_es.sqrt(), p.ellps.one_es, p.ellps.rone_es)
+        } else {
+            (1., 1., 1.)
+        };
+
+        Ok(Self {
+            radius_p,
+            radius_p2,
+            radius_p_inv2,
+            radius_g,
+            radius_g_1,
+            c,
+            flip_axis,
+        })
+    }
+
+    #[inline]
+    fn is_ellipsoid(&self) -> bool {
+        self.radius_p2 != 1.
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, mut phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let r = if self.is_ellipsoid() {
+            // Calculation of geocentric latitude.
+            phi = (self.radius_p2 * phi.tan()).atan();
+            self.radius_p / (self.radius_p * phi.cos()).hypot(phi.sin())
+        } else {
+            1.
+        };
+
+        // Calculation of the three components of the vector from satellite to
+        // position on earth surface (lam, phi).
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = lam.sin_cos();
+        let vx = r * coslam * cosphi;
+        let vy = r * sinlam * cosphi;
+        let vz = r * sinphi;
+
+        // Check visibility.
+        if (self.radius_g - vx) * vx - vy * vy - vz * vz * self.radius_p_inv2 < 0. {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+
+        // Calculation based on view angles from satellite.
+        let tmp = self.radius_g - vx;
+
+        Ok(if self.flip_axis {
+            (
+                self.radius_g_1 * (vy / vz.hypot(tmp)).atan(),
+                self.radius_g_1 * (vz / tmp).atan(),
+                z,
+            )
+        } else {
+            (
+                self.radius_g_1 * (vy / tmp).atan(),
+                self.radius_g_1 * (vz / vy.hypot(tmp)).atan(),
+                z,
+            )
+        })
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // Setting three components of vector from satellite to position.
+        let mut vx = -1.0;
+        let (mut vy, mut vz);
+        if self.flip_axis {
+            vz = (y / self.radius_g_1).tan();
+            vy = (x / self.radius_g_1).tan() * 1.0f64.hypot(vz);
+        } else {
+            vy = (x / self.radius_g_1).tan();
+            vz = (y / self.radius_g_1).tan() * 1.0f64.hypot(vy);
+        }
+
+        // Calculation of terms in cubic equation and determinant.
+        let a = vz / self.radius_p;
+        let a = vy * vy + a * a + vx * vx;
+        let b = 2. * self.radius_g * vx;
+        let det = b * b - 4. * a * self.c;
+        if det < 0. {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+
+        // Calculation of three components of vector from satellite to position.
+        let k = (-b - det.sqrt()) / (2. * a);
+        vx = self.radius_g + k * vx;
+        vy *= k;
+        vz *= k;
+
+        // Calculation of longitude and latitude.
+        let lam = vy.atan2(vx);
+        let phi = (vz * lam.cos() / vx).atan();
+        Ok((
+            lam,
+            if self.is_ellipsoid() {
+                (self.radius_p_inv2 * phi.tan()).atan()
+            } else {
+                phi
+            },
+            z,
+        ))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_geos_ellipsoidal() {
+        let p = Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222527.07036580026, 110551.30341332949, 0.)),
+            ((2., -1., 0.), (222527.07036580026, -110551.30341332949, 0.)),
+            ((-2., 1., 0.), (-222527.07036580026, 110551.30341332949, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222527.07036580026, -110551.30341332949, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_geos_spherical() {
+        let p = Proj::from_proj_string("+proj=geos +R=6400000 +h=35785831").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223289.45763579503, 111677.65745653701, 0.)),
+            ((2., -1., 0.), (223289.45763579503, -111677.65745653701, 0.)),
+            ((-2., 1., 0.), (-223289.45763579503, 111677.65745653701, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223289.45763579503, -111677.65745653701, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_geos_sweep() {
+        let p_x = Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831 +sweep=x").unwrap();
+        let p_y = Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831 +sweep=y").unwrap();
+
+        let (lam, phi) = (30f64.to_radians(), 40f64.to_radians());
+
+        let (x_x, y_x, _) = p_x.projection().forward(lam, phi, 0.).unwrap();
+        let (x_y, y_y, _) = p_y.projection().forward(lam, phi, 0.).unwrap();
+
+        // The sweep axis changes the output
+        assert!((x_x - x_y).abs() > 1.0e-3);
+        assert!((y_x - y_y).abs() > 1.0e-3);
+
+        // Both are invertible
+        let (l, p, _) = p_x.projection().inverse(x_x, y_x, 0.).unwrap();
+        assert!((l - lam).abs() < EPS_10 && (p - phi).abs() < EPS_10);
+        let (l, p, _) = p_y.projection().inverse(x_y, y_y, 0.).unwrap();
+        assert!((l - lam).abs() < EPS_10 && (p - phi).abs() < EPS_10);
+
+        // Default sweep is 'y'
+        let p_d = Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831").unwrap();
+        let (x_d, y_d, _) = p_d.projection().forward(lam, phi, 0.).unwrap();
+        assert_eq!((x_d, y_d), (x_y, y_y));
+
+        // Invalid sweep
+        assert!(matches!(
+            Proj::from_proj_string("+proj=geos +ellps=GRS80 +h=35785831 +sweep=z"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+
+    #[test]
+    fn proj_geos_invalid_h() {
+        assert!(matches!(
+            Proj::from_proj_string("+proj=geos +ellps=GRS80"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+//!
+//! Ginsburg VIII (TsNIIGAiK)
+//!
+//! ref: <https://proj.org/operations/projections/gins8.html>
+//!
+//! gins8: "Ginsburg VIII (TsNIIGAiK)" "\n\tPCyl, Sph., no inv"
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { gins8 }
+
+const CL: f64 = 0.000952426;
+const CP: f64 = 0.162388;
+const C12: f64 = 1. / 12.;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn gins8(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let t = phi * phi;
+        let l2 = lam * lam;
+        Ok((
+            lam * (1. - CP * t) * (0.87 - CL * l2 * l2),
+            phi * (1. + t * C12),
+            z,
+        ))
+    }
+
+    pub fn inverse(&self, _: f64, _: f64, _: f64) -> Result<(f64, f64, f64)> {
+        Err(Error::NoInverseProjectionDefined)
+    }
+
+    pub const fn has_inverse() -> bool {
+        false
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::test_proj_forward;
+
+    #[test]
+    fn proj_gins8() {
+        let p = Proj::from_proj_string("+proj=gins8 +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (194350.25093959007, 111703.90763533534, 0.)),
+            ((2., -1., 0.), (194350.25093959007, -111703.90763533534, 0.)),
+            ((-2., 1., 0.), (-194350.25093959007, 111703.90763533534, 0.)),
+            (
+                (-2., -1., 0.),
+                (-194350.25093959007, -111703.90763533534, 0.),
+            ),
+            ((30., 45., 0.), (2623149.3850389835, 5284933.884746168, 0.)),
+            (
+                (-120., -60., 0.),
+                (-9383015.41617578, -7314533.990478963, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        assert!(p.projection().inverse(0., 0., 0.).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+//!
+//! Gnomonic
+//!
+//! ref: <https://proj.org/operations/projections/gnom.html>
+//!
+//! gnom: "Gnomonic" "\n\tAzi, Sph"
+//!
+//! Great circles are mapped to straight lines. Only the hemisphere
+//! centered on the projection center can be mapped: points at 90° or
+//! more from the center are rejected.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{EPS_10, FRAC_PI_2};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { gnom }
+
+#[allow(non_camel_case_types)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    N_POLE,
+    S_POLE,
+    EQUIT,
+    OBLIQ,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    phi0: f64,
+    sinph0: f64,
+    cosph0: f64,
+    mode: Mode,
+}
+
+impl Projection {
+    pub fn gnom(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        let phi0 = p.phi0;
+        let (sinph0, cosph0) = phi0.sin_cos();
+
+        let mode = if (phi0.abs() - FRAC_PI_2).abs() < EPS_10 {
+            if phi0 < 0. {
+                Mode::S_POLE
+            } else {
+                Mode::N_POLE
+            }
+        } else if phi0.abs() < EPS_10 {
+            Mode::EQUIT
+        } else {
+            Mode::OBLIQ
+        };
+
+        Ok(Self {
+            phi0,
+            sinph0,
+            cosph0,
+            mode,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = lam.sin_cos();
+
+        // Cosine of the angular distance to the center
+        let cosc = match self.mode {
+            Mode::EQUIT => cosphi * coslam,
+            Mode::OBLIQ => self.sinph0 * sinphi + self.cosph0 * cosphi * coslam,
+            Mode::S_POLE => -sinphi,
+            Mode::N_POLE => sinphi,
+        };
+        if cosc <= EPS_10 {
+            return Err(Error::ToleranceConditionError);
+        }
+
+        let y = match self.mode {
+            Mode::EQUIT => sinphi,
+            Mode::OBLIQ => self.cosph0 * sinphi - self.sinph0 * cosphi * coslam,
+            Mode::N_POLE => -cosphi * coslam,
+            Mode::S_POLE => cosphi * coslam,
+        };
+
+        Ok((cosphi * sinlam / cosc, y / cosc, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let rh = x.hypot(y);
+        if rh <= EPS_10 {
+            return Ok((0., self.phi0, z));
+        }
+
+        let c = rh.atan();
+        let (sinc, cosc) = c.sin_cos();
+
+        let (phi, x, y) = match self.mode {
+            Mode::OBLIQ => {
+                let phi = asin_clamped(cosc * self.sinph0 + y * sinc * self.cosph0 / rh);
+                (
+                    phi,
+                    x * sinc * self.cosph0,
+                    (cosc - self.sinph0 * phi.sin()) * rh,
+                )
+            }
+            Mode::EQUIT => (asin_clamped(y * sinc / rh), x * sinc, cosc * rh),
+            Mode::S_POLE => (c - FRAC_PI_2, x, y),
+            Mode::N_POLE => (FRAC_PI_2 - c, x, -y),
+        };
+
+        Ok((x.atan2(y), phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[inline]
+fn asin_clamped(v: f64) -> f64 {
+    if v.abs() >= 1. {
+        FRAC_PI_2.copysign(v)
+    } else {
+        v.asin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_gnom() {
+        let p = Proj::from_proj_string("+proj=gnom +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223492.9247471855, 111780.50920659293, 0.)),
+            ((2., -1., 0.), (223492.9247471855, -111780.50920659293, 0.)),
+            ((-2., 1., 0.), (-223492.9247471855, 111780.50920659293, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223492.9247471855, -111780.50920659293, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_gnom_aspects() {
+        let inputs = [
+            (
+                "+proj=gnom +R=6400000 +lat_0=45 +lon_0=10",
+                ((20., 50., 0.), (722094.9200331227, 608507.2566405439, 0.)),
+            ),
+            (
+                "+proj=gnom +R=6400000 +lat_0=90",
+                ((-30., 60., 0.), (-1847520.8614068024, -3200000., 0.)),
+            ),
+            (
+                "+proj=gnom +R=6400000 +lat_0=-90",
+                (
+                    (30., -70., 0.),
+                    (1164704.7496518476, 2017327.8022137897, 0.),
+                ),
+            ),
+        ];
+
+        for (projstr, input) in inputs {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            test_proj_forward(&p, &[input], 1.0e-6);
+            test_proj_inverse(&p, &[input], EPS_10);
+        }
+    }
+
+    #[test]
+    fn proj_gnom_hemisphere() {
+        let p = Proj::from_proj_string("+proj=gnom +R=6400000 +lat_0=45 +lon_0=10").unwrap();
+
+        // At 90° from the center, and beyond
+        for (lon, lat) in [(10f64, -45f64), (190., 45.), (-170., 0.)] {
+            assert!(matches!(
+                p.projection()
+                    .forward((lon - 10.).to_radians(), lat.to_radians(), 0.),
+                Err(Error::ToleranceConditionError)
+            ));
+        }
+
+        // Close to the horizon, the inverse maps back consistently
+        let (lam, phi) = (0., -44f64.to_radians());
+        let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+        let (lam_i, phi_i, _) = p.projection().inverse(x, y, 0.).unwrap();
+        assert!((lam_i - lam).abs() < 1.0e-10);
+        assert!((phi_i - phi).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn proj_gnom_great_circles() {
+        let p = Proj::from_proj_string("+proj=gnom +R=6400000 +lat_0=45 +lon_0=10").unwrap();
+
+        // Unit vector of (lon, lat)
+        let v = |lon: f64, lat: f64| {
+            let (lam, phi) = (lon.to_radians(), lat.to_radians());
+            [phi.cos() * lam.cos(), phi.cos() * lam.sin(), phi.sin()]
+        };
+
+        // Interpolate along the great circle from a to b
+        let slerp = |a: [f64; 3], b: [f64; 3], t: f64| {
+            let omega = (a[0] * b[0] + a[1] * b[1] + a[2] * b[2]).acos();
+            let (ka, kb) = (
+                ((1. - t) * omega).sin() / omega.sin(),
+                (t * omega).sin() / omega.sin(),
+            );
+            let w = [0, 1, 2].map(|i| ka * a[i] + kb * b[i]);
+            (w[1].atan2(w[0]), w[2].atan2(w[0].hypot(w[1])))
+        };
+
+        let (a, b) = (v(-20., 30.), v(40., 60.));
+        let pts = [0., 0.3, 1.].map(|t| {
+            let (lam, phi) = slerp(a, b, t);
+            let (x, y, _) = p
+                .projection()
+                .forward(lam - 10f64.to_radians(), phi, 0.)
+                .unwrap();
+            (x, y)
+        });
+
+        // The projected points are collinear
+        let cross = (pts[1].0 - pts[0].0) * (pts[2].1 - pts[0].1)
+            - (pts[1].1 - pts[0].1) * (pts[2].0 - pts[0].0);
+        assert!(cross.abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,377 @@
+//!
+//! Modified stereographic projections
+//!
+//! ref: <https://proj.org/operations/projections/gs48.html>
+//!
+//! gs48: "Modified Stereographic of 48 U.S." "\n\tAzi(mod)"
+//! gs50: "Modified Stereographic of 50 U.S." "\n\tAzi(mod)"
+//! alsk: "Modified Stereographic of Alaska" "\n\tAzi(mod)"
+//!
+//! The oblique stereographic coordinates are transformed by a complex
+//! polynomial. Center, ellipsoid and coefficients are fixed by the
+//! definition of each projection: gs50 and alsk use the Clarke 1866
+//! ellipsoid, or a sphere of radius 6370997 m if a sphere is given.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{aasin, consts::FRAC_PI_2};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { gs48, gs50, alsk }
+
+const EPSLN: f64 = 1.0e-12;
+const MAX_ITER: usize = 20;
+
+/// Radius of the sphere
+const SPHERE_R: f64 = 6370997.;
+/// Clarke 1866 semi-major axis and eccentricity squared
+const CLRK66_A: f64 = 6378206.4;
+const CLRK66_ES: f64 = 0.00676866;
+
+/// Complex number as (real, imaginary)
+type Complex = (f64, f64);
+
+const GS48: [Complex; 5] = [
+    (0.98879, 0.),
+    (0., 0.),
+    (-0.050909, 0.),
+    (0., 0.),
+    (0.075528, 0.),
+];
+
+const GS50_E: [Complex; 10] = [
+    (0.9827497, 0.),
+    (0.0210669, 0.0053804),
+    (-0.1031415, -0.0571664),
+    (-0.0323337, -0.0322847),
+    (0.0502303, 0.1211983),
+    (0.0251805, 0.0895678),
+    (-0.0012315, -0.1416121),
+    (0.0072202, -0.1317091),
+    (-0.0194029, 0.0759677),
+    (-0.0210072, 0.0834037),
+];
+
+const GS50_S: [Complex; 10] = [
+    (0.9842990, 0.),
+    (0.0211642, 0.0037608),
+    (-0.1036018, -0.0575102),
+    (-0.0329095, -0.0320119),
+    (0.0499471, 0.1223335),
+    (0.0260460, 0.0899805),
+    (0.0007388, -0.1435792),
+    (0.0075848, -0.1334108),
+    (-0.0216473, 0.0776645),
+    (-0.0225161, 0.0853673),
+];
+
+const ALSK_E: [Complex; 6] = [
+    (0.9945303, 0.),
+    (0.0052083, -0.0027404),
+    (0.0072721, 0.0048181),
+    (-0.0151089, -0.1932526),
+    (0.0642675, -0.1381226),
+    (0.3582802, -0.2884586),
+];
+
+const ALSK_S: [Complex; 6] = [
+    (0.9972523, 0.),
+    (0.0052513, -0.0041175),
+    (0.0074606, 0.0048125),
+    (-0.0153783, -0.1968253),
+    (0.0636871, -0.1408027),
+    (0.3660976, -0.2937382),
+];
+
+#[inline]
+fn cmul(a: Complex, b: Complex) -> Complex {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+/// Evaluate z * (C[0] + C[1] z + ... + C[n] z^n)
+fn zpoly1(z: Complex, coefs: &[Complex]) -> Complex {
+    let a = coefs
+        .iter()
+        .rev()
+        .skip(1)
+        .fold(coefs[coefs.len() - 1], |a, c| {
+            let (r, i) = cmul(z, a);
+            (c.0 + r, c.1 + i)
+        });
+    cmul(z, a)
+}
+
+/// Evaluate the polynomial of [`zpoly1`] and its derivative
+fn zpolyd1(z: Complex, coefs: &[Complex]) -> (Complex, Complex) {
+    let n = coefs.len() - 1;
+    let mut a = coefs[n];
+    let mut b = a;
+    for (k, c) in coefs[..n].iter().enumerate().rev() {
+        if k + 1 < n {
+            let (r, i) = cmul(z, b);
+            b = (a.0 + r, a.1 + i);
+        }
+        let (r, i) = cmul(z, a);
+        a = (c.0 + r, c.1 + i);
+    }
+    let (r, i) = cmul(z, b);
+    b = (a.0 + r, a.1 + i);
+    (cmul(z, a), b)
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    zcoeff: &'static [Complex],
+    e: f64,
+    phi0: f64,
+    cchio: f64,
+    schio: f64,
+}
+
+impl Projection {
+    /// Conformal latitude
+    #[inline]
+    fn chi(phi: f64, e: f64) -> f64 {
+        let esphi = e * phi.sin();
+        2. * ((0.5 * (FRAC_PI_2 + phi)).tan() * ((1. - esphi) / (1. + esphi)).powf(e * 0.5)).atan()
+            - FRAC_PI_2
+    }
+
+    fn init(p: &mut ProjData, zcoeff: &'static [Complex], lon0: f64, lat0: f64) -> Result<Self> {
+        p.lam0 = lon0.to_radians();
+        p.phi0 = lat0.to_radians();
+        let e = p.ellps.e;
+        let chio = if p.ellps.is_ellipsoid() {
+            Self::chi(p.phi0, e)
+        } else {
+            p.phi0
+        };
+        Ok(Self {
+            zcoeff,
+            e,
+            phi0: p.phi0,
+            cchio: chio.cos(),
+            schio: chio.sin(),
+        })
+    }
+
+    pub fn gs48(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        p.ellps = Ellipsoid::sphere(SPHERE_R)?;
+        Self::init(p, &GS48, -96., 39.)
+    }
+
+    pub fn gs50(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        if p.ellps.is_ellipsoid() {
+            p.ellps = Ellipsoid::try_from_es(CLRK66_A, CLRK66_ES)?;
+            Self::init(p, &GS50_E, -120., 45.)
+        } else {
+            p.ellps = Ellipsoid::sphere(SPHERE_R)?;
+            Self::init(p, &GS50_S, -120., 45.)
+        }
+    }
+
+    pub fn alsk(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        if p.ellps.is_ellipsoid() {
+            p.ellps = Ellipsoid::try_from_es(CLRK66_A, CLRK66_ES)?;
+            Self::init(p, &ALSK_E, -152., 64.)
+        } else {
+            p.ellps = Ellipsoid::sphere(SPHERE_R)?;
+            Self::init(p, &ALSK_S, -152., 64.)
+        }
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinlon, coslon) = lam.sin_cos();
+        let (schi, cchi) = Self::chi(phi, self.e).sin_cos();
+        let denom = 1. + self.schio * schi + self.cchio * cchi * coslon;
+        if denom == 0. {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let s = 2. / denom;
+        let (x, y) = zpoly1(
+            (
+                s * cchi * sinlon,
+                s * (self.cchio * schi - self.schio * cchi * coslon),
+            ),
+            self.zcoeff,
+        );
+        Ok((x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // Newton-Raphson on the complex polynomial
+        let mut p = (x, y);
+        let mut i = MAX_ITER;
+        loop {
+            let (fxy, fpxy) = zpolyd1(p, self.zcoeff);
+            let (fr, fi) = (fxy.0 - x, fxy.1 - y);
+            let den = fpxy.0 * fpxy.0 + fpxy.1 * fpxy.1;
+            let dp = (
+                -(fr * fpxy.0 + fi * fpxy.1) / den,
+                -(fi * fpxy.0 - fr * fpxy.1) / den,
+            );
+            p = (p.0 + dp.0, p.1 + dp.1);
+            if dp.0.abs() + dp.1.abs() <= EPSLN {
+                break;
+            }
+            i -= 1;
+            if i == 0 {
+                return Err(Error::InverseProjectionFailure);
+            }
+        }
+
+        let rh = p.0.hypot(p.1);
+        if rh <= EPSLN {
+            return Ok((0., self.phi0, z));
+        }
+        let (sinz, cosz) = (2. * (0.5 * rh).atan()).sin_cos();
+        let chi = aasin(cosz * self.schio + p.1 * sinz * self.cchio / rh)?;
+
+        // Geodetic latitude from conformal latitude
+        let mut phi = chi;
+        let mut i = MAX_ITER;
+        loop {
+            let esphi = self.e * phi.sin();
+            let dphi = 2.
+                * ((0.5 * (FRAC_PI_2 + chi)).tan()
+                    * ((1. + esphi) / (1. - esphi)).powf(self.e * 0.5))
+                .atan()
+                - FRAC_PI_2
+                - phi;
+            phi += dphi;
+            if dphi.abs() <= EPSLN {
+                break;
+            }
+            i -= 1;
+            if i == 0 {
+                return Err(Error::InverseProjectionFailure);
+            }
+        }
+
+        let lam = (p.0 * sinz).atan2(rh * self.cchio * cosz - p.1 * self.schio * sinz);
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_gs48() {
+        let p = Proj::from_proj_string("+proj=gs48").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (-119., 40., 0.),
+                (-1923908.446529347, 355874.6589444787, 0.),
+            ),
+            (
+                (-70., 25., 0.),
+                (2621806.385925741, -1199686.6690566603, 0.),
+            ),
+            ((-87., 45., 0.), (701972.210539479, 696340.7122562404, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_gs50() {
+        let p = Proj::from_proj_string("+proj=gs50 +ellps=clrk66").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (-160., 65., 0.),
+                (-1874628.5377402327, 2660907.9422910144, 0.),
+            ),
+            (
+                (-130., 45., 0.),
+                (-771831.5188533344, 48465.16649130483, 0.),
+            ),
+            ((-80., 35., 0.), (3498441.843735656, -274715.9348787739, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+
+        let p = Proj::from_proj_string("+proj=gs50 +R=6370997").unwrap();
+
+        let inputs = [
+            (
+                (-160., 65., 0.),
+                (-1867268.2534600093, 2656506.230401824, 0.),
+            ),
+            (
+                (-130., 45., 0.),
+                (-769572.1896729926, 48324.31244086382, 0.),
+            ),
+            ((-80., 35., 0.), (3490469.241897691, -278082.534992637, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_alsk() {
+        let p = Proj::from_proj_string("+proj=alsk +ellps=clrk66").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (-160., 55., 0.),
+                (-513253.146950843, -968928.0318679453, 0.),
+            ),
+            (
+                (-160., 70., 0.),
+                (-305001.1338976377, 687494.4649586475, 0.),
+            ),
+            ((-145., 70., 0.), (266454.3050886006, 683423.4774930278, 0.)),
+            ((-145., 60., 0.), (389141.322439244, -423913.251230398, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+
+        let p = Proj::from_proj_string("+proj=alsk +R=6370997").unwrap();
+
+        let inputs = [
+            (
+                (-160., 55., 0.),
+                (-511510.3194108449, -967150.9916760764, 0.),
+            ),
+            (
+                (-160., 70., 0.),
+                (-303744.77129036956, 685439.7459411242, 0.),
+            ),
+            ((-145., 70., 0.), (265354.974019663, 681386.8928745739, 0.)),
+            (
+                (-145., 60., 0.),
+                (387711.99539402657, -422980.6855054605, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+}
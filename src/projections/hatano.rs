@@ -0,0 +1,116 @@
+//!
+//! Hatano Asymmetrical Equal Area
+//!
+//! ref: <https://proj.org/operations/projections/hatano.html>
+//!
+//! hatano: "Hatano Asymmetrical Equal Area" "\n\tPCyl., Sph."
+//!
+//! A Mollweide-like equal area projection using different constants
+//! for the northern and southern hemispheres. The auxiliary angle is
+//! found by Newton-Raphson in the forward direction, the inverse is
+//! closed-form.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::Result;
+use crate::math::{aasin_tol, newton};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { hatano }
+
+const NITER: usize = 20;
+const EPS: f64 = 1.0e-7;
+const ONETOL: f64 = 1.000001;
+
+// North and south constants
+const CN: f64 = 2.67595;
+const CS: f64 = 2.43763;
+const RCN: f64 = 0.3736990601468637;
+const RCS: f64 = 0.4102345310814193;
+const FYCN: f64 = 1.75859;
+const FYCS: f64 = 1.93052;
+const RYCN: f64 = 0.5686373742600607;
+const RYCS: f64 = 0.5179951515653813;
+const FXC: f64 = 0.85;
+const RXC: f64 = 1.1764705882352942;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn hatano(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let c = phi.sin() * if phi < 0. { CS } else { CN };
+        // Like proj, use the last approximation on failure
+        let th = 0.5
+            * newton(phi, EPS, NITER, |th| (th + th.sin() - c) / (1. + th.cos()))
+                .unwrap_or_else(|th| th);
+        Ok((
+            FXC * lam * th.cos(),
+            th.sin() * if th < 0. { FYCS } else { FYCN },
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let th = aasin_tol(y * if y < 0. { RYCS } else { RYCN }, ONETOL)?;
+        let lam = RXC * x / th.cos();
+        let th = 2. * th;
+        let phi = aasin_tol((th + th.sin()) * if y < 0. { RCS } else { RCN }, ONETOL)?;
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_hatano() {
+        let p = Proj::from_proj_string("+proj=hatano +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Northern and southern hemispheres are not symmetric
+        let inputs = [
+            ((2., 1., 0.), (189878.87894652804, 131409.8024406255, 0.)),
+            ((2., -1., 0.), (189881.08195244466, -131409.14227607418, 0.)),
+            ((-2., 1., 0.), (-189878.87894652804, 131409.8024406255, 0.)),
+            (
+                (-2., -1., 0.),
+                (-189881.08195244466, -131409.14227607418, 0.),
+            ),
+            ((30., 45., 0.), (2476655.433468928, 5559263.652200762, 0.)),
+            (
+                (-120., -60., 0.),
+                (-9452492.492709205, -6897993.903913585, 0.),
+            ),
+            ((60., 80., 0.), (3891605.3068388086, 8219507.446584481, 0.)),
+            (
+                (170., -85., 0.),
+                (12149334.059938606, -8134167.528088228, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+}
@@ -0,0 +1,684 @@
+//!
+//! Icosahedral Snyder Equal Area
+//!
+//! ref: <https://proj.org/operations/projections/isea.html>
+//!
+//! isea: "Icosahedral Snyder Equal Area" "\n\tSph"
+//!
+//! The `mode` parameter select the output of the projection:
+//!
+//! * `plane`: plane coordinates (the default)
+//! * `di`: quad and (d, i) cell indices
+//! * `dd`: quad (d, d) coordinates
+//! * `hex`: global hexagon coordinates
+//!
+//! `aperture` (3 or 4, default to 3) and `resolution` (default to 4)
+//! define the grid used for cell indexing.
+//!
+use crate::errors::{Error, Result};
+use crate::math::consts::{FRAC_PI_2, PI, TAU};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { isea }
+
+/// Output of the projection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum IseaMode {
+    Plane,
+    Di,
+    Dd,
+    Hex,
+}
+
+impl IseaMode {
+    fn from_param(mode: &str) -> Result<Self> {
+        match mode {
+            "plane" => Ok(Self::Plane),
+            "di" => Ok(Self::Di),
+            "dd" => Ok(Self::Dd),
+            "hex" => Ok(Self::Hex),
+            _ => Err(Error::InvalidParameterValue(
+                "Invalid value for mode: only plane, di, dd or hex are supported",
+            )),
+        }
+    }
+}
+
+// Standard orientation
+const ISEA_STD_LAT: f64 = 1.0172219679233507;
+const ISEA_STD_LON: f64 = 0.19634954084936207;
+
+// sqrt(5)/PI
+const ISEA_SCALE: f64 = 0.8301572857837594;
+
+// 26.565051177 degrees
+const V_LAT: f64 = 0.46364760899944494;
+
+// 52.62263186 degrees
+const E_RAD: f64 = 0.9184381870218678;
+
+// 10.81231696 degrees
+const F_RAD: f64 = 0.18871053072122403;
+
+const DEG36: f64 = PI / 5.;
+const DEG72: f64 = 2. * PI / 5.;
+const DEG108: f64 = 3. * PI / 5.;
+const DEG120: f64 = 2. * PI / 3.;
+const DEG144: f64 = 4. * PI / 5.;
+
+// R tan(g) sin(60)
+const TABLE_G: f64 = 0.6615845383;
+
+// H = 0.25 R tan g
+const TABLE_H: f64 = 0.1909830056;
+
+// R' in Snyder's paper is for the truncated icosahedron
+const RPRIME: f64 = 0.9103832815309029;
+
+// Snyder constants for the icosahedron: g, G and theta
+const SNYDER_G: f64 = 37.37736814 * PI / 180.;
+const SNYDER_BIG_G: f64 = 36. * PI / 180.;
+const SNYDER_THETA: f64 = 30. * PI / 180.;
+
+// Maximum resolution for cell indexing
+const MAX_RESOLUTION: i32 = 30;
+const MAX_HEX_RESOLUTION: i32 = 26;
+
+/// Icosahedron vertices (lon, lat)
+const VERTEX: [(f64, f64); 12] = [
+    (0.0, FRAC_PI_2),
+    (PI, V_LAT),
+    (-DEG108, V_LAT),
+    (-DEG36, V_LAT),
+    (DEG36, V_LAT),
+    (DEG108, V_LAT),
+    (-DEG144, -V_LAT),
+    (-DEG72, -V_LAT),
+    (0.0, -V_LAT),
+    (DEG72, -V_LAT),
+    (DEG144, -V_LAT),
+    (0.0, -FRAC_PI_2),
+];
+
+const TRI_V1: [usize; 21] = [
+    0, 0, 0, 0, 0, 0, 6, 7, 8, 9, 10, 2, 3, 4, 5, 1, 11, 11, 11, 11, 11,
+];
+
+/// Triangle centers (lon, lat)
+const ICOSTRIANGLES: [(f64, f64); 21] = [
+    (0.0, 0.0),
+    (-DEG144, E_RAD),
+    (-DEG72, E_RAD),
+    (0.0, E_RAD),
+    (DEG72, E_RAD),
+    (DEG144, E_RAD),
+    (-DEG144, F_RAD),
+    (-DEG72, F_RAD),
+    (0.0, F_RAD),
+    (DEG72, F_RAD),
+    (DEG144, F_RAD),
+    (-DEG108, -F_RAD),
+    (-DEG36, -F_RAD),
+    (DEG36, -F_RAD),
+    (DEG108, -F_RAD),
+    (PI, -F_RAD),
+    (-DEG108, -E_RAD),
+    (-DEG36, -E_RAD),
+    (DEG36, -E_RAD),
+    (DEG108, -E_RAD),
+    (PI, -E_RAD),
+];
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    o_lat: f64,
+    o_lon: f64,
+    o_az: f64,
+    aperture: i32,
+    resolution: i32,
+    radius: f64,
+    mode: IseaMode,
+}
+
+impl Projection {
+    pub fn isea(_: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let (mut o_lat, mut o_lon) = match params.try_value::<&str>("orient")? {
+            Some("isea") | None => (ISEA_STD_LAT, ISEA_STD_LON),
+            Some("pole") => (FRAC_PI_2, 0.),
+            Some(_) => {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for orient: only isea or pole are supported",
+                ))
+            }
+        };
+
+        let o_az = params.try_angular_value("azi")?.unwrap_or(0.);
+
+        if let Some(lon_0) = params.try_angular_value("lon_0")? {
+            o_lon = lon_0;
+        }
+        if let Some(lat_0) = params.try_angular_value("lat_0")? {
+            o_lat = lat_0;
+        }
+
+        let mode = params
+            .try_value::<&str>("mode")?
+            .map(IseaMode::from_param)
+            .transpose()?
+            .unwrap_or(IseaMode::Plane);
+
+        let radius = if params.check_option("rescale")? {
+            ISEA_SCALE
+        } else {
+            1.0
+        };
+
+        let aperture = params.try_value("aperture")?.unwrap_or(3);
+        if aperture != 3 && aperture != 4 {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for aperture: only 3 or 4 are supported",
+            ));
+        }
+
+        let resolution = params.try_value("resolution")?.unwrap_or(4);
+        if !(0..=MAX_RESOLUTION).contains(&resolution) {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for resolution: it should be in the range [0, 30]",
+            ));
+        }
+        // Hexagon coordinates are encoded with the quad number
+        // in a 32 bits integer.
+        if mode == IseaMode::Hex && resolution > MAX_HEX_RESOLUTION {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for resolution: it should be less than 27 with hex mode",
+            ));
+        }
+
+        Ok(Self {
+            o_lat,
+            o_lon,
+            o_az,
+            aperture,
+            resolution,
+            radius,
+            mode,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (tri, mut pt) = self.transform(lam, phi)?;
+
+        if self.mode == IseaMode::Plane {
+            let (x, y) = tri_plane(tri, pt, self.radius);
+            return Ok((x, y, z));
+        }
+
+        // convert to isea standard triangle size
+        pt.0 = pt.0 / self.radius * ISEA_SCALE + 0.5;
+        pt.1 = pt.1 / self.radius * ISEA_SCALE + 2.0 * 0.14433756729740644;
+
+        let (x, y) = match self.mode {
+            IseaMode::Dd => ptdd(tri, pt).1,
+            IseaMode::Di => self.ptdi(tri, pt)?.1,
+            IseaMode::Hex => {
+                let (quad, (d, i)) = self.ptdi(tri, pt)?;
+                if d < (i32::MIN >> 4) as f64 || d > (i32::MAX >> 4) as f64 {
+                    return Err(Error::CoordTransOutsideProjectionDomain);
+                }
+                (((d as i32) * 16 + quad) as f64, i)
+            }
+            IseaMode::Plane => unreachable!(),
+        };
+        Ok((x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, _: f64, _: f64, _: f64) -> Result<(f64, f64, f64)> {
+        Err(Error::NoInverseProjectionDefined)
+    }
+
+    pub const fn has_inverse() -> bool {
+        false
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+
+    // Return the triangle and the coordinates in the triangle
+    fn transform(&self, lam: f64, phi: f64) -> Result<(i32, (f64, f64))> {
+        let (lon, lat) = ctran((self.o_lon, self.o_lat), (lam, phi), self.o_az);
+        let (tri, (x, y)) = snyder_forward(lon, lat)?;
+        Ok((tri, (x * self.radius, y * self.radius)))
+    }
+
+    // Return the quad and the (d, i) coordinates
+    fn ptdi(&self, tri: i32, pt: (f64, f64)) -> Result<(i32, (f64, f64))> {
+        let (quad, v) = ptdd(tri, pt);
+        self.dddi(quad, v)
+    }
+
+    fn dddi(&self, mut quad: i32, pt: (f64, f64)) -> Result<(i32, (f64, f64))> {
+        if self.aperture == 3 && self.resolution % 2 != 0 {
+            return self.dddi_ap3odd(quad, pt);
+        }
+
+        let sidelength = (self.aperture as f64).powf(self.resolution as f64 / 2.0);
+        if sidelength > i32::MAX as f64 {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let sidelength = (sidelength + 0.5) as i64;
+        let hexwidth = 1.0 / sidelength as f64;
+
+        let (x, y) = rotate(pt, -30.0);
+        let (mut hx, hy, mut hz) = hex_iso(hexbin2(hexwidth, x, y)?);
+
+        // we may actually be on another quad
+        if quad <= 5 {
+            if hx == 0 && hz == -sidelength {
+                // north pole
+                quad = 0;
+                (hx, hz) = (0, 0);
+            } else if hz == -sidelength {
+                quad += 1;
+                if quad == 6 {
+                    quad = 1;
+                }
+                hz = hx - sidelength;
+                hx = 0;
+            } else if hx == sidelength {
+                quad += 5;
+                hx = 0;
+            }
+        } else if quad >= 6 {
+            if hz == 0 && hx == sidelength {
+                // south pole
+                quad = 11;
+                (hx, hz) = (0, 0);
+            } else if hx == sidelength {
+                quad += 1;
+                if quad == 11 {
+                    quad = 6;
+                }
+                hx = hy + sidelength;
+                hz = -hx;
+            } else if hy == -sidelength {
+                quad -= 4;
+                hz = -hx;
+            }
+        }
+
+        Ok((quad, (hx as f64, -hz as f64)))
+    }
+
+    fn dddi_ap3odd(&self, mut quad: i32, pt: (f64, f64)) -> Result<(i32, (f64, f64))> {
+        // This is the number of hexes from apex to base of a triangle
+        let sidelength = (2f64.powi(self.resolution) + 1.0) / 2.0;
+
+        // apex to base is cos(30deg)
+        let hexwidth = (PI / 6.0).cos() / sidelength;
+
+        let maxcoord = (sidelength * 2.0 + 0.5) as i64;
+
+        let (hx, hy, hz) = hex_iso(hexbin2(hexwidth, pt.0, pt.1)?);
+
+        let mut d = hx - hz;
+        let mut i = hx + hy + hy;
+
+        // you want to test for max coords for the next quad in the same
+        // "row" first to get the case where both are max
+        if quad <= 5 {
+            if d == 0 && i == maxcoord {
+                // north pole
+                quad = 0;
+                d = 0;
+                i = 0;
+            } else if i == maxcoord {
+                // upper right in next quad
+                quad += 1;
+                if quad == 6 {
+                    quad = 1;
+                }
+                i = maxcoord - d;
+                d = 0;
+            } else if d == maxcoord {
+                // lower right in quad to lower right
+                quad += 5;
+                d = 0;
+            }
+        } else if quad >= 6 {
+            if i == 0 && d == maxcoord {
+                // south pole
+                quad = 11;
+                d = 0;
+                i = 0;
+            } else if d == maxcoord {
+                // lower right in next quad
+                quad += 1;
+                if quad == 11 {
+                    quad = 6;
+                }
+                d = maxcoord - i;
+                i = 0;
+            } else if i == maxcoord {
+                // upper right in quad to upper right
+                quad = (quad - 4) % 5;
+                i = 0;
+            }
+        }
+
+        Ok((quad, (d as f64, i as f64)))
+    }
+}
+
+#[inline]
+fn is_downtri(tri: i32) -> bool {
+    ((tri - 1) / 5) % 2 == 1
+}
+
+#[inline]
+fn normalize_lon(mut lon: f64) -> f64 {
+    lon %= TAU;
+    while lon > PI {
+        lon -= TAU;
+    }
+    while lon < -PI {
+        lon += TAU;
+    }
+    lon
+}
+
+fn az_adjustment(triangle: usize) -> f64 {
+    let v = VERTEX[TRI_V1[triangle]];
+    let c = ICOSTRIANGLES[triangle];
+
+    // looks like the adjustment is always either 0 or 180
+    sph_azimuth(c, v)
+}
+
+fn triangle_xy(triangle: i32) -> (f64, f64) {
+    let triangle = (triangle - 1) % 20;
+
+    let mut x = TABLE_G * ((triangle % 5) - 2) as f64 * 2.0;
+    if triangle > 9 {
+        x += TABLE_G;
+    }
+    let y = match triangle / 5 {
+        0 => 5.0 * TABLE_H,
+        1 => TABLE_H,
+        2 => -TABLE_H,
+        _ => -5.0 * TABLE_H,
+    };
+    (x * RPRIME, y * RPRIME)
+}
+
+// Snyder eq 14
+fn sph_azimuth(f: (f64, f64), t: (f64, f64)) -> f64 {
+    (t.1.cos() * (t.0 - f.0).sin())
+        .atan2(f.1.cos() * t.1.sin() - f.1.sin() * t.1.cos() * (t.0 - f.0).cos())
+}
+
+// Return the triangle and the coordinates relative to
+// the triangle center
+fn snyder_forward(lon: f64, lat: f64) -> Result<(i32, (f64, f64))> {
+    let cot_theta = 1.0 / SNYDER_THETA.tan();
+    let tan_g = SNYDER_G.tan();
+
+    for (i, center) in ICOSTRIANGLES.iter().enumerate().skip(1) {
+        // step 1
+        let z = (center.1.sin() * lat.sin() + center.1.cos() * lat.cos() * (lon - center.0).cos())
+            .acos();
+
+        // not on this triangle
+        if z > SNYDER_G + 0.000005 {
+            continue;
+        }
+
+        // step 2
+        let mut az = sph_azimuth(*center, (lon, lat)) - az_adjustment(i);
+        if az < 0.0 {
+            az += TAU;
+        }
+
+        // adjust az for the point to fall within the range of 0 to
+        // 120 degrees for the triangle of the icosahedron by
+        // subtracting or adding multiples of 120 degrees to az and
+        // recording the amount of adjustment
+        let mut az_adjust_multiples = 0;
+        while az < 0.0 {
+            az += DEG120;
+            az_adjust_multiples -= 1;
+        }
+        while az > DEG120 + f64::EPSILON {
+            az -= DEG120;
+            az_adjust_multiples += 1;
+        }
+
+        // step 3: q from eq 9.
+        let q = tan_g.atan2(az.cos() + az.sin() * cot_theta);
+
+        // not in this triangle
+        if z > q + 0.000005 {
+            continue;
+        }
+
+        // step 4: apply equations 5-8 and 10-12 in order
+
+        // eq 6
+        let h =
+            (az.sin() * SNYDER_BIG_G.sin() * SNYDER_G.cos() - az.cos() * SNYDER_BIG_G.cos()).acos();
+
+        // eq 7
+        let ag = az + SNYDER_BIG_G + h - PI;
+
+        // eq 8
+        let mut azprime = (2.0 * ag).atan2(RPRIME * RPRIME * tan_g * tan_g - 2.0 * ag * cot_theta);
+
+        // eq 10
+        let dprime = RPRIME * tan_g / (azprime.cos() + azprime.sin() * cot_theta);
+
+        // eq 11
+        let f = dprime / (2.0 * RPRIME * (q / 2.0).sin());
+
+        // eq 12
+        let rho = 2.0 * RPRIME * f * (z / 2.0).sin();
+
+        // add back the same 120 degree multiple adjustment from step 2
+        azprime += DEG120 * az_adjust_multiples as f64;
+
+        return Ok((i as i32, (rho * azprime.sin(), rho * azprime.cos())));
+    }
+
+    // The coordinate is not on any triangle
+    Err(Error::CoordTransOutsideProjectionDomain)
+}
+
+// Return the coordinates of `pt` in a coordinate system
+// where `np` is the north pole.
+//
+// Formula from Snyder, Map Projections: A working manual, p31
+fn snyder_ctran(np: (f64, f64), pt: (f64, f64)) -> (f64, f64) {
+    let (lambda, phi) = pt;
+    let (beta, alpha) = np;
+
+    let cos_p = phi.cos();
+    let sin_a = alpha.sin();
+
+    // mpawm 5-7
+    let sin_phip = sin_a * phi.sin() - alpha.cos() * cos_p * (lambda - beta).cos();
+
+    // mpawm 5-8b
+    let lp_b = (cos_p * (lambda - beta).sin())
+        .atan2(sin_a * cos_p * (lambda - beta).cos() + alpha.cos() * phi.sin());
+
+    (normalize_lon(lp_b + beta), sin_phip.asin())
+}
+
+fn ctran(np: (f64, f64), pt: (f64, f64), lon0: f64) -> (f64, f64) {
+    let (lon, lat) = snyder_ctran((np.0 + PI, np.1), pt);
+
+    // snyder is down tri 3, isea is along side of tri1 from vertex 0 to
+    // vertex 1 these are 180 degrees apart
+    (normalize_lon(lon - (PI - lon0 + np.0) + PI), lat)
+}
+
+fn rotate(pt: (f64, f64), degrees: f64) -> (f64, f64) {
+    let mut rad = -degrees.to_radians();
+    while rad >= TAU {
+        rad -= TAU;
+    }
+    while rad <= -TAU {
+        rad += TAU;
+    }
+    let (sin, cos) = rad.sin_cos();
+    (pt.0 * cos + pt.1 * sin, -pt.0 * sin + pt.1 * cos)
+}
+
+fn tri_plane(tri: i32, mut pt: (f64, f64), radius: f64) -> (f64, f64) {
+    if is_downtri(tri) {
+        pt = rotate(pt, 180.0);
+    }
+    let tc = triangle_xy(tri);
+    (pt.0 + tc.0 * radius, pt.1 + tc.1 * radius)
+}
+
+// convert projected triangle coords to quad xy coords, return quad number
+fn ptdd(tri: i32, pt: (f64, f64)) -> (i32, (f64, f64)) {
+    let downtri = is_downtri(tri);
+    let quad = ((tri - 1) % 5) + ((tri - 1) / 10) * 5 + 1;
+
+    let (mut x, mut y) = rotate(pt, if downtri { 240.0 } else { 60.0 });
+    if downtri {
+        x += 0.5;
+        // cos(30deg)
+        y += 0.8660254037844386;
+    }
+    (quad, (x, y))
+}
+
+// Convert hex coordinates to iso coordinates
+fn hex_iso((x, y): (i64, i64)) -> (i64, i64, i64) {
+    let y = if x >= 0 { -y - (x + 1) / 2 } else { -y - x / 2 };
+    (x, y, -x - y)
+}
+
+// Convert iso coordinates to hex coordinates
+fn hex_xy((x, y): (i64, i64)) -> (i64, i64) {
+    if x >= 0 {
+        (x, -y - (x + 1) / 2)
+    } else {
+        (x, -y - x / 2)
+    }
+}
+
+fn hexbin2(width: f64, x: f64, y: f64) -> Result<(i64, i64)> {
+    let x = x / (30f64).to_radians().cos(); // rotated X coord
+    let y = y - x / 2.0; // adjustment for rotated X
+
+    // adjust for actual hexwidth
+    if width == 0. {
+        return Err(Error::CoordTransOutsideProjectionDomain);
+    }
+    let x = x / width;
+    let y = y / width;
+    let z = -x - y;
+
+    let rx = (x + 0.5).floor();
+    let ry = (y + 0.5).floor();
+    let rz = (z + 0.5).floor();
+
+    if (rx + ry).abs() > i32::MAX as f64 || (rx + ry + rz).abs() > i32::MAX as f64 {
+        return Err(Error::CoordTransOutsideProjectionDomain);
+    }
+
+    let (mut ix, mut iy, iz) = (rx as i64, ry as i64, rz as i64);
+
+    let s = ix + iy + iz;
+    if s != 0 {
+        let abs_dx = (rx - x).abs();
+        let abs_dy = (ry - y).abs();
+        let abs_dz = (rz - z).abs();
+
+        if abs_dx >= abs_dy && abs_dx >= abs_dz {
+            ix -= s;
+        } else if abs_dy >= abs_dx && abs_dy >= abs_dz {
+            iy -= s;
+        }
+    }
+
+    Ok(hex_xy((ix, iy)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IseaMode;
+    use crate::errors::Error;
+    use crate::proj::Proj;
+    use crate::tests::utils::test_proj_forward;
+
+    #[test]
+    fn proj_isea() {
+        let p = Proj::from_proj_string("+proj=isea +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (-1097074.948022474, 3442909.309037183, 0.)),
+            ((2., -1., 0.), (-1097074.948264795, 3233611.728585708, 0.)),
+            ((-2., 1., 0.), (-1575486.353641554, 3442168.342028188, 0.)),
+            ((-2., -1., 0.), (-1575486.353880179, 3234352.695594706, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+
+    #[test]
+    fn proj_isea_modes() {
+        for (mode, expected) in [
+            ("plane", IseaMode::Plane),
+            ("di", IseaMode::Di),
+            ("dd", IseaMode::Dd),
+            ("hex", IseaMode::Hex),
+        ] {
+            assert_eq!(IseaMode::from_param(mode).unwrap(), expected);
+            for aperture in [3, 4] {
+                let p = Proj::from_proj_string(&format!(
+                    "+proj=isea +R=1 +mode={mode} +aperture={aperture} +resolution=5"
+                ))
+                .unwrap();
+                let (x, y, _) = p.projection().forward(0.1, 0.2, 0.).unwrap();
+                if mode == "di" || mode == "hex" {
+                    // Cell indices
+                    assert_eq!((x.fract(), y.fract()), (0., 0.));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn proj_isea_invalid() {
+        for projstr in [
+            "+proj=isea +mode=foo",
+            "+proj=isea +orient=foo",
+            "+proj=isea +aperture=2",
+            "+proj=isea +aperture=7 +mode=di",
+            "+proj=isea +resolution=-1",
+            "+proj=isea +resolution=31 +mode=di",
+            "+proj=isea +resolution=27 +mode=hex",
+        ] {
+            assert!(
+                matches!(
+                    Proj::from_proj_string(projstr),
+                    Err(Error::InvalidParameterValue(_))
+                ),
+                "{projstr}"
+            );
+        }
+
+        assert!(Proj::from_proj_string("+proj=isea +resolution=30 +mode=di").is_ok());
+        assert!(Proj::from_proj_string("+proj=isea +resolution=26 +mode=hex").is_ok());
+    }
+}
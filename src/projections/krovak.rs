@@ -0,0 +1,231 @@
+//!
+//! Krovak
+//!
+//! ref: <https://proj.org/operations/projections/krovak.html>
+//!
+//! krovak: "Krovak" "\n\tPCyl., Ell."
+//!
+//! Oblique conformal conic projection used in the Czech Republic
+//! and Slovakia (S-JTSK). As in proj, the Bessel ellipsoid is always
+//! used and the defaults are those of the S-JTSK: `lat_0=49.5`,
+//! `lon_0=24.8333333333333` (42°30' east of Ferro, that is 24°50' east
+//! of Greenwich) and `k=0.9999`.
+//!
+//! By default, the axes are oriented towards east and north, that is
+//! the opposite of the Westing and Southing of the S-JTSK.
+//! With `+czech`, the signs are reversed and the output is
+//! (Westing, Southing).
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{FRAC_PI_2, FRAC_PI_4};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { krovak }
+
+const EPS: f64 = 1e-15;
+// Latitude of the cone axis: 59°42'42.69689"
+const UQ: f64 = 1.04216856380474;
+// Latitude of the pseudo standard parallel: 78°30'
+const S0: f64 = 1.37008346281555;
+const MAX_ITER: usize = 100;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    e: f64,
+    alpha: f64,
+    k: f64,
+    n: f64,
+    rho0: f64,
+    ad: f64,
+    czech: f64,
+}
+
+impl Projection {
+    pub fn krovak(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        // Bessel is the fixed ellipsoid
+        p.ellps = Ellipsoid::try_from_es(6377397.155, 0.006674372230614)?;
+
+        // Latitude of the projection center: 49°30'N
+        if params.get("lat_0").is_none() {
+            p.phi0 = 0.863937979737193;
+        }
+        // Longitude of the projection center: 42°30' east of Ferro,
+        // Ferro being 17°40' west of Greenwich, that is 24°50' east
+        // of Greenwich
+        if params.get("lon_0").is_none() {
+            p.lam0 = 0.7417649320975901 - 0.308341501185665;
+        }
+        if ["k", "k_0", "k0"].iter().all(|k| params.get(k).is_none()) {
+            p.k0 = 0.9999;
+        }
+
+        let czech = if params.check_option("czech")? {
+            1.
+        } else {
+            -1.
+        };
+
+        let el = &p.ellps;
+        let (e, es) = (el.e, el.es);
+        let sinphi0 = p.phi0.sin();
+
+        let alpha = (1. + (es * p.phi0.cos().powi(4)) / (1. - es)).sqrt();
+        let u0 = (sinphi0 / alpha).asin();
+        let g = ((1. + e * sinphi0) / (1. - e * sinphi0)).powf(alpha * e / 2.);
+        let tan_half_phi0 = (p.phi0 / 2. + FRAC_PI_4).tan();
+        if tan_half_phi0 == 0. {
+            return Err(Error::InvalidParameterValue("Invalid value for lat_0"));
+        }
+        let k = (u0 / 2. + FRAC_PI_4).tan() / tan_half_phi0.powf(alpha) * g;
+        let n0 = (1. - es).sqrt() / (1. - es * sinphi0 * sinphi0);
+        let n = S0.sin();
+
+        Ok(Self {
+            e,
+            alpha,
+            k,
+            n,
+            rho0: p.k0 * n0 / S0.tan(),
+            ad: FRAC_PI_2 - UQ,
+            czech,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let esinphi = self.e * phi.sin();
+        let gfi = ((1. + esinphi) / (1. - esinphi)).powf(self.alpha * self.e / 2.);
+
+        let u = 2.
+            * ((self.k * (phi / 2. + FRAC_PI_4).tan().powf(self.alpha) / gfi).atan() - FRAC_PI_4);
+        // Longitudes are counted positively westwards
+        let deci = -self.alpha * lam;
+
+        let (sinad, cosad) = self.ad.sin_cos();
+        let s = (cosad * u.sin() + sinad * u.cos() * deci.cos()).asin();
+        let d = (u.cos() * deci.sin() / s.cos()).asin();
+
+        let eps = self.n * d;
+        let rho = self.rho0 * (S0 / 2. + FRAC_PI_4).tan().powf(self.n)
+            / (s / 2. + FRAC_PI_4).tan().powf(self.n);
+
+        Ok((
+            self.czech * rho * eps.sin(),
+            self.czech * rho * eps.cos(),
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (x, y) = (self.czech * x, self.czech * y);
+
+        let rho = x.hypot(y);
+        let eps = x.atan2(y);
+
+        let d = eps / S0.sin();
+        let s = if rho == 0. {
+            FRAC_PI_2
+        } else {
+            2. * (((self.rho0 / rho).powf(1. / self.n) * (S0 / 2. + FRAC_PI_4).tan()).atan()
+                - FRAC_PI_4)
+        };
+
+        let (sinad, cosad) = self.ad.sin_cos();
+        let u = (cosad * s.sin() - sinad * s.cos() * d.cos()).asin();
+        let deci = (s.cos() * d.sin() / u.cos()).asin();
+
+        let lam = -deci / self.alpha;
+
+        // Iteration for the latitude
+        let c = self.k.powf(-1. / self.alpha) * (u / 2. + FRAC_PI_4).tan().powf(1. / self.alpha);
+        let mut fi1 = u;
+        let mut i = MAX_ITER;
+        loop {
+            let esinfi = self.e * fi1.sin();
+            let phi =
+                2. * ((c * ((1. + esinfi) / (1. - esinfi)).powf(self.e / 2.)).atan() - FRAC_PI_4);
+            if (fi1 - phi).abs() < EPS {
+                break Ok((lam, phi, z));
+            }
+            fi1 = phi;
+            i -= 1;
+            if i == 0 {
+                break Err(Error::CoordTransOutsideProjectionDomain);
+            }
+        }
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_krovak() {
+        // The Bessel ellipsoid is used whatever the ellipsoid parameter
+        let p = Proj::from_proj_string("+proj=krovak +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (-3196535.232563641, -6617878.867551444, 0.)),
+            ((2., -1., 0.), (-3260035.440552109, -6898873.614878031, 0.)),
+            ((-2., 1., 0.), (-3756305.328869175, -6478142.561571511, 0.)),
+            ((-2., -1., 0.), (-3831703.658501982, -6759107.170155565, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_krovak_czech() {
+        // EPSG Guidance Note 7-2, example for Krovak (EPSG:9819)
+        let input = (
+            (
+                16. + 50. / 60. + 59.179 / 3600.,
+                50. + 12. / 60. + 32.442 / 3600.,
+                0.,
+            ),
+            (568990.99543731, 1050538.63084608, 0.),
+        );
+
+        let p = Proj::from_proj_string("+proj=krovak +ellps=bessel +czech").unwrap();
+        test_proj_forward(&p, &[input], 1.0e-6);
+        test_proj_inverse(&p, &[input], EPS_10);
+
+        // Old Town Square in Prague, with and without the Czech
+        // axis orientation (EPSG:5514)
+        let (lonlat, (x, y, _)) = (
+            (14.4208, 50.0875, 0.),
+            (742930.62097368, 1043080.19180927, 0.),
+        );
+
+        let p = Proj::from_proj_string("+proj=krovak +ellps=bessel +czech").unwrap();
+        test_proj_forward(&p, &[(lonlat, (x, y, 0.))], 1.0e-6);
+        test_proj_inverse(&p, &[(lonlat, (x, y, 0.))], EPS_10);
+
+        let p = Proj::from_proj_string(concat!(
+            "+proj=krovak +lat_0=49.5 +lon_0=24.83333333333333 +alpha=30.28813972222222",
+            " +k=0.9999 +x_0=0 +y_0=0 +ellps=bessel",
+            " +towgs84=589,76,480,0,0,0,0 +units=m +no_defs",
+        ))
+        .unwrap();
+        test_proj_forward(&p, &[(lonlat, (-x, -y, 0.))], 1.0e-6);
+        test_proj_inverse(&p, &[(lonlat, (-x, -y, 0.))], EPS_10);
+    }
+}
@@ -15,7 +15,7 @@ use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
 // Projection stub
-super::projection! { laea }
+super::projection! { laea; inverse_valid_domain }
 
 #[derive(Debug, Clone)]
 pub(crate) enum Projection {
@@ -48,6 +48,15 @@ impl Projection {
         }
     }
 
+    /// Check that (x, y) is within the radius of the
+    /// projected sphere (resp. ellipsoid).
+    pub fn inverse_valid_domain(&self, x: f64, y: f64) -> bool {
+        match self {
+            Self::El(p) => p.inverse_valid_domain(x, y),
+            Self::Sp(_) => x.hypot(y) <= 2. + EPS_10,
+        }
+    }
+
     pub const fn has_inverse() -> bool {
         true
     }
@@ -166,7 +175,7 @@ impl EProj {
                 b = (2. / b).sqrt();
                 (
                     xmf * b * cosb * sinlam,
-                    ymf * b * cosb1 * sinb - sinb1 * cosb * coslam,
+                    ymf * b * (cosb1 * sinb - sinb1 * cosb * coslam),
                 )
             }
             EQUIT { xmf, ymf, .. } => {
@@ -192,7 +201,7 @@ impl EProj {
                 }
             }
             S_POLE => {
-                if (phi - FRAC_PI_2) < EPS_10 {
+                if (phi - FRAC_PI_2).abs() < EPS_10 {
                     return Err(Error::ToleranceConditionError);
                 }
                 let q = self.qp + q;
@@ -208,6 +217,17 @@ impl EProj {
         Ok((x, y, z))
     }
 
+    fn inverse_valid_domain(&self, x: f64, y: f64) -> bool {
+        use EMode::*;
+
+        match self.mode {
+            EQUIT { dd, rq, .. } | OBLIQ { dd, rq, .. } => {
+                (x / dd).hypot(y * dd) <= 2. * rq + EPS_10
+            }
+            N_POLE | S_POLE => x * x + y * y <= 2. * self.qp + EPS_10,
+        }
+    }
+
     fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
         use EMode::*;
 
@@ -218,7 +238,7 @@ impl EProj {
                 if rho < EPS_10 {
                     return Ok((0., self.phi0, z));
                 }
-                let (sce, cce) = (2. * (0.5 * rho / rq).asin()).sin_cos();
+                let (sce, cce) = (2. * asin_antipode(0.5 * rho / rq)?).sin_cos();
                 (y * sce / rho, x * sce, rho * cce)
             }
             OBLIQ {
@@ -233,7 +253,7 @@ impl EProj {
                 if rho < EPS_10 {
                     return Ok((0., self.phi0, z));
                 }
-                let (sce, cce) = (2. * (0.5 * rho / rq).asin()).sin_cos();
+                let (sce, cce) = (2. * asin_antipode(0.5 * rho / rq)?).sin_cos();
                 (
                     cce * sinb1 + y * sce * cosb1 / rho,
                     x * sce,
@@ -246,6 +266,9 @@ impl EProj {
                     return Ok((0., self.phi0, z));
                 }
                 let ab = 1. - q / self.qp;
+                if ab < -1. - EPS_10 {
+                    return Err(Error::ToleranceConditionError);
+                }
                 if matches!(self.mode, N_POLE) {
                     (ab, x, -y)
                 } else {
@@ -253,7 +276,21 @@ impl EProj {
                 }
             }
         };
-        Ok((xx.atan2(yy), authlat(ab.asin(), self.apa), z))
+        Ok((xx.atan2(yy), authlat(ab.clamp(-1., 1.).asin(), self.apa), z))
+    }
+}
+
+/// asin of the half angular distance, the antipode of the center
+/// is at 1: reject points beyond the antipode instead of returning NaN.
+#[inline]
+fn asin_antipode(v: f64) -> Result<f64> {
+    if v > 1. {
+        if v - 1. > EPS_10 {
+            return Err(Error::ToleranceConditionError);
+        }
+        Ok(FRAC_PI_2)
+    } else {
+        Ok(v.asin())
     }
 }
 
@@ -323,7 +360,7 @@ impl SProj {
                     y = (2. / y).sqrt();
                     Ok((
                         y * cosphi * lam.sin(),
-                        y * cosb1 * sinphi - sinb1 * cosphi * coslam,
+                        y * (cosb1 * sinphi - sinb1 * cosphi * coslam),
                         z,
                     ))
                 }
@@ -347,16 +384,11 @@ impl SProj {
 
     fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
         let rh = x.hypot(y);
-        let mut phi = rh * 0.5;
-        if phi > 1. {
-            return Err(Error::ToleranceConditionError);
-        }
-
         let lam;
 
         use SMode::*;
 
-        phi = 2. * phi.asin();
+        let mut phi = 2. * asin_antipode(rh * 0.5)?;
         match self.mode {
             EQUIT => {
                 let (sinz, cosz) = phi.sin_cos();
@@ -397,9 +429,27 @@ impl SProj {
 
 #[cfg(test)]
 mod tests {
+    use crate::errors::Error;
     use crate::math::consts::EPS_10;
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use crate::transform::transform;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn proj_laea_inverse_valid_domain() {
+        let p = Proj::from_proj_string("+proj=laea +R=6400000").unwrap();
+        assert!(p.inverse_valid_domain(0., 2. * 6400000. - 1.));
+        assert!(!p.inverse_valid_domain(0., 2. * 6400000. + 1.));
+
+        let p = Proj::from_proj_string("+proj=laea +ellps=GRS80 +lat_0=45").unwrap();
+        assert!(p.inverse_valid_domain(1.0e6, 1.0e6));
+        assert!(!p.inverse_valid_domain(1.3e7, 0.));
+
+        let p = Proj::from_proj_string("+proj=laea +ellps=GRS80 +lat_0=90").unwrap();
+        assert!(p.inverse_valid_domain(1.0e6, 1.0e6));
+        assert!(!p.inverse_valid_domain(1.3e7, 0.));
+    }
 
     #[test]
     fn proj_laea_el() {
@@ -408,18 +458,12 @@ mod tests {
         println!("{:#?}", p.projection());
 
         let inputs = [
-            ((2., 1., 0.), (222602.471450095181, 110589.82722441027, 0.)),
-            (
-                (2., -1., 0.),
-                (222602.471450095181, -110589.827224408786, 0.),
-            ),
-            (
-                (-2., 1., 0.),
-                (-222602.471450095181, 110589.82722441027, 0.),
-            ),
+            ((2., 1., 0.), (222602.47145009518, 110589.82722441027, 0.)),
+            ((2., -1., 0.), (222602.47145009518, -110589.82722440879, 0.)),
+            ((-2., 1., 0.), (-222602.47145009518, 110589.82722441027, 0.)),
             (
                 (-2., -1., 0.),
-                (-222602.471450095181, -110589.827224408786, 0.),
+                (-222602.47145009518, -110589.82722440879, 0.),
             ),
         ];
 
@@ -434,22 +478,138 @@ mod tests {
         println!("{:#?}", p.projection());
 
         let inputs = [
-            ((2., 1., 0.), (223365.281370124663, 111716.668072915665, 0.)),
+            ((2., 1., 0.), (223365.28137012466, 111716.66807291567, 0.)),
+            ((2., -1., 0.), (223365.28137012466, -111716.66807291567, 0.)),
+            ((-2., 1., 0.), (-223365.28137012466, 111716.66807291567, 0.)),
             (
-                (2., -1., 0.),
-                (223365.281370124663, -111716.668072915665, 0.),
+                (-2., -1., 0.),
+                (-223365.28137012466, -111716.66807291567, 0.),
             ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_laea_el_obliq() {
+        let p = Proj::from_proj_string("+proj=laea +lat_0=52 +lon_0=10 +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
             (
-                (-2., 1., 0.),
-                (-223365.281370124663, 111716.668072915665, 0.),
+                (12., 51., 0.),
+                (140377.85802321532, -109338.12855995362, 0.),
             ),
+            ((8., 53., 0.), (-134262.46448555612, 113122.7157595865, 0.)),
             (
-                (-2., -1., 0.),
-                (-223365.281370124663, -111716.668072915665, 0.),
+                (-20., 30., 0.),
+                (-2868503.981814689, -1871135.3607228661, 0.),
             ),
         ];
 
-        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        // The inverse is limited by the accuracy of the
+        // authalic latitude series
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    fn proj_laea_el_south_pole() {
+        let p = Proj::from_proj_string("+proj=laea +lat_0=-90 +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (10., -80., 0.),
+                (193688.74905532788, 1098463.4811819778, 0.),
+            ),
+            (
+                (-120., -60., 0.),
+                (-2866387.8131612465, -1654909.7755305085, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        // The inverse is limited by the accuracy of the
+        // authalic latitude series
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    fn proj_laea_sp_obliq() {
+        let p = Proj::from_proj_string("+proj=laea +lat_0=52 +lon_0=10 +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            (
+                (12., 51., 0.),
+                (140576.62355696099, -109772.64601678537, 0.),
+            ),
+            ((8., 53., 0.), (-134432.16991153077, 113555.04302002434, 0.)),
+            (
+                (-20., 30., 0.),
+                (-2876972.4273486673, -1881453.7306390877, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_laea_epsg_3035() {
+        // Example from EPSG Guidance Note 7-2
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        let p = Proj::from_proj_string(
+            "+proj=laea +lat_0=52 +lon_0=10 +x_0=4321000 +y_0=3210000 +ellps=GRS80",
+        )
+        .unwrap();
+
+        let mut pt = (5f64.to_radians(), 50f64.to_radians(), 0.);
+        transform(&geo, &p, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0, 3962799.45, epsilon = 1.0e-2);
+        assert_abs_diff_eq!(pt.1, 2999718.85, epsilon = 1.0e-2);
+    }
+
+    #[test]
+    fn proj_laea_round_trip_antipode() {
+        for projstr in [
+            "+proj=laea +lat_0=52 +lon_0=10 +ellps=GRS80",
+            "+proj=laea +lat_0=52 +lon_0=10 +R=6371000",
+            "+proj=laea +ellps=GRS80",
+            "+proj=laea +lat_0=90 +ellps=GRS80",
+            "+proj=laea +lat_0=-90 +R=6371000",
+        ] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let d = p.data();
+            let pp = p.projection();
+
+            // Center and points close to the antipode
+            let lat0 = d.phi0;
+            let near = -lat0 + 0.01 * lat0.signum();
+            let pts = [
+                (0., lat0),
+                (0.1, (lat0 + 0.1).clamp(-1.5, 1.5)),
+                (3.1, near),
+                (-3.1, near),
+            ];
+            for (lam, phi) in pts {
+                let (x, y, _) = pp.forward(lam, phi, 0.).unwrap();
+                let (lam_i, phi_i, _) = pp.inverse(x, y, 0.).unwrap();
+                assert_abs_diff_eq!(phi_i, phi, epsilon = 1.0e-9);
+                assert_abs_diff_eq!(lam_i, lam, epsilon = 1.0e-9);
+            }
+
+            // Beyond the antipode
+            let (x, y, _) = pp.forward(3.1, near, 0.).unwrap();
+            assert!(matches!(
+                pp.inverse(x * 1.01, y * 1.01, 0.),
+                Err(Error::ToleranceConditionError)
+            ));
+        }
+    }
 }
@@ -4,9 +4,15 @@
 //! We don't actually change the coordinates, but we want proj=latlong
 //! to act sort of like a projection.
 //!
-use crate::errors::Result;
+//! Linear units (`units`, `to_meter`) are meaningless for geographic
+//! coordinates: they are ignored with a warning, unless the `strict`
+//! option is set, in which case an error is returned.
+//!
+use crate::errors::{Error, Result};
+use crate::log::warn;
 use crate::parameters::ParamList;
 use crate::proj::{ProjData, ProjType};
+use crate::units;
 
 // Projection stub
 super::projection! { latlong, longlat }
@@ -15,13 +21,31 @@ super::projection! { latlong, longlat }
 pub(crate) struct Projection {}
 
 impl Projection {
-    pub fn latlong(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+    pub fn latlong(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        Self::check_linear_units(params)?;
         p.proj_type = ProjType::Latlong;
         p.x0 = 0.;
         p.y0 = 0.;
         Ok(Self {})
     }
 
+    fn check_linear_units(params: &ParamList) -> Result<()> {
+        let has_linear_units = params.get("to_meter").is_some()
+            || match params.try_value::<&str>("units")? {
+                Some(units) => !units.eq_ignore_ascii_case(units::DEGREES),
+                None => false,
+            };
+        if has_linear_units {
+            if params.check_option("strict")? {
+                return Err(Error::InvalidParameterValue(
+                    "Linear units are not allowed for geographic coordinates",
+                ));
+            }
+            warn!("Linear units are ignored for geographic coordinates");
+        }
+        Ok(())
+    }
+
     pub fn longlat(p: &mut ProjData, params: &ParamList) -> Result<Self> {
         Self::latlong(p, params)
     }
@@ -48,6 +72,7 @@ impl Projection {
 #[cfg(test)]
 mod tests {
     use crate::adaptors::transform_xy;
+    use crate::errors::Error;
     use crate::proj::Proj;
 
     #[test]
@@ -61,6 +86,27 @@ mod tests {
         assert_eq!(p.projname(), "latlong");
     }
 
+    #[test]
+    fn proj_latlon_linear_units() {
+        // Lenient mode: units are ignored
+        let p = Proj::from_proj_string("+proj=latlong +datum=WGS84 +units=m").unwrap();
+        assert_eq!(p.units(), "degrees");
+        assert!(Proj::from_proj_string("+proj=longlat +datum=WGS84 +to_meter=0.3048").is_ok());
+        assert!(
+            Proj::from_proj_string("+proj=longlat +datum=WGS84 +units=degrees +strict").is_ok()
+        );
+
+        // Strict mode
+        assert!(matches!(
+            Proj::from_proj_string("+proj=latlong +datum=WGS84 +units=m +strict"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+        assert!(matches!(
+            Proj::from_proj_string("+proj=longlat +datum=WGS84 +to_meter=0.3048 +strict"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+
     #[test]
     fn proj_latlon_to_latlon() {
         let p_from = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
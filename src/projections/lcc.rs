@@ -12,26 +12,37 @@
 //! x_0: x offset in meters
 //! y_0: y offset in meters
 //!
+//! The standard parallels may be given in any order, the
+//! projection is identical.
+//!
+//! Without `lat_2`, this is the EPSG 1SP variant: `lat_0` defaults
+//! to `lat_1` and `k_0` is the scale factor at the standard parallel.
+//!
 
 use crate::errors::{Error, Result};
 use crate::math::{
-    consts::{EPS_10, FRAC_PI_2, FRAC_PI_4},
+    consts::{EPS_10, FRAC_PI_2, FRAC_PI_4, PI},
     msfn, phi2, tsfn,
 };
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
 // Projection stub
-super::projection! { lcc }
+super::projection! { lcc; inverse_valid_domain }
 
 #[derive(Debug, Clone)]
 pub(crate) struct Projection {
     n: f64,
     rho0: f64,
+    // log of the isometric function at lat_0
+    lnt0: f64,
     c: f64,
     ellips: bool,
     e: f64,
     k0: f64,
+    // Reciprocals used in inverse
+    rn: f64,
+    rk0: f64,
 }
 
 impl Projection {
@@ -49,6 +60,15 @@ impl Projection {
             return Err(Error::ProjErrConicLatEqual);
         }
 
+        // `n` and `c` are symmetric in the standard parallels but
+        // the rounding errors are not: sort the parallels so that
+        // the results do not depend on their order.
+        let (phi1, phi2) = if phi1 <= phi2 {
+            (phi1, phi2)
+        } else {
+            (phi2, phi1)
+        };
+
         let phi0 = p.phi0;
 
         let sinphi = phi1.sin();
@@ -59,7 +79,10 @@ impl Projection {
 
         let ellips = el.es != 0.;
 
-        let (n, c, rho0);
+        // Origin at the pole: the origin is the apex of the cone
+        let polar_origin = (phi0.abs() - FRAC_PI_2).abs() < EPS_10;
+
+        let (n, c, rho0, lnt0);
 
         if ellips {
             let m1 = msfn(sinphi, cosphi, el.es);
@@ -75,10 +98,11 @@ impl Projection {
             //rho0 = m1 * ml1.powf(-n);
             //c = rho0 / n;
             c = m1 * ml1.powf(-n) / n;
-            rho0 = if (phi0.abs() - FRAC_PI_2).abs() < EPS_10 {
+            lnt0 = tsfn(phi0, phi0.sin(), el.e).ln();
+            rho0 = if polar_origin {
                 0.
             } else {
-                c * tsfn(phi0, phi0.sin(), el.e).powf(n)
+                c * (n * lnt0).exp()
             }
         } else {
             n = if secant {
@@ -88,57 +112,72 @@ impl Projection {
                 sinphi
             };
             c = cosphi * (FRAC_PI_4 + 0.5 * phi1).tan().powf(n) / n;
-            rho0 = if (phi0.abs() - FRAC_PI_2).abs() < EPS_10 {
+            lnt0 = -(FRAC_PI_4 + 0.5 * phi0).tan().ln();
+            rho0 = if polar_origin {
                 0.
             } else {
-                c * (FRAC_PI_4 + 0.5 * phi0).tan().powf(-n)
+                c * (n * lnt0).exp()
             }
         }
 
         Ok(Self {
             n,
             rho0,
+            lnt0,
             c,
             ellips,
             e: el.e,
             k0: p.k0,
+            rn: 1. / n,
+            rk0: 1. / p.k0,
         })
     }
 
     #[inline(always)]
     pub fn forward(&self, mut lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
-        let rho = if (phi.abs() - FRAC_PI_2).abs() < EPS_10 {
+        let (rho, drho) = if (phi.abs() - FRAC_PI_2).abs() < EPS_10 {
             if (phi * self.n) <= 0. {
                 return Err(Error::ToleranceConditionError);
             } else {
-                0.
+                (0., self.rho0)
             }
         } else {
-            self.c
-                * if self.ellips {
-                    tsfn(phi, phi.sin(), self.e).powf(self.n)
-                } else {
-                    (FRAC_PI_4 + 0.5 * phi).tan().powf(-self.n)
-                }
+            let lnt = if self.ellips {
+                tsfn(phi, phi.sin(), self.e).ln()
+            } else {
+                -(FRAC_PI_4 + 0.5 * phi).tan().ln()
+            };
+            let rho = self.c * (self.n * lnt).exp();
+            // Compute rho0 - rho without cancellation near lat_0
+            let drho = if self.rho0 == 0. {
+                -rho
+            } else {
+                -self.rho0 * (self.n * (lnt - self.lnt0)).exp_m1()
+            };
+            (rho, drho)
         };
 
         lam *= self.n;
 
+        // rho0 - rho * cos(lam) = (rho0 - rho) + 2 * rho * sin²(lam / 2)
+        let s = (0.5 * lam).sin();
         Ok((
             self.k0 * (rho * lam.sin()),
-            self.k0 * (self.rho0 - rho * lam.cos()),
+            self.k0 * (drho + 2. * rho * s * s),
             z,
         ))
     }
 
     #[inline(always)]
     pub fn inverse(&self, mut x: f64, mut y: f64, z: f64) -> Result<(f64, f64, f64)> {
-        x /= self.k0;
-        y /= self.k0;
+        x *= self.rk0;
+        y *= self.rk0;
 
         y = self.rho0 - y;
-        // XXX Check this version of hypoth against the
-        // one given in proj4
+        // Like proj, we use `hypot` instead of `(x * x + y * y).sqrt()`:
+        // it is slightly slower but do not overflow (resp. underflow) for
+        // large (resp. tiny) coordinates magnitude, where the naive
+        // formula would return `inf` (resp. `0`).
         let mut rho = x.hypot(y);
         let (lam, phi);
         if rho != 0. {
@@ -147,12 +186,16 @@ impl Projection {
                 x = -x;
                 y = -y;
             }
+            let theta = x.atan2(y);
+            if theta.abs() > self.n.abs() * PI + EPS_10 {
+                return Err(Error::PointBeyondConeApex);
+            }
             phi = if self.ellips {
-                phi2((rho / self.c).powf(1. / self.n), self.e)?
+                phi2((rho / self.c).powf(self.rn), self.e)?
             } else {
-                2. * (self.c / rho).powf(1. / self.n).atan() - FRAC_PI_2
+                2. * (self.c / rho).powf(self.rn).atan() - FRAC_PI_2
             };
-            lam = x.atan2(y) / self.n;
+            lam = theta * self.rn;
         } else {
             lam = 0.;
             phi = if self.n > 0. { FRAC_PI_2 } else { -FRAC_PI_2 };
@@ -160,6 +203,17 @@ impl Projection {
         Ok((lam, phi, z))
     }
 
+    /// Check that (x, y) is in the image of the cone: a point
+    /// on the wrong side of the apex cannot be inverted.
+    pub fn inverse_valid_domain(&self, x: f64, y: f64) -> bool {
+        let (mut x, mut y) = (x * self.rk0, self.rho0 - y * self.rk0);
+        if self.n < 0. {
+            x = -x;
+            y = -y;
+        }
+        x.atan2(y).abs() <= self.n.abs() * PI + EPS_10
+    }
+
     pub const fn has_inverse() -> bool {
         true
     }
@@ -172,9 +226,11 @@ impl Projection {
 #[cfg(test)]
 mod tests {
     use crate::adaptors::transform_xy;
-    use crate::math::consts::EPS_10;
+    use crate::errors::Error;
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use approx::assert_abs_diff_eq;
 
     #[test]
     fn proj_lcc() {
@@ -183,18 +239,12 @@ mod tests {
         println!("{:#?}", p.projection());
 
         let inputs = [
-            ((2., 1., 0.), (222588.439735968423, 110660.533870799671, 0.)),
-            (
-                (2., -1., 0.),
-                (222756.879700278747, -110532.797660827026, 0.),
-            ),
-            (
-                (-2., 1., 0.),
-                (-222588.439735968423, 110660.533870799671, 0.),
-            ),
+            ((2., 1., 0.), (222588.43973596842, 110660.53387080018, 0.)),
+            ((2., -1., 0.), (222756.87970027875, -110532.79766087756, 0.)),
+            ((-2., 1., 0.), (-222588.43973596842, 110660.53387080018, 0.)),
             (
                 (-2., -1., 0.),
-                (-222756.879700278747, -110532.797660827026, 0.),
+                (-222756.87970027875, -110532.79766087756, 0.),
             ),
         ];
 
@@ -202,6 +252,121 @@ mod tests {
         test_proj_inverse(&p, &inputs, EPS_10);
     }
 
+    #[test]
+    fn proj_lcc_swapped_parallels() {
+        for (ellps, pts) in [("+ellps=GRS80", [2f64, 1.]), ("+R=6400000", [-10., 45.])] {
+            let p1 =
+                Proj::from_proj_string(&format!("+proj=lcc {ellps} +lat_1=0.5 +lat_2=2")).unwrap();
+            let p2 =
+                Proj::from_proj_string(&format!("+proj=lcc {ellps} +lat_1=2 +lat_2=0.5")).unwrap();
+
+            // The order of the standard parallels does not matter
+            assert_eq!(
+                format!("{:?}", p1.projection()),
+                format!("{:?}", p2.projection())
+            );
+
+            let (lam, phi) = (pts[0].to_radians(), pts[1].to_radians());
+            assert_eq!(
+                p1.projection().forward(lam, phi, 0.).unwrap(),
+                p2.projection().forward(lam, phi, 0.).unwrap(),
+            );
+        }
+
+        let p1 = Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_1=33 +lat_2=45").unwrap();
+        let p2 = Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_1=45 +lat_2=33").unwrap();
+        assert_eq!(
+            format!("{:?}", p1.projection()),
+            format!("{:?}", p2.projection())
+        );
+    }
+
+    #[test]
+    fn proj_lcc_inverse_valid_domain() {
+        let p = Proj::from_proj_string(
+            "+proj=lcc +lat_1=33 +lat_2=45 +lat_0=39 +lon_0=-96 +ellps=GRS80 +x_0=1000",
+        )
+        .unwrap();
+
+        // Apex of the cone
+        let (_, y_apex, _) = p.projection().forward(0., FRAC_PI_2, 0.).unwrap();
+        let y_apex = y_apex * p.data().ellps.a;
+
+        assert!(p.inverse_valid_domain(1000., 0.));
+        assert!(p.inverse_valid_domain(1000., y_apex));
+        assert!(p.inverse_valid_domain(1.0e6, 1.0e6));
+
+        // Wrong side of the apex
+        assert!(!p.inverse_valid_domain(1000., y_apex + 1.0e5));
+        assert!(!p.inverse_valid_domain(1.0e5, y_apex + 1.0e6));
+
+        // Southern cone
+        let p = Proj::from_proj_string("+proj=lcc +lat_1=-33 +lat_2=-45 +ellps=GRS80").unwrap();
+        let (_, y_apex, _) = p.projection().forward(0., -FRAC_PI_2, 0.).unwrap();
+        let y_apex = y_apex * p.data().ellps.a;
+        assert!(p.inverse_valid_domain(0., 0.));
+        assert!(!p.inverse_valid_domain(0., y_apex - 1.0e5));
+
+        // No check for latlong
+        let p = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+        assert!(p.inverse_valid_domain(1.0e5, 1.0e6));
+    }
+
+    #[test]
+    fn proj_lcc_polar_origin() {
+        // PROJ gie reference values for +lat_0=0: with the origin at
+        // the pole (rho0 = 0 at either pole, like PROJ) only the northings
+        // are shifted, by the radius of the equator on the cone.
+        let gie = [
+            ((2., 1., 0.), (222588.439735968, 110660.533870800, 0.)),
+            ((2., -1., 0.), (222756.879700279, -110532.797660827, 0.)),
+            ((-2., 1., 0.), (-222588.439735968, 110660.533870800, 0.)),
+            ((-2., -1., 0.), (-222756.879700279, -110532.797660827, 0.)),
+        ];
+
+        for lat_0 in ["90", "-90"] {
+            let p = Proj::from_proj_string(&format!(
+                "+proj=lcc +ellps=GRS80 +lat_1=0.5 +lat_2=2 +lat_0={lat_0}"
+            ))
+            .unwrap();
+
+            println!("{:#?}", p.projection());
+
+            let (_, y0, _) = p.projection().forward(0., 0., 0.).unwrap();
+            let y0 = y0 * p.data().ellps.a;
+
+            let inputs = gie.map(|(input, (x, y, z))| (input, (x, y + y0, z)));
+
+            test_proj_forward(&p, &inputs, 1.0e-4);
+            test_proj_inverse(&p, &inputs, 1.0e-10);
+        }
+
+        let p =
+            Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_0=90 +lat_1=30 +lat_2=60").unwrap();
+
+        // The pole is the origin
+        let pp = p.projection();
+        let (x, y, _) = pp.forward(0., FRAC_PI_2, 0.).unwrap();
+        assert_eq!((x, y), (0., 0.));
+        let (lam, phi, _) = pp.inverse(0., 0., 0.).unwrap();
+        assert_eq!((lam, phi), (0., FRAC_PI_2));
+
+        // No NaN far from the pole
+        let (x, y, _) = pp.forward(0.5, -1.0, 0.).unwrap();
+        assert!(x.is_finite() && y.is_finite());
+        let (lam, phi, _) = pp.inverse(x, y, 0.).unwrap();
+        assert_abs_diff_eq!(lam, 0.5, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(phi, -1.0, epsilon = 1.0e-12);
+
+        // The opposite pole is also accepted, like PROJ
+        let p =
+            Proj::from_proj_string("+proj=lcc +R=6400000 +lat_0=-90 +lat_1=30 +lat_2=60").unwrap();
+        assert_eq!(
+            p.projection().forward(0., FRAC_PI_2, 0.).unwrap(),
+            (0., 0., 0.)
+        );
+    }
+
     #[test]
     fn proj_lcc_latlon_to_lcc() {
         let p_from = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
@@ -210,6 +375,132 @@ mod tests {
         let (lon_in, lat_in) = (2.0f64.to_radians(), 1.0f64.to_radians());
 
         let out = transform_xy(&p_from, &p_to, lon_in, lat_in).unwrap();
-        assert_eq!(out, (222588.439735968423, 110660.533870799671));
+        assert_eq!(out, (222588.43973596842, 110660.53387080018));
+    }
+
+    #[test]
+    fn proj_lcc_inverse_large_magnitude() {
+        let p = Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+        let pp = p.projection();
+
+        // Naive formula would overflow
+        let (x, y) = (1.0e198f64, -1.0e200f64);
+        assert!((x * x + y * y).sqrt().is_infinite());
+
+        let (lam, phi, _) = pp.inverse(x, y, 0.).unwrap();
+        assert!(lam.is_finite());
+        assert_abs_diff_eq!(phi, -FRAC_PI_2, epsilon = EPS_10);
+
+        // Naive formula would underflow and snap to the apex
+        let (x, y) = (1.0e-200f64, 1.0e-200f64);
+        assert_eq!((x * x + y * y).sqrt(), 0.);
+        let (lam, phi, _) = pp.inverse(x, y, 0.).unwrap();
+        assert!(lam.is_finite() && phi.is_finite());
+    }
+
+    #[test]
+    fn proj_lcc_inverse_reciprocals() {
+        // Check that inverse with cached reciprocals
+        // round trip with forward
+        let p = Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_1=49 +lat_2=44 +k_0=0.9996")
+            .unwrap();
+
+        let pp = p.projection();
+
+        (-10..=10).for_each(|i| {
+            let (lam, phi) = ((i as f64).to_radians(), (45. + i as f64).to_radians());
+            let (x, y, _) = pp.forward(lam, phi, 0.).unwrap();
+            let (lam_out, phi_out, _) = pp.inverse(x, y, 0.).unwrap();
+            assert_abs_diff_eq!(lam_out, lam, epsilon = EPS_10);
+            assert_abs_diff_eq!(phi_out, phi, epsilon = EPS_10);
+        });
+    }
+
+    #[test]
+    fn proj_lcc_beyond_apex() {
+        let p = Proj::from_proj_string("+proj=lcc +lat_1=33 +lat_2=45 +ellps=GRS80").unwrap();
+
+        let (_, y_apex, _) = p.projection().forward(0., FRAC_PI_2, 0.).unwrap();
+
+        // On the cone
+        assert!(p.projection().inverse(0.1, y_apex - 0.1, 0.).is_ok());
+        // Beyond the apex
+        assert!(matches!(
+            p.projection().inverse(0., y_apex + 0.1, 0.),
+            Err(Error::PointBeyondConeApex)
+        ));
+
+        // Southern cone
+        let p = Proj::from_proj_string("+proj=lcc +lat_1=-33 +lat_2=-45 +R=6400000").unwrap();
+        let (_, y_apex, _) = p.projection().forward(0., -FRAC_PI_2, 0.).unwrap();
+        assert!(p.projection().inverse(0.1, y_apex + 0.1, 0.).is_ok());
+        assert!(matches!(
+            p.projection().inverse(0., y_apex - 0.1, 0.),
+            Err(Error::PointBeyondConeApex)
+        ));
+    }
+
+    #[test]
+    fn proj_lcc_near_origin() {
+        let p = Proj::from_proj_string("+proj=lcc +ellps=GRS80 +lat_0=46.5 +lat_1=49 +lat_2=44")
+            .unwrap();
+
+        // rho ≈ rho0: reference values computed with 40 digits,
+        // the naive form `rho0 - rho * cos(lam)` has a relative
+        // error of about 1e-4 on the first point.
+        let inputs = [
+            (
+                (1.0e-4, 46.5, 0.),
+                (7.668975207040699, 4.856088884312927e-6, 0.),
+            ),
+            (
+                (1.0e-3, 46.500001, 0.),
+                (76.68975066194223, 0.11154121032173885, 0.),
+            ),
+        ];
+
+        let d = p.data();
+        for ((lon, lat, _), (x, y, _)) in inputs {
+            let (lam, phi) = (f64::to_radians(lon), f64::to_radians(lat));
+            let (xp, yp, _) = p.projection().forward(lam, phi, 0.).unwrap();
+            assert_abs_diff_eq!(xp * d.ellps.a, x, epsilon = 1.0e-9);
+            assert!(
+                ((yp * d.ellps.a - y) / y).abs() < 1.0e-8,
+                "{} {y}",
+                yp * d.ellps.a
+            );
+        }
+    }
+
+    #[test]
+    fn proj_lcc_1sp() {
+        // EPSG guidance note 7-2, Lambert Conic Conformal (1SP):
+        // JAD69 / Jamaica National Grid
+        let p = Proj::from_proj_string(concat!(
+            "+proj=lcc +lat_1=18 +lon_0=-77 +k_0=1",
+            " +x_0=250000 +y_0=150000 +ellps=clrk66"
+        ))
+        .unwrap();
+
+        let inputs = [(
+            (-76.94368333333334, 17.932166666666667, 0.),
+            (255966.58, 142493.51, 0.),
+        )];
+
+        // Published values are rounded to the centimeter
+        test_proj_forward(&p, &inputs, 1.0e-2);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+
+        // The scale factor applies at the standard parallel
+        let p_k = Proj::from_proj_string(concat!(
+            "+proj=lcc +lat_1=18 +lon_0=-77 +k_0=0.9996",
+            " +x_0=250000 +y_0=150000 +ellps=clrk66"
+        ))
+        .unwrap();
+        let (lam, phi) = (0.01, 0.3);
+        let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+        let (x_k, y_k, _) = p_k.projection().forward(lam, phi, 0.).unwrap();
+        assert_abs_diff_eq!(x_k, 0.9996 * x, epsilon = 1.0e-15);
+        assert_abs_diff_eq!(y_k, 0.9996 * y, epsilon = 1.0e-15);
     }
 }
@@ -0,0 +1,287 @@
+//!
+//! McBryde-Thomas pseudocylindrical projections
+//!
+//! ref: <https://proj.org/operations/projections/mbt_s.html>
+//!
+//! mbt_s: "McBryde-Thomas Flat-Polar Sine (No. 1)" "\n\tPCyl., Sph."
+//! mbt_fps: "McBryde-Thomas Flat-Pole Sine (No. 2)" "\n\tCyl., Sph."
+//! mbtfpp: "McBride-Thomas Flat-Polar Parabolic" "\n\tCyl., Sph."
+//! mbtfpq: "McBryde-Thomas Flat-Polar Quartic" "\n\tCyl., Sph."
+//!
+//! mbt_fps and mbtfpq solve for the parametric latitude by
+//! Newton-Raphson in the forward direction, the inverses are closed-form.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{aasin, aasin_tol, consts::PI, newton};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { mbt_s, mbt_fps, mbtfpp, mbtfpq }
+
+const LOOP_TOL: f64 = 1.0e-7;
+
+// mbt_s
+const S_P: f64 = 1.48875;
+const S_Q: f64 = 1.36509;
+const S_CX: f64 = S_Q / S_P;
+const S_CY: f64 = S_P;
+const S_CP: f64 = 1. / S_Q;
+
+// mbt_fps
+const FPS_MAX_ITER: usize = 10;
+const FPS_C1: f64 = 0.45503;
+const FPS_C2: f64 = 1.36509;
+const FPS_C3: f64 = 1.41546;
+const FPS_CX: f64 = 0.22248;
+const FPS_CY: f64 = 1.44492;
+const FPS_C1_2: f64 = 1. / 3.;
+
+// mbtfpp
+const FPP_CS: f64 = 0.9525793444156804;
+const FPP_FXC: f64 = 0.9258200997725514;
+const FPP_FYC: f64 = 3.401680257083045;
+const FPP_C23: f64 = 2. / 3.;
+const FPP_C13: f64 = 1. / 3.;
+const FPP_ONEEPS: f64 = 1.0000001;
+
+// mbtfpq
+const FPQ_NITER: usize = 20;
+const FPQ_C: f64 = 1.7071067811865475;
+const FPQ_RC: f64 = 0.585786437626905;
+const FPQ_FYC: f64 = 1.874758284622695;
+const FPQ_RYC: f64 = 0.533402096794177;
+const FPQ_FXC: f64 = 0.3124597141037825;
+const FPQ_RXC: f64 = 3.2004125807650623;
+const FPQ_ONETOL: f64 = 1.000001;
+
+#[derive(Debug, Clone, Copy)]
+enum Type {
+    S,
+    Fps,
+    Fpp,
+    Fpq,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    t: Type,
+}
+
+impl Projection {
+    fn init(p: &mut ProjData, t: Type) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self { t })
+    }
+
+    pub fn mbt_s(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::init(p, Type::S)
+    }
+
+    pub fn mbt_fps(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::init(p, Type::Fps)
+    }
+
+    pub fn mbtfpp(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::init(p, Type::Fpp)
+    }
+
+    pub fn mbtfpq(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::init(p, Type::Fpq)
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (x, y) = match self.t {
+            Type::S => {
+                let x = S_CX * lam * phi.cos();
+                let phi = phi * S_CP;
+                (x / phi.cos(), S_CY * phi.sin())
+            }
+            Type::Fps => {
+                let k = FPS_C3 * phi.sin();
+                // Like proj, use the last approximation on failure
+                let phi = newton(phi, LOOP_TOL, FPS_MAX_ITER, |phi| {
+                    let t = phi / FPS_C2;
+                    (FPS_C1 * t.sin() + phi.sin() - k) / (FPS_C1_2 * t.cos() + phi.cos())
+                })
+                .unwrap_or_else(|phi| phi);
+                let t = phi / FPS_C2;
+                (
+                    FPS_CX * lam * (1. + 3. * phi.cos() / t.cos()),
+                    FPS_CY * t.sin(),
+                )
+            }
+            Type::Fpp => {
+                let phi = (FPP_CS * phi.sin()).asin();
+                (
+                    FPP_FXC * lam * (2. * (FPP_C23 * phi).cos() - 1.),
+                    FPP_FYC * (FPP_C13 * phi).sin(),
+                )
+            }
+            Type::Fpq => {
+                let c = FPQ_C * phi.sin();
+                let phi = newton(phi, LOOP_TOL, FPQ_NITER, |phi| {
+                    ((0.5 * phi).sin() + phi.sin() - c) / (0.5 * (0.5 * phi).cos() + phi.cos())
+                })
+                .unwrap_or_else(|phi| phi);
+                (
+                    FPQ_FXC * lam * (1. + 2. * phi.cos() / (0.5 * phi).cos()),
+                    FPQ_FYC * (0.5 * phi).sin(),
+                )
+            }
+        };
+        Ok((x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (lam, phi) = match self.t {
+            Type::S => {
+                let phi = aasin(y / S_CY)?;
+                let c = phi.cos();
+                let phi = phi / S_CP;
+                (x * c / (S_CX * phi.cos()), phi)
+            }
+            Type::Fps => {
+                let t = aasin(y / FPS_CY)?;
+                let phi = FPS_C2 * t;
+                let lam = x / (FPS_CX * (1. + 3. * phi.cos() / t.cos()));
+                (lam, aasin((FPS_C1 * t.sin() + phi.sin()) / FPS_C3)?)
+            }
+            Type::Fpp => {
+                let phi = 3. * aasin_tol(y / FPP_FYC, FPP_ONEEPS)?;
+                let lam = x / (FPP_FXC * (2. * (FPP_C23 * phi).cos() - 1.));
+                (lam, aasin_tol(phi.sin() / FPP_CS, FPP_ONEEPS)?)
+            }
+            Type::Fpq => {
+                let t = FPQ_RYC * y;
+                let (t, phi) = if t.abs() > 1. {
+                    if t.abs() > FPQ_ONETOL {
+                        return Err(Error::CoordTransOutsideProjectionDomain);
+                    }
+                    (1f64.copysign(t), PI.copysign(t))
+                } else {
+                    (t, 2. * t.asin())
+                };
+                let lam = FPQ_RXC * x / (1. + 2. * phi.cos() / (0.5 * phi).cos());
+                (lam, aasin_tol(FPQ_RC * (t + phi.sin()), FPQ_ONETOL)?)
+            }
+        };
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_mbt_s() {
+        let p = Proj::from_proj_string("+proj=mbt_s +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (204831.24057099217, 121816.46669603502, 0.)),
+            ((2., -1., 0.), (204831.24057099217, -121816.46669603502, 0.)),
+            ((-2., 1., 0.), (-204831.24057099217, 121816.46669603502, 0.)),
+            (
+                (-2., -1., 0.),
+                (-204831.24057099217, -121816.46669603502, 0.),
+            ),
+            ((30., 45., 0.), (2589636.730823614, 5184418.814823944, 0.)),
+            (
+                (-120., -60., 0.),
+                (-8536332.65936121, -6613097.9380002525, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_mbt_fps() {
+        let p = Proj::from_proj_string("+proj=mbt_fps +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (198798.17612984995, 125512.01725453066, 0.)),
+            ((2., -1., 0.), (198798.17612984995, -125512.01725453066, 0.)),
+            ((-2., 1., 0.), (-198798.17612984995, 125512.01725453066, 0.)),
+            (
+                (-2., -1., 0.),
+                (-198798.17612984995, -125512.01725453066, 0.),
+            ),
+            ((30., 45., 0.), (2581130.7136160154, 5300257.59137185, 0.)),
+            (
+                (-120., -60., 0.),
+                (-8775405.899509406, -6714823.962519264, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_mbtfpp() {
+        let p = Proj::from_proj_string("+proj=mbtfpp +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (206804.78692982037, 120649.76256579254, 0.)),
+            ((2., -1., 0.), (206804.78692982037, -120649.76256579254, 0.)),
+            ((-2., 1., 0.), (-206804.78692982037, 120649.76256579254, 0.)),
+            (
+                (-2., -1., 0.),
+                (-206804.78692982037, -120649.76256579254, 0.),
+            ),
+            ((30., 45., 0.), (2364463.39279156, 5309039.664494909, 0.)),
+            (
+                (-120., -60., 0.),
+                (-7397373.373563397, -6918077.033745701, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_mbtfpq() {
+        let p = Proj::from_proj_string("+proj=mbtfpq +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (209391.854738393, 119161.04019905483, 0.)),
+            ((2., -1., 0.), (209391.854738393, -119161.04019905483, 0.)),
+            ((-2., 1., 0.), (-209391.854738393, 119161.04019905483, 0.)),
+            ((-2., -1., 0.), (-209391.854738393, -119161.04019905483, 0.)),
+            ((30., 45., 0.), (2507915.0511835343, 5162710.209505076, 0.)),
+            (
+                (-120., -60., 0.),
+                (-8054079.270351485, -6659415.6666553, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+}
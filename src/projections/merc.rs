@@ -2,8 +2,18 @@
 //! Pseudo Mercator
 //!
 //! merc: "Mercator" "\n\tCyl, Sph&Ell\n\tlat_ts="
+//!
+//! The scale factor of merc is given either by `k_0` or by `lat_ts`,
+//! but not both, unless they agree.
+//!
+//! As in proj, the northing origin of merc and webmerc is always the
+//! equator: `lat_0` is ignored, and a false northing must be given
+//! with `y_0`.
 //! webmerc: "Web Mercator / Pseudo Mercator" "\n\tCyl, Ell\n\t"
 //!
+//! With the `clamp` option, webmerc clamp the latitude to the
+//! square extent of tile servers, i.e ±85.0511287798°.
+//!
 
 // Projection stub
 super::projection! { merc, webmerc }
@@ -17,49 +27,69 @@ use crate::math::{
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
+// Latitude of the square extent: atan(sinh(PI))
+const WEBMERC_MAX_LAT: f64 = 1.4844222297453324;
+
 #[derive(Debug, Clone)]
 pub(crate) struct Projection {
     is_ellps: bool,
     k0: f64,
+    rk0: f64,
     e: f64,
+    clamp: bool,
 }
 
 impl Projection {
     pub fn merc(p: &mut ProjData, params: &ParamList) -> Result<Self> {
         let phits: Option<f64> = params.try_angular_value("lat_ts")?;
         if let Some(phits) = phits {
-            if phits >= FRAC_PI_2 {
+            if phits.abs() >= FRAC_PI_2 {
                 return Err(Error::InvalidParameterValue(
                     "lat_ts larger than 90 degrees",
                 ));
             }
         }
 
-        if p.ellps.is_ellipsoid() {
-            if let Some(phits) = phits {
-                p.k0 = msfn(phits.sin(), phits.cos(), p.ellps.es);
+        if let Some(phits) = phits {
+            let k0 = if p.ellps.is_ellipsoid() {
+                msfn(phits.sin(), phits.cos(), p.ellps.es)
+            } else {
+                phits.cos()
+            };
+            // The scale factor is derived from lat_ts: accept a redundant
+            // k_0 (as in common EPSG:3857 definitions) only if it agrees.
+            if ["k_0", "k0", "k"].iter().any(|k| params.get(k).is_some())
+                && (p.k0 - k0).abs() > EPS_10
+            {
+                return Err(Error::InvalidParameterValue("k_0 disagrees with lat_ts"));
             }
-        } else if let Some(phits) = phits {
-            p.k0 = phits.cos();
+            p.k0 = k0;
         }
 
         Ok(Self {
             is_ellps: p.ellps.is_ellipsoid(),
             k0: p.k0,
+            rk0: 1. / p.k0,
             e: p.ellps.e,
+            clamp: false,
         })
     }
 
-    pub fn webmerc(p: &mut ProjData, _params: &ParamList) -> Result<Self> {
+    pub fn webmerc(p: &mut ProjData, params: &ParamList) -> Result<Self> {
         p.k0 = 1.0;
         Ok(Self {
             is_ellps: false,
             k0: p.k0,
+            rk0: 1. / p.k0,
             e: p.ellps.e,
+            clamp: params.check_option("clamp")?,
         })
     }
 
-    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+    pub fn forward(&self, lam: f64, mut phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.clamp {
+            phi = phi.clamp(-WEBMERC_MAX_LAT, WEBMERC_MAX_LAT);
+        }
         if (phi.abs() - FRAC_PI_2).abs() <= EPS_10 {
             return Err(Error::ToleranceConditionError);
         }
@@ -75,11 +105,17 @@ impl Projection {
         }
     }
 
+    /// Inverse projection
+    ///
+    /// Any finite `y` is mapped to a latitude in [-90°, 90°]: the
+    /// latitude approaches the poles as `y` grows, and is
+    /// numerically equal to ±90° for `|y| > ~38` (in units of the
+    /// semi-major axis). Such latitudes cannot be projected back.
     pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
         if self.is_ellps {
-            Ok((x / self.k0, phi2((-y / self.k0).exp(), self.e)?, z))
+            Ok((x * self.rk0, phi2((-y * self.rk0).exp(), self.e)?, z))
         } else {
-            Ok((x / self.k0, (y / self.k0).sinh().atan(), z))
+            Ok((x * self.rk0, (y * self.rk0).sinh().atan(), z))
         }
     }
 
@@ -94,9 +130,11 @@ impl Projection {
 
 #[cfg(test)]
 mod tests {
-    use crate::math::consts::EPS_10;
+    use crate::errors::Error;
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
     use crate::proj::Proj;
     use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use approx::assert_abs_diff_eq;
 
     #[test]
     fn proj_merc_merc_ellps() {
@@ -181,4 +219,187 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_merc_webmerc_clamp() {
+        let p = Proj::from_proj_string("+proj=webmerc +ellps=WGS84").unwrap();
+        let p_clamp = Proj::from_proj_string("+proj=webmerc +ellps=WGS84 +clamp").unwrap();
+
+        let a = p.data().ellps.a;
+        let phi = 89f64.to_radians();
+
+        // Without clamping
+        let (_, y, _) = p.projection().forward(0., phi, 0.).unwrap();
+        assert_abs_diff_eq!(y * a, 30240971.95838618, epsilon = 1.0e-6);
+
+        // With clamping: y at the square extent
+        let (_, y, _) = p_clamp.projection().forward(0., phi, 0.).unwrap();
+        assert_abs_diff_eq!(y * a, 20037508.342789244, epsilon = 1.0e-6);
+        let (_, y, _) = p_clamp.projection().forward(0., -phi, 0.).unwrap();
+        assert_abs_diff_eq!(y * a, -20037508.342789244, epsilon = 1.0e-6);
+
+        // Same results within the extent
+        let phi = 60f64.to_radians();
+        assert_eq!(
+            p.projection().forward(0.1, phi, 0.).unwrap(),
+            p_clamp.projection().forward(0.1, phi, 0.).unwrap(),
+        );
+    }
+
+    #[test]
+    fn proj_merc_inverse_large_y() {
+        for projstr in ["+proj=merc +ellps=GRS80", "+proj=merc +R=6400000"] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let mut last = 0.;
+            for y in [1., 5., 10., 20., 40., 1.0e3, 1.0e10, f64::MAX] {
+                let (_, phi, _) = p.projection().inverse(0., y, 0.).unwrap();
+                assert!(!phi.is_nan());
+                assert!(phi >= last && phi <= FRAC_PI_2);
+                last = phi;
+
+                let (_, phi, _) = p.projection().inverse(0., -y, 0.).unwrap();
+                assert!(!phi.is_nan());
+                assert!(phi >= -FRAC_PI_2);
+            }
+            assert_eq!(last, FRAC_PI_2);
+        }
+    }
+
+    #[test]
+    fn proj_merc_lat_ts() {
+        let p = Proj::from_proj_string("+proj=merc +ellps=GRS80 +lat_ts=0").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (222638.98158654713, 110579.96521825077, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222638.98158654713, -110579.96521825077, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, EPS_10);
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        // EPSG guidance note 7-2, Mercator (variant B)
+        let p = Proj::from_proj_string("+proj=merc +ellps=krass +lat_ts=42 +lon_0=51").unwrap();
+
+        let inputs = [((53., 53., 0.), (165704.29, 5171848.07, 0.))];
+
+        test_proj_forward(&p, &inputs, 1.0e-2);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    fn proj_merc_lat_ts_k0_exclusive() {
+        for k in ["+k=0.9", "+k_0=0.9"] {
+            assert!(matches!(
+                Proj::from_proj_string(&format!("+proj=merc +ellps=GRS80 +lat_ts=10 {k}")),
+                Err(Error::InvalidParameterValue(_))
+            ));
+        }
+        assert!(Proj::from_proj_string("+proj=merc +ellps=GRS80 +k_0=0.9").is_ok());
+        assert!(Proj::from_proj_string("+proj=merc +ellps=GRS80 +lat_ts=0 +k=1").is_ok());
+        assert!(matches!(
+            Proj::from_proj_string("+proj=merc +ellps=GRS80 +lat_ts=-90"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+
+    #[test]
+    fn proj_merc_lat_ts_k0_agree() {
+        // k_0 is the scale factor at lat_ts=10 on GRS80
+        let p1 = Proj::from_proj_string("+proj=merc +ellps=GRS80 +lat_ts=10").unwrap();
+        let p2 =
+            Proj::from_proj_string("+proj=merc +ellps=GRS80 +lat_ts=10 +k_0=0.9849071648361515")
+                .unwrap();
+        assert_abs_diff_eq!(p1.data().k0, 0.9849071648361515, epsilon = 1.0e-12);
+        assert_eq!(p1.data().k0, p2.data().k0);
+    }
+
+    #[test]
+    fn proj_merc_pole() {
+        for projstr in ["+proj=merc +ellps=GRS80", "+proj=merc +R=6400000"] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            for phi in [FRAC_PI_2, -FRAC_PI_2] {
+                assert!(matches!(
+                    p.projection().forward(0., phi, 0.),
+                    Err(Error::ToleranceConditionError)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn proj_merc_sphere_selection() {
+        let p_el = Proj::from_proj_string("+proj=merc +ellps=WGS84").unwrap();
+        let p_sp = Proj::from_proj_string("+proj=merc +R=6378137").unwrap();
+
+        let inputs_el = [((10., 45., 0.), (1113194.9079327357, 5591295.918553392, 0.))];
+        let inputs_sp = [((10., 45., 0.), (1113194.9079327357, 5621521.486192067, 0.))];
+
+        test_proj_forward(&p_el, &inputs_el, 1.0e-6);
+        test_proj_inverse(&p_el, &inputs_el, 1.0e-10);
+        test_proj_forward(&p_sp, &inputs_sp, 1.0e-6);
+        test_proj_inverse(&p_sp, &inputs_sp, 1.0e-10);
+
+        // Spheres derived from the ellipsoid select the spherical
+        // formulas as well
+        for projstr in [
+            "+proj=merc +ellps=WGS84 +R=6378137",
+            "+proj=merc +ellps=WGS84 +R_A",
+        ] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let (lam, phi) = (10f64.to_radians(), 45f64.to_radians());
+            let (_, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+            assert_abs_diff_eq!(y, 45f64.to_radians().tan().asinh(), epsilon = 1.0e-15);
+        }
+    }
+
+    #[test]
+    fn proj_merc_webmerc_extent() {
+        // EPSG:3857 square extent
+        let p = Proj::from_proj_string("+proj=webmerc +datum=WGS84").unwrap();
+
+        let inputs = [
+            ((0., 0., 0.), (0., 0., 0.)),
+            (
+                (180., 85.0511287798066, 0.),
+                (20037508.342789244, 20037508.342789244, 0.),
+            ),
+            (
+                (-180., -85.0511287798066, 0.),
+                (-20037508.342789244, -20037508.342789244, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_merc_lat_0() {
+        use crate::transform::transform;
+
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+        let northing = |projstr: &str| {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let mut pt = (10f64.to_radians(), 50f64.to_radians(), 0.);
+            transform(&geo, &p, &mut pt).unwrap();
+            pt.1
+        };
+
+        // lat_0 does not offset the northing, as in proj
+        let y = northing("+proj=merc +ellps=WGS84 +lat_0=0");
+        assert_abs_diff_eq!(y, 6413524.594163641, epsilon = 1.0e-6);
+        assert_eq!(northing("+proj=merc +ellps=WGS84 +lat_0=45"), y);
+        assert_eq!(
+            northing("+proj=webmerc +ellps=WGS84 +lat_0=45"),
+            northing("+proj=webmerc +ellps=WGS84")
+        );
+
+        // Unlike the other cylindrical projections using lat_0
+        let y = northing("+proj=eqc +ellps=WGS84 +lat_0=0");
+        let y45 = northing("+proj=eqc +ellps=WGS84 +lat_0=45");
+        assert_abs_diff_eq!(y - y45, 6378137. * 45f64.to_radians(), epsilon = 1.0e-6);
+    }
 }
@@ -17,6 +17,7 @@
 //
 
 use crate::errors::Result;
+use crate::factors::Factors;
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 
@@ -24,10 +25,25 @@ use std::fmt;
 
 pub(crate) type ProjFn = fn(&ProjParams, f64, f64, f64) -> Result<(f64, f64, f64)>;
 
+/// Optional check of the inverse projection domain
+pub(crate) type ValidDomainFn = fn(&ProjParams, f64, f64) -> bool;
+
+/// Optional analytic scale factors
+pub(crate) type FactorsFn = fn(&ProjParams, f64, f64) -> Option<Result<Factors>>;
+
 /// Setup: returned by the init() function
-/// Order of members: (params, inverse, forward)
+/// Order of members: (params, inverse, forward, has_inverse, has_forward,
+/// inverse_valid_domain, factors)
 #[derive(Clone)]
-pub(crate) struct ProjDelegate(ProjParams, ProjFn, ProjFn, bool, bool);
+pub(crate) struct ProjDelegate(
+    ProjParams,
+    ProjFn,
+    ProjFn,
+    bool,
+    bool,
+    Option<ValidDomainFn>,
+    Option<FactorsFn>,
+);
 
 impl ProjDelegate {
     #[inline(always)]
@@ -46,6 +62,26 @@ impl ProjDelegate {
     pub fn has_forward(&self) -> bool {
         self.4
     }
+
+    /// Return false if the inverse projection of (u, v) is known
+    /// to be impossible
+    ///
+    /// Projections without a specific check always return true.
+    pub fn inverse_valid_domain(&self, u: f64, v: f64) -> bool {
+        self.5.map_or(true, |f| f(&self.0, u, v))
+    }
+
+    /// Return the analytic scale factors at (lam, phi), if the
+    /// projection provides them.
+    pub fn factors(&self, lam: f64, phi: f64) -> Option<Result<Factors>> {
+        self.6.and_then(|f| f(&self.0, lam, phi))
+    }
+
+    /// Return true if both delegates use the same projection
+    /// implementation, regardless of the parameters.
+    pub fn is_same_projection(&self, other: &Self) -> bool {
+        std::mem::discriminant(&self.0) == std::mem::discriminant(&other.0)
+    }
 }
 
 impl fmt::Debug for ProjDelegate {
@@ -84,18 +120,51 @@ macro_rules! downcast {
     };
 }
 
+// Optional delegate functions, used when not
+// provided by the projection
+mod delegate_defaults {
+    use super::{FactorsFn, ValidDomainFn};
+
+    pub(crate) const INVERSE_VALID_DOMAIN: Option<ValidDomainFn> = None;
+    pub(crate) const FACTORS: Option<FactorsFn> = None;
+}
+
+// Optional delegate functions provided by the projection: the
+// definitions shadow the glob imported defaults.
+macro_rules! delegate_ext {
+    ($name:ident, inverse_valid_domain) => {
+        pub(crate) const INVERSE_VALID_DOMAIN: Option<$crate::projections::ValidDomainFn> =
+            Some(|p, u, v| $crate::projections::downcast!($name, p).inverse_valid_domain(u, v));
+    };
+    ($name:ident, factors) => {
+        pub(crate) const FACTORS: Option<$crate::projections::FactorsFn> = Some(|p, lam, phi| {
+            $crate::projections::downcast!($name, p)
+                .factors(lam, phi)
+                .into()
+        });
+    };
+}
+
 //
 // Use the following declaration in projection modules
 //
 // `super::projection!(projection_name);`
 //
+// Optional delegate functions are listed after a semicolon:
+//
+// `super::projection!(projection_name; inverse_valid_domain, factors);`
+//
 macro_rules! projection_delegate {
-    ($name:ident, $($init:ident),+ $(,)?) => {
+    ($name:ident, [$($ext:ident),*], $($init:ident),+ $(,)?) => {
         pub(crate) mod stub {
             use $crate::errors::Result;
             use $crate::parameters::ParamList;
             use $crate::proj::ProjData;
+            // Shadowed by the optional delegate functions
+            #[allow(unused_imports)]
+            use $crate::projections::delegate_defaults::*;
             use $crate::projections::{$name, ProjDelegate, ProjParams};
+            $(delegate_ext!($name, $ext);)*
             $(pub(crate) fn $init(p: &mut ProjData, params: &ParamList) -> Result<ProjDelegate> {
                 Ok(ProjDelegate(
                     ProjParams::$name($name::Projection::$init(p, params)?),
@@ -103,6 +172,8 @@ macro_rules! projection_delegate {
                     forward_,
                     $name::Projection::has_inverse(),
                     $name::Projection::has_forward(),
+                    INVERSE_VALID_DOMAIN,
+                    FACTORS,
                 ))
             })+
             pub(crate) fn inverse_(
@@ -126,15 +197,15 @@ macro_rules! projection_delegate {
 }
 
 macro_rules! projection {
-    ($name:ident $(,)? $($init:ident),*) => {
-        projection_delegate!{ $name, $name, $($init,)* }
+    ($name:ident $(,)? $($init:ident),* $(; $($ext:ident),+)?) => {
+        projection_delegate!{ $name, [$($($ext),+)?], $name, $($init,)* }
     };
 }
 
 use downcast;
 use projection;
 
-const NUM_PROJECTIONS: usize = 20;
+pub(crate) const NUM_PROJECTIONS: usize = 68;
 
 macro_rules! declare_projections {
     ($(($name:ident $(,)? $($init:ident),*)),+ $(,)?) => {
@@ -161,18 +232,49 @@ macro_rules! declare_projections {
 // ---------------------------
 
 pub mod aea;
+pub mod aeqd;
+pub mod bacon;
+pub mod cass;
+pub mod cea;
+pub mod col_urban;
+pub mod comill;
+pub mod eqc;
 pub mod estmerc;
 pub mod etmerc;
+pub mod euler;
 pub mod geocent;
+pub mod geos;
+pub mod gins8;
+pub mod gnom;
+pub mod gs48;
+pub mod hatano;
+pub mod isea;
+pub mod krovak;
 pub mod laea;
 pub mod latlong;
 pub mod lcc;
+pub mod mbt_s;
 pub mod merc;
 pub mod moll;
+pub mod natearth2;
+pub mod ob_tran;
+pub mod oea;
+pub mod omerc;
+pub mod ortho;
+pub mod patterson;
+pub mod qsc;
+pub mod robin;
+pub mod sch;
+pub mod sinu;
 pub mod somerc;
 pub mod stere;
 pub mod sterea;
 pub mod tmerc;
+pub mod tpeqd;
+pub mod vandg;
+pub mod vandg2;
+pub mod vandg4;
+pub mod wag7;
 
 #[rustfmt::skip]
 declare_projections! [
@@ -188,6 +290,37 @@ declare_projections! [
     (somerc),
     (laea),
     (moll, wag4, wag5),
+    (geos),
+    (ob_tran),
+    (isea),
+    (natearth2),
+    (patterson),
+    (comill),
+    (euler, murd1, murd2, murd3, pconic, tissot, vitk1),
+    (vandg2, vandg3),
+    (vandg4),
+    (bacon, apian, ortel),
+    (gs48, gs50, alsk),
+    (mbt_s, mbt_fps, mbtfpp, mbtfpq),
+    (hatano),
+    (gins8),
+    (oea),
+    (tpeqd),
+    (sch),
+    (col_urban),
+    (ortho),
+    (eqc),
+    (qsc),
+    (aeqd),
+    (sinu, gn_sinu, eck6, mbtfps),
+    (robin),
+    (krovak),
+    (omerc),
+    (cea),
+    (cass),
+    (vandg),
+    (gnom),
+    (wag7),
 ];
 
 ///
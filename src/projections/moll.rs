@@ -8,11 +8,11 @@
 //! wag5: "Wagner V" "\n\tPCyl., Sph.";
 //!
 use crate::ellps::Ellipsoid;
-#[allow(unused_imports)]
 use crate::errors::{Error, Result};
 use crate::math::{
     aasin,
-    consts::{FRAC_PI_2, PI, TAU},
+    consts::{EPS_10, FRAC_PI_2, FRAC_PI_4, PI, TAU},
+    newton,
 };
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
@@ -20,11 +20,15 @@ use crate::proj::ProjData;
 // Projection stub
 super::projection! { moll, wag4, wag5 }
 
+const NITER: usize = 10;
+const TOL: f64 = 1e-7;
+
 #[derive(Debug, Clone)]
 pub(crate) struct Projection {
     c_x: f64,
     c_y: f64,
     c_p: f64,
+    moll: bool,
 }
 
 impl Projection {
@@ -44,6 +48,7 @@ impl Projection {
             c_x: 0.90977,
             c_y: 1.65014,
             c_p: 3.00896,
+            moll: false,
         })
     }
 
@@ -60,30 +65,34 @@ impl Projection {
             c_x: 2. * r / PI,
             c_y: r / sp,
             c_p,
+            moll: pp == FRAC_PI_2,
         })
     }
 
     #[inline(always)]
-    pub fn forward(&self, lam: f64, mut phi: f64, z: f64) -> Result<(f64, f64, f64)> {
-        const NITER: isize = 10;
-        const TOL: f64 = 1e-7;
-
-        let k = self.c_p * phi.sin();
-        let mut i = NITER;
-        while i > 0 {
-            let v = (phi + phi.sin() - k) / (1. + phi.cos());
-            phi -= v;
-            if v.abs() < TOL {
-                break;
-            }
-            i -= 1;
-        }
-        if i == 0 {
-            phi = FRAC_PI_2 * phi.signum();
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // Solve t + sin(t) = c_p * sin(phi), with theta = t / 2
+        let theta = if (phi.abs() - FRAC_PI_2).abs() < EPS_10 {
+            FRAC_PI_2.copysign(phi)
+        } else if self.moll && phi.abs() > FRAC_PI_4 {
+            // For the Mollweide, the root is degenerate at the poles (t = ±π):
+            // solve u - sin(u) = π (1 - sin|phi|) for u = π - |t| instead,
+            // starting from the series expansion u³/6 = π (1 - sin|phi|)
+            let d = TAU * (0.5 * (FRAC_PI_2 - phi.abs())).sin().powi(2);
+            let s = (6. * d).cbrt();
+            let u = if s < 1e-3 {
+                s * (1. + s * s / 60.)
+            } else {
+                newton(s, TOL, NITER, |u| (u - u.sin() - d) / (1. - u.cos()))
+                    .map_err(|_| Error::ForwardProjectionFailure)?
+            };
+            (0.5 * (PI - u)).copysign(phi)
         } else {
-            phi *= 0.5;
-        }
-        Ok((self.c_x * lam * phi.cos(), self.c_y * phi.sin(), z))
+            let k = self.c_p * phi.sin();
+            0.5 * newton(phi, TOL, NITER, |t| (t + t.sin() - k) / (1. + t.cos()))
+                .map_err(|_| Error::ForwardProjectionFailure)?
+        };
+        Ok((self.c_x * lam * theta.cos(), self.c_y * theta.sin(), z))
     }
 
     #[cfg(not(feature = "proj4js-compat"))]
@@ -150,6 +159,44 @@ mod tests {
         test_proj_inverse(&p, &inputs, EPS_10);
     }
 
+    #[test]
+    fn proj_moll_pole() {
+        let p = Proj::from_proj_string("+proj=moll +R=6400000").unwrap();
+
+        // Equator and mid-latitude
+        let inputs = [
+            ((10., 0., 0.), (1005662.9776875343, 0., 0.)),
+            ((-60., 45., 0.), (-4862826.773894611, 5358550.221464842, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+
+        // Close to the poles
+        let inputs = [
+            ((120., 89.99, 0.), (39805.69344748794, 9050917.56246612, 0.)),
+            (
+                (120., -89.99, 0.),
+                (39805.69344748794, -9050917.56246612, 0.),
+            ),
+            (
+                (30., 89.9999999, 0.),
+                (4.619046402063862, 9050966.799177201, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs[..2], 1.0e-8);
+
+        // At the poles
+        let inputs = [
+            ((0., 90., 0.), (0., 9050966.79918781, 0.)),
+            ((0., -90., 0.), (0., -9050966.79918781, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+    }
+
     #[test]
     fn proj_wag4() {
         let p = Proj::from_proj_string("+proj=wag4").unwrap();
@@ -189,4 +236,33 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_wag_round_trip() {
+        let p = Proj::from_proj_string("+proj=wag4 +R=6400000").unwrap();
+
+        let inputs = [
+            ((30., 45., 0.), (2409826.1457151, 5540292.474589425, 0.)),
+            (
+                (-120., -50., 0.),
+                (-9198142.636511272, -6076809.5546637345, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+
+        let p = Proj::from_proj_string("+proj=wag5 +R=6400000").unwrap();
+
+        let inputs = [
+            ((30., 45., 0.), (2518937.5404021568, 5949116.84057745, 0.)),
+            (
+                (-120., -50., 0.),
+                (-9584475.713515015, -6529618.210211453, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
 }
@@ -0,0 +1,151 @@
+//!
+//! Natural Earth II
+//!
+//! ref: <https://proj.org/operations/projections/natearth2.html>
+//!
+//! natearth2: "Natural Earth 2" "\n\tPCyl., Sph."
+//!
+//! The projection is defined by polynomials in latitude, designed by
+//! Bojan Savric, Tom Patterson and Bernhard Jenny.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{consts::PI, newton};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { natearth2 }
+
+const A0: f64 = 0.84719;
+const A1: f64 = -0.13063;
+const A2: f64 = -0.04515;
+const A3: f64 = 0.05494;
+const A4: f64 = -0.02326;
+const A5: f64 = 0.00331;
+const B0: f64 = 1.01183;
+const B1: f64 = -0.02625;
+const B2: f64 = 0.01926;
+const B3: f64 = -0.00396;
+const C0: f64 = B0;
+const C1: f64 = 9. * B1;
+const C2: f64 = 11. * B2;
+const C3: f64 = 13. * B3;
+
+const EPS: f64 = 1e-11;
+const MAX_Y: f64 = 0.84719 * 0.535117535153096 * PI;
+const MAX_ITER: usize = 100;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn natearth2(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    fn x_scale(phi: f64) -> f64 {
+        let phi2 = phi * phi;
+        let phi4 = phi2 * phi2;
+        let phi6 = phi2 * phi4;
+        A0 + A1 * phi2 + phi6 * phi6 * (A2 + A3 * phi2 + A4 * phi4 + A5 * phi6)
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let phi2 = phi * phi;
+        let phi4 = phi2 * phi2;
+        Ok((
+            lam * Self::x_scale(phi),
+            phi * (B0 + phi4 * phi4 * (B1 + B2 * phi2 + B3 * phi4)),
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // make sure y is inside valid range
+        let y = y.clamp(-MAX_Y, MAX_Y);
+
+        // latitude: Newton-Raphson
+        let yc = newton(y, EPS, MAX_ITER, |yc| {
+            let y2 = yc * yc;
+            let y4 = y2 * y2;
+            let f = yc * (B0 + y4 * y4 * (B1 + B2 * y2 + B3 * y4)) - y;
+            let fder = C0 + y4 * y4 * (C1 + C2 * y2 + C3 * y4);
+            f / fder
+        })
+        .map_err(|_| Error::InverseProjectionFailure)?;
+
+        // longitude
+        Ok((x / Self::x_scale(yc), yc, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_natearth2() {
+        let p = Proj::from_proj_string("+proj=natearth2 +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (189255.17293473083, 113022.49581090701, 0.)),
+            ((2., -1., 0.), (189255.17293473083, -113022.49581090701, 0.)),
+            ((-2., 1., 0.), (-189255.17293473083, 113022.49581090701, 0.)),
+            (
+                (-2., -1., 0.),
+                (-189255.17293473083, -113022.49581090701, 0.),
+            ),
+            ((30., 45., 0.), (2565368.368708349, 5074458.184814872, 0.)),
+            (
+                (120., -70., 0.),
+                (8155352.865863897, -7666106.941536834, 0.),
+            ),
+            ((-179., 89., 0.), (-4687876.32054593, 9104372.836986855, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_natearth2_ellps() {
+        // Ellipsoid is mapped to the sphere of radius a
+        let p = Proj::from_proj_string("+proj=natearth2 +ellps=WGS84").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (188608.65952131333, 112636.40036935797, 0.)),
+            ((30., 45., 0.), (2556604.8298575566, 5057123.35992509, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_natearth2_inverse_pole() {
+        let p = Proj::from_proj_string("+proj=natearth2").unwrap();
+
+        let (_, y, _) = p.projection().forward(0., FRAC_PI_2, 0.).unwrap();
+        // y beyond the pole is clamped
+        let (_, phi, _) = p.projection().inverse(0., 2. * y, 0.).unwrap();
+        assert!((phi - FRAC_PI_2).abs() < 1.0e-10);
+    }
+}
@@ -0,0 +1,262 @@
+//!
+//! General Oblique Transformation
+//!
+//! ref: <https://proj.org/operations/projections/ob_tran.html>
+//!
+//! ob_tran: "General Oblique Transformation" "\n\tMisc Sph"
+//! "\n\to_proj= plus parameters for projection"
+//! "\n\to_lat_p= o_lon_p= (new pole) or"
+//! "\n\to_alpha= o_lon_c= o_lat_c= or"
+//! "\n\to_lon_1= o_lat_1= o_lon_2= o_lat_2="
+//!
+//! The rotation is a spherical operation: as in proj4, the earth
+//! is forced to a sphere (with the same semi-major axis) for both the
+//! oblique transformation and the rotated projection given by `o_proj`,
+//! even if the latter supports ellipsoids.
+//!
+//! Rotated coordinates from `o_proj=latlong` are returned in radians
+//! scaled by the semi-major axis, use `+a=1 +to_meter=0.0174532925199433`
+//! to get degrees.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{
+    aasin, aatan2, adjlon,
+    consts::{EPS_10, FRAC_PI_2},
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+use crate::projections::{find_projection, ProjDelegate};
+
+// Projection stub
+super::projection! { ob_tran }
+
+const TOL: f64 = EPS_10;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    link: Box<ProjDelegate>,
+    lamp: f64,
+    cphip: f64,
+    sphip: f64,
+    oblique: bool,
+}
+
+impl Projection {
+    pub fn ob_tran(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        // get name of projection to be translated
+        let name: &str = params
+            .try_value("o_proj")?
+            .ok_or(Error::InvalidParameterValue("Missing parameter o_proj"))?;
+
+        // avoid endless recursion
+        if name.eq_ignore_ascii_case("ob_tran") {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for o_proj: it should be different from ob_tran",
+            ));
+        }
+
+        let proj_init = find_projection(name).ok_or(Error::ProjectionNotFound)?;
+
+        // Force spherical earth
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        // The rotated projection get its own copy of the
+        // projection data.
+        let mut link_data = p.clone();
+        let link = Box::new(proj_init.init(&mut link_data, params)?);
+
+        let angle = |name| -> Result<f64> { Ok(params.try_angular_value(name)?.unwrap_or(0.)) };
+
+        let (lamp, phip) = if params.get("o_alpha").is_some() {
+            let lamc = angle("o_lon_c")?;
+            let phic = angle("o_lat_c")?;
+            let alpha = angle("o_alpha")?;
+
+            if (phic.abs() - FRAC_PI_2).abs() <= TOL {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for o_lat_c: |o_lat_c| should be < 90°",
+                ));
+            }
+
+            (
+                lamc + aatan2(-alpha.cos(), -alpha.sin() * phic.sin()),
+                aasin(phic.cos() * alpha.sin())?,
+            )
+        } else if params.get("o_lat_p").is_some() {
+            // specified new pole
+            (angle("o_lon_p")?, angle("o_lat_p")?)
+        } else {
+            // specified new "equator" points
+            let lam1 = angle("o_lon_1")?;
+            let phi1 = angle("o_lat_1")?;
+            let lam2 = angle("o_lon_2")?;
+            let phi2 = angle("o_lat_2")?;
+
+            if phi1.abs() > FRAC_PI_2 - TOL {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for o_lat_1: |o_lat_1| should be < 90°",
+                ));
+            }
+            if phi2.abs() > FRAC_PI_2 - TOL {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for o_lat_2: |o_lat_2| should be < 90°",
+                ));
+            }
+            if (phi1 - phi2).abs() < TOL {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for o_lat_1 and o_lat_2: o_lat_1 should be different from o_lat_2",
+                ));
+            }
+            if phi1.abs() < TOL {
+                return Err(Error::InvalidParameterValue(
+                    "Invalid value for o_lat_1: o_lat_1 should be different from 0",
+                ));
+            }
+
+            let lamp = (phi1.cos() * phi2.sin() * lam1.cos()
+                - phi1.sin() * phi2.cos() * lam2.cos())
+            .atan2(phi1.sin() * phi2.cos() * lam2.sin() - phi1.cos() * phi2.sin() * lam1.sin());
+            (lamp, (-(lamp - lam1).cos() / phi1.tan()).atan())
+        };
+
+        let (sphip, cphip) = phip.sin_cos();
+
+        Ok(Self {
+            link,
+            lamp,
+            cphip,
+            sphip,
+            oblique: phip.abs() > TOL,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if !self.link.has_forward() {
+            return Err(Error::NoForwardProjectionDefined);
+        }
+
+        let (sinlam, coslam) = lam.sin_cos();
+        let (sinphi, cosphi) = phi.sin_cos();
+
+        let (lam, phi) = if self.oblique {
+            (
+                // Formula (5-8b) of Snyder's "Map projections: a working manual"
+                adjlon(
+                    aatan2(
+                        cosphi * sinlam,
+                        self.sphip * cosphi * coslam + self.cphip * sinphi,
+                    ) + self.lamp,
+                ),
+                // Formula (5-7)
+                aasin(self.sphip * sinphi - self.cphip * cosphi * coslam)?,
+            )
+        } else {
+            (
+                adjlon(aatan2(cosphi * sinlam, sinphi) + self.lamp),
+                aasin(-cosphi * coslam)?,
+            )
+        };
+
+        self.link.forward(lam, phi, z)
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if !self.link.has_inverse() {
+            return Err(Error::NoInverseProjectionDefined);
+        }
+
+        let (lam, phi, z) = self.link.inverse(x, y, z)?;
+
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = (lam - self.lamp).sin_cos();
+
+        if self.oblique {
+            Ok((
+                // Formula (5-10b)
+                aatan2(
+                    cosphi * sinlam,
+                    self.sphip * cosphi * coslam - self.cphip * sinphi,
+                ),
+                // Formula (5-9)
+                aasin(self.sphip * sinphi + self.cphip * cosphi * coslam)?,
+                z,
+            ))
+        } else {
+            Ok((aatan2(cosphi * sinlam, -sinphi), aasin(cosphi * coslam)?, z))
+        }
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_ob_tran_moll() {
+        let p =
+            Proj::from_proj_string("+proj=ob_tran +o_proj=moll +o_lat_p=45 +o_lon_p=0").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (226958.84639910352, -5227664.759933702, 0.)),
+            ((2., -1., 0.), (229925.6265274753, -5444548.622388931, 0.)),
+            ((-2., 1., 0.), (-226958.84639910352, -5227664.759933702, 0.)),
+            ((-2., -1., 0.), (-229925.6265274753, -5444548.622388931, 0.)),
+            ((30., 50., 0.), (1887679.2874880107, 1051218.039813525, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_ob_tran_force_sphere() {
+        // The rotated projection is computed on the sphere
+        let p_el = Proj::from_proj_string(
+            "+proj=ob_tran +o_proj=tmerc +o_lat_p=30 +o_lon_p=10 +ellps=GRS80",
+        )
+        .unwrap();
+        let p_sp = Proj::from_proj_string(
+            "+proj=ob_tran +o_proj=tmerc +o_lat_p=30 +o_lon_p=10 +R=6378137",
+        )
+        .unwrap();
+
+        assert!(!p_el.data().ellps.is_ellipsoid());
+
+        let (lam, phi) = (2f64.to_radians(), 1f64.to_radians());
+        assert_eq!(
+            p_el.projection().forward(lam, phi, 0.).unwrap(),
+            p_sp.projection().forward(lam, phi, 0.).unwrap(),
+        );
+    }
+
+    #[test]
+    fn proj_ob_tran_invalid() {
+        assert!(matches!(
+            Proj::from_proj_string("+proj=ob_tran +o_lat_p=45"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+        assert!(matches!(
+            Proj::from_proj_string("+proj=ob_tran +o_proj=ob_tran +o_lat_p=45"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+        assert!(matches!(
+            Proj::from_proj_string("+proj=ob_tran +o_proj=foo +o_lat_p=45"),
+            Err(Error::ProjectionNotFound)
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+//!
+//! Oblated Equal Area
+//!
+//! ref: <https://proj.org/operations/projections/oea.html>
+//!
+//! oea: "Oblated Equal Area" "\n\tMisc Sph\n\tn= m= theta="
+//!
+//! `m` and `n` control the shape of the oval, `theta` rotates it.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{aacos, aasin, aatan2};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { oea }
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    theta: f64,
+    m: f64,
+    n: f64,
+    two_r_m: f64,
+    two_r_n: f64,
+    rm: f64,
+    rn: f64,
+    hm: f64,
+    hn: f64,
+    cp0: f64,
+    sp0: f64,
+}
+
+impl Projection {
+    pub fn oea(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let n: f64 = params.try_value("n")?.unwrap_or(0.);
+        if n <= 0. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for n: it should be > 0",
+            ));
+        }
+        let m: f64 = params.try_value("m")?.unwrap_or(0.);
+        if m <= 0. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for m: it should be > 0",
+            ));
+        }
+
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+
+        Ok(Self {
+            theta: params.try_angular_value("theta")?.unwrap_or(0.),
+            m,
+            n,
+            two_r_m: 2. / m,
+            two_r_n: 2. / n,
+            rm: 1. / m,
+            rn: 1. / n,
+            hm: 0.5 * m,
+            hn: 0.5 * n,
+            cp0: p.phi0.cos(),
+            sp0: p.phi0.sin(),
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sp, cp) = phi.sin_cos();
+        let cl = lam.cos();
+        let az = aatan2(cp * lam.sin(), self.cp0 * sp - self.sp0 * cp * cl) + self.theta;
+        let shz = (0.5 * aacos(self.sp0 * sp + self.cp0 * cp * cl)?).sin();
+        let mm = aasin(shz * az.sin())?;
+        let nn = aasin(shz * az.cos() * mm.cos() / (mm * self.two_r_m).cos())?;
+        Ok((
+            self.m * (mm * self.two_r_m).sin() * nn.cos() / (nn * self.two_r_n).cos(),
+            self.n * (nn * self.two_r_n).sin(),
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let nn = self.hn * aasin(y * self.rn)?;
+        let mm = self.hm * aasin(x * self.rm * (nn * self.two_r_n).cos() / nn.cos())?;
+        let xp = 2. * mm.sin();
+        let yp = 2. * nn.sin() * (mm * self.two_r_m).cos() / mm.cos();
+        let az = aatan2(xp, yp) - self.theta;
+        let (saz, caz) = az.sin_cos();
+        let (sz, cz) = (2. * aasin(0.5 * xp.hypot(yp))?).sin_cos();
+        Ok((
+            aatan2(sz * saz, self.cp0 * cz - self.sp0 * sz * caz),
+            aasin(self.sp0 * cz + self.cp0 * sz * caz)?,
+            z,
+        ))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_oea() {
+        let p = Proj::from_proj_string("+proj=oea +R=6400000 +n=1 +m=2 +theta=3").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (228926.87209787167, 99870.48843007932, 0.)),
+            ((2., -1., 0.), (217242.58403694766, -123247.88560747862, 0.)),
+            ((-2., 1., 0.), (-217242.58403694766, 123247.88560747862, 0.)),
+            (
+                (-2., -1., 0.),
+                (-228926.87209787167, -99870.48843007932, 0.),
+            ),
+            ((30., 45., 0.), (3634288.5756058237, 4527815.303793712, 0.)),
+            (
+                (-60., -30., 0.),
+                (-6617574.570329159, -3347553.975684552, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_oea_lat_0() {
+        let p =
+            Proj::from_proj_string("+proj=oea +R=6400000 +n=1 +m=2 +theta=3 +lat_0=30").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (69476.77612360015, -3108310.585042362, 0.)),
+            (
+                (-2., -1., 0.),
+                (-460751.7591661632, -3279906.7251439923, 0.),
+            ),
+            ((30., 45., 0.), (2517376.747041535, 1873436.5634517288, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_oea_invalid() {
+        for projstr in ["+proj=oea +m=2", "+proj=oea +n=1", "+proj=oea +n=1 +m=-1"] {
+            assert!(matches!(
+                Proj::from_proj_string(projstr),
+                Err(Error::InvalidParameterValue(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,481 @@
+//!
+//! Oblique Mercator
+//!
+//! ref: <https://proj.org/operations/projections/omerc.html>
+//!
+//! omerc: "Oblique Mercator" "\n\tCyl, Sph&Ell no_rot\n\talpha= [gamma=] [no_off] lonc= or\n\t lon_1= lat_1= lon_2= lat_2="
+//!
+//! Hotine Oblique Mercator, the center line is defined either by the
+//! projection center (`lat_0`, `lonc`) and its azimuth `alpha`
+//! or the angle `gamma` from the rectified grid to the oblique grid,
+//! or by two points (`lon_1`, `lat_1`) and (`lon_2`, `lat_2`).
+//!
+//! With the azimuth, the coordinates are relative to the projection center
+//! (EPSG variant B) by default; with `+no_uoff` (or `+no_off`) they are
+//! relative to the natural origin of the projection (EPSG variant A).
+//!
+//! With `+no_rot`, the coordinates are not rotated to the rectified
+//! grid, and are relative to the natural origin.
+//!
+use crate::errors::{Error, Result};
+use crate::math::{
+    aasin, adjlon,
+    consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU},
+    phi2, tsfn,
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { omerc }
+
+const TOL: f64 = 1.0e-7;
+const EPS: f64 = 1.0e-10;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    e: f64,
+    a: f64,
+    b: f64,
+    e_: f64,
+    ar_b: f64,
+    br_a: f64,
+    r_b: f64,
+    singam: f64,
+    cosgam: f64,
+    sinrot: f64,
+    cosrot: f64,
+    v_pole_n: f64,
+    v_pole_s: f64,
+    u_0: f64,
+    no_rot: bool,
+}
+
+/// Constants of the Hotine formulation at the projection center
+struct Hotine {
+    a: f64,
+    b: f64,
+    e: f64,
+    d: f64,
+    f: f64,
+}
+
+impl Hotine {
+    fn new(p: &ProjData) -> Self {
+        let el = &p.ellps;
+        let com = el.one_es.sqrt();
+        if p.phi0.abs() > EPS {
+            let (sinph0, cosph0) = p.phi0.sin_cos();
+            let con = 1. - el.es * sinph0 * sinph0;
+            let b = (1. + el.es * cosph0.powi(4) / el.one_es).sqrt();
+            let d = b * com / (cosph0 * con.sqrt());
+            let f = if d * d - 1. <= 0. {
+                d
+            } else {
+                d + (d * d - 1.).sqrt().copysign(p.phi0)
+            };
+            Self {
+                a: b * p.k0 * com / con,
+                b,
+                e: f * tsfn(p.phi0, sinph0, el.e).powf(b),
+                d,
+                f,
+            }
+        } else {
+            Self {
+                a: p.k0,
+                b: 1. / com,
+                e: 1.,
+                d: 1.,
+                f: 1.,
+            }
+        }
+    }
+
+    /// Return the azimuth of the center line and the angles to the
+    /// rectified grid from `alpha` and/or `gamma`, and set the longitude
+    /// of the natural origin from the longitude of the projection
+    /// center `lonc`
+    fn azimuth(
+        &self,
+        p: &mut ProjData,
+        alpha: Option<f64>,
+        gamma: Option<f64>,
+        lamc: f64,
+    ) -> Result<(f64, f64, f64)> {
+        if (p.phi0.abs() - FRAC_PI_2).abs() <= TOL {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_0: |lat_0| should be < 90°",
+            ));
+        }
+        let (alpha_c, gamma0, gamma) = match (alpha, gamma) {
+            (Some(alpha_c), gamma) => {
+                let gamma0 = aasin(alpha_c.sin() / self.d)?;
+                (alpha_c, gamma0, gamma.unwrap_or(alpha_c))
+            }
+            (None, Some(gamma)) => (aasin(self.d * gamma.sin())?, gamma, gamma),
+            (None, None) => unreachable!(),
+        };
+        let f = self.f;
+        p.lam0 = lamc - aasin(0.5 * (f - 1. / f) * gamma0.tan())? / self.b;
+        Ok((alpha_c, gamma0, gamma))
+    }
+
+    /// Return the azimuth of the center line and the angles to the
+    /// rectified grid from two points on the center line, and set
+    /// the longitude of the natural origin
+    fn two_points(
+        &self,
+        p: &mut ProjData,
+        (lam1, phi1): (f64, f64),
+        (mut lam2, phi2): (f64, f64),
+    ) -> Result<(f64, f64, f64)> {
+        if phi1.abs() > FRAC_PI_2 - TOL {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_1: |lat_1| should be < 90°",
+            ));
+        }
+        if phi2.abs() > FRAC_PI_2 - TOL {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_2: |lat_2| should be < 90°",
+            ));
+        }
+        if (phi1 - phi2).abs() <= TOL {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_1 and lat_2: lat_1 should be different from lat_2",
+            ));
+        }
+        if phi1.abs() <= TOL {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_1: lat_1 should be different from 0",
+            ));
+        }
+        if (p.phi0.abs() - FRAC_PI_2).abs() <= TOL {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_0: |lat_0| should be < 90°",
+            ));
+        }
+
+        let e = p.ellps.e;
+        let h = tsfn(phi1, phi1.sin(), e).powf(self.b);
+        let l = tsfn(phi2, phi2.sin(), e).powf(self.b);
+        let f = self.e / h;
+        let pp = (l - h) / (l + h);
+        if pp == 0. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_1 and lat_2",
+            ));
+        }
+        let j = self.e * self.e;
+        let j = (j - l * h) / (j + l * h);
+        let con = lam1 - lam2;
+        if con < -PI {
+            lam2 -= TAU;
+        } else if con > PI {
+            lam2 += TAU;
+        }
+        p.lam0 = adjlon(
+            0.5 * (lam1 + lam2) - (j * (0.5 * self.b * (lam1 - lam2)).tan() / pp).atan() / self.b,
+        );
+        let denom = f - 1. / f;
+        if denom == 0. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_1 and lat_2",
+            ));
+        }
+        let gamma0 = (2. * (self.b * adjlon(lam1 - p.lam0)).sin() / denom).atan();
+        let alpha_c = aasin(self.d * gamma0.sin())?;
+        Ok((alpha_c, gamma0, alpha_c))
+    }
+}
+
+impl Projection {
+    pub fn omerc(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let no_rot = params.check_option("no_rot")?;
+        let alpha = params.try_angular_value("alpha")?;
+        let gamma = params.try_angular_value("gamma")?;
+
+        let hotine = Hotine::new(p);
+
+        let (alpha_c, gamma0, gamma, no_off) = if alpha.is_some() || gamma.is_some() {
+            let lamc = params.try_angular_value("lonc")?.unwrap_or(0.);
+            let (alpha_c, gamma0, gamma) = hotine.azimuth(p, alpha, gamma, lamc)?;
+            let no_off = params.check_option("no_off")? || params.check_option("no_uoff")?;
+            (alpha_c, gamma0, gamma, no_off)
+        } else {
+            let point = |lon, lat| -> Result<(f64, f64)> {
+                Ok((
+                    params.try_angular_value(lon)?.unwrap_or(0.),
+                    params.try_angular_value(lat)?.unwrap_or(0.),
+                ))
+            };
+            let (alpha_c, gamma0, gamma) =
+                hotine.two_points(p, point("lon_1", "lat_1")?, point("lon_2", "lat_2")?)?;
+            (alpha_c, gamma0, gamma, false)
+        };
+
+        let Hotine { a, b, e: e_, d, .. } = hotine;
+
+        let r_b = 1. / b;
+        let ar_b = a * r_b;
+
+        let u_0 = if no_off {
+            0.
+        } else {
+            (ar_b * ((d * d - 1.).sqrt() / alpha_c.cos()).atan())
+                .abs()
+                .copysign(p.phi0)
+        };
+
+        Ok(Self {
+            e: p.ellps.e,
+            a,
+            b,
+            e_,
+            ar_b,
+            br_a: 1. / ar_b,
+            r_b,
+            singam: gamma0.sin(),
+            cosgam: gamma0.cos(),
+            sinrot: gamma.sin(),
+            cosrot: gamma.cos(),
+            v_pole_n: ar_b * (FRAC_PI_4 - 0.5 * gamma0).tan().ln(),
+            v_pole_s: ar_b * (FRAC_PI_4 + 0.5 * gamma0).tan().ln(),
+            u_0,
+            no_rot,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (u, v) = if (phi.abs() - FRAC_PI_2).abs() > EPS {
+            let q = self.e_ / tsfn(phi, phi.sin(), self.e).powf(self.b);
+            let temp = 1. / q;
+            let s = 0.5 * (q - temp);
+            let t = 0.5 * (q + temp);
+            let v = (self.b * lam).sin();
+            let u = (s * self.singam - v * self.cosgam) / t;
+            if (u.abs() - 1.).abs() < EPS {
+                return Err(Error::CoordTransOutsideProjectionDomain);
+            }
+            let temp = (self.b * lam).cos();
+            (
+                if temp.abs() < TOL {
+                    self.a * lam
+                } else {
+                    self.ar_b * (s * self.cosgam + v * self.singam).atan2(temp)
+                },
+                0.5 * self.ar_b * ((1. - u) / (1. + u)).ln(),
+            )
+        } else {
+            (
+                self.ar_b * phi,
+                if phi > 0. {
+                    self.v_pole_n
+                } else {
+                    self.v_pole_s
+                },
+            )
+        };
+
+        if self.no_rot {
+            Ok((u, v, z))
+        } else {
+            let u = u - self.u_0;
+            Ok((
+                v * self.cosrot + u * self.sinrot,
+                u * self.cosrot - v * self.sinrot,
+                z,
+            ))
+        }
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (u, v) = if self.no_rot {
+            (x, y)
+        } else {
+            (
+                y * self.cosrot + x * self.sinrot + self.u_0,
+                x * self.cosrot - y * self.sinrot,
+            )
+        };
+
+        let qp = (-self.br_a * v).exp();
+        if qp == 0. {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let sp = 0.5 * (qp - 1. / qp);
+        let tp = 0.5 * (qp + 1. / qp);
+        let vp = (self.br_a * u).sin();
+        let up = (vp * self.cosgam + sp * self.singam) / tp;
+        if (up.abs() - 1.).abs() < EPS {
+            Ok((0., FRAC_PI_2.copysign(up), z))
+        } else {
+            let ts = (self.e_ / ((1. + up) / (1. - up)).sqrt()).powf(1. / self.b);
+            Ok((
+                -self.r_b * (sp * self.cosgam - vp * self.singam).atan2((self.br_a * u).cos()),
+                phi2(ts, self.e)?,
+                z,
+            ))
+        }
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    // EPSG:29873, Timbalai 1948 / RSO Borneo (m)
+    const TIMBALAI: &str = concat!(
+        "+proj=omerc +lat_0=4 +lonc=115 +alpha=53.31582047222222 +gamma=53.13010236111111",
+        " +k=0.99984 +x_0=590476.87 +y_0=442857.65 +ellps=evrstSS +units=m",
+    );
+
+    #[test]
+    fn proj_omerc_variant_b() {
+        let p = Proj::from_proj_string(TIMBALAI).unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // EPSG Guidance Note 7-2, example for Hotine Oblique Mercator
+        // variant B (EPSG:9815)
+        let inputs = [(
+            (
+                115. + 48. / 60. + 19.8196 / 3600.,
+                5. + 23. / 60. + 14.1129 / 3600.,
+                0.,
+            ),
+            (679245.7281789328, 596562.7774724748, 0.),
+        )];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        // The projection center is at the false origin
+        test_proj_forward(&p, &[((115., 4., 0.), (590476.87, 442857.65, 0.))], 1.0e-6);
+    }
+
+    #[test]
+    fn proj_omerc_variant_a() {
+        let p = Proj::from_proj_string(&format!("{TIMBALAI} +no_uoff")).unwrap();
+
+        // Same point with variant A (EPSG:9812): coordinates are relative
+        // to the natural origin, the false origin is shifted by
+        // uc = 738096.0941164047 m along the rectified v axis.
+        let inputs = [(
+            (
+                115. + 48. / 60. + 19.8196 / 3600.,
+                5. + 23. / 60. + 14.1129 / 3600.,
+                0.,
+            ),
+            (1269722.603525815, 1039420.4338706397, 0.),
+        )];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        // `+no_off` is an alias of `+no_uoff`
+        let q = Proj::from_proj_string(&format!("{TIMBALAI} +no_off")).unwrap();
+        test_proj_forward(&q, &inputs, 1.0e-6);
+
+        // The difference between both variants is uc rotated by gamma
+        let b = Proj::from_proj_string(TIMBALAI).unwrap();
+        let (lam, phi) = (0.01, 0.08);
+        let (xa, ya, _) = p.projection().forward(lam, phi, 0.).unwrap();
+        let (xb, yb, _) = b.projection().forward(lam, phi, 0.).unwrap();
+        let (uc, gamma) = (
+            738096.0941164047 / p.data().ellps.a,
+            53.13010236111111f64.to_radians(),
+        );
+        assert!((xa - xb - uc * gamma.sin()).abs() < EPS_10);
+        assert!((ya - yb - uc * gamma.cos()).abs() < EPS_10);
+    }
+
+    #[test]
+    fn proj_omerc_no_rot() {
+        let p = Proj::from_proj_string(&format!("{TIMBALAI} +no_rot")).unwrap();
+        let (lam, phi) = (0.01, 0.08);
+        let (u, v, _) = p.projection().forward(lam, phi, 0.).unwrap();
+        let (lam_i, phi_i, _) = p.projection().inverse(u, v, 0.).unwrap();
+        assert!((lam_i - lam).abs() < EPS_10);
+        assert!((phi_i - phi).abs() < EPS_10);
+
+        // Unrotated coordinates are relative to the natural origin
+        let a = Proj::from_proj_string(&format!("{TIMBALAI} +no_uoff")).unwrap();
+        let (x, y, _) = a.projection().forward(lam, phi, 0.).unwrap();
+        let gamma = 53.13010236111111f64.to_radians();
+        assert!((x - (v * gamma.cos() + u * gamma.sin())).abs() < EPS_10);
+        assert!((y - (u * gamma.cos() - v * gamma.sin())).abs() < EPS_10);
+    }
+
+    #[test]
+    fn proj_omerc_two_points() {
+        let p = Proj::from_proj_string("+proj=omerc +ellps=GRS80 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222650.796885261, 110642.229314984, 0.)),
+            ((2., -1., 0.), (222650.796885261, -110642.229314984, 0.)),
+            ((-2., 1., 0.), (-222650.796885261, 110642.229314984, 0.)),
+            ((-2., -1., 0.), (-222650.796885261, -110642.229314984, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        for projstr in [
+            "+proj=omerc +ellps=GRS80 +lat_1=1 +lat_2=1",
+            "+proj=omerc +ellps=GRS80 +lat_1=0 +lat_2=2",
+            "+proj=omerc +ellps=GRS80 +lat_1=90 +lat_2=2",
+            "+proj=omerc +ellps=GRS80 +lat_1=1 +lat_2=2 +lat_0=90",
+        ] {
+            assert!(
+                matches!(
+                    Proj::from_proj_string(projstr),
+                    Err(Error::InvalidParameterValue(_))
+                ),
+                "{projstr}"
+            );
+        }
+    }
+
+    #[test]
+    fn proj_omerc_peninsula_rso() {
+        // EPSG:3375, GDM2000 / Peninsula RSO (variant A)
+        let p = Proj::from_proj_string(concat!(
+            "+proj=omerc +lat_0=4 +lonc=102.25 +alpha=323.0257964666666",
+            " +k=0.99984 +x_0=804671 +y_0=0 +no_uoff +gamma=323.1301023611111",
+            " +ellps=GRS80 +units=m +no_defs",
+        ))
+        .unwrap();
+
+        let inputs = [
+            // Kuala Lumpur
+            (
+                (101.6869, 3.139, 0.),
+                (410073.0009195694, 347389.5845652996, 0.),
+            ),
+            // George Town
+            (
+                (100.3327, 5.4141, 0.),
+                (260649.1155237697, 599486.9722309036, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+}
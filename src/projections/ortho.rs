@@ -0,0 +1,474 @@
+//!
+//! Orthographic
+//!
+//! ref: <https://proj.org/operations/projections/ortho.html>
+//!
+//! ortho: "Orthographic" "\n\tAzi, Sph&Ell";
+//!
+//! The ellipsoidal formulation follows the EPSG guidance note 7-2,
+//! §3.3.5 (Orthographic), as introduced in PROJ 7.2.
+//!
+use crate::errors::{Error, Result};
+use crate::math::{
+    adjlon,
+    consts::{EPS_10, FRAC_PI_2, PI},
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { ortho }
+
+#[allow(non_camel_case_types)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    N_POLE,
+    S_POLE,
+    EQUIT,
+    OBLIQ,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    phi0: f64,
+    sinph0: f64,
+    cosph0: f64,
+    mode: Mode,
+    // Ellipsoid
+    es: f64,
+    one_es: f64,
+    b: f64,
+    nu0: f64,
+    y_shift: f64,
+    y_scale: f64,
+}
+
+impl Projection {
+    pub fn ortho(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        let phi0 = p.phi0;
+        let (sinph0, cosph0) = phi0.sin_cos();
+
+        let mode = if (phi0.abs() - FRAC_PI_2).abs() <= EPS_10 {
+            if phi0 < 0. {
+                Mode::S_POLE
+            } else {
+                Mode::N_POLE
+            }
+        } else if phi0.abs() > EPS_10 {
+            Mode::OBLIQ
+        } else {
+            Mode::EQUIT
+        };
+
+        let es = p.ellps.es;
+        let nu0 = 1. / (1. - es * sinph0 * sinph0).sqrt();
+
+        Ok(Self {
+            phi0,
+            sinph0,
+            cosph0,
+            mode,
+            es,
+            one_es: p.ellps.one_es,
+            b: p.ellps.b / p.ellps.a,
+            nu0,
+            y_shift: es * nu0 * sinph0 * cosph0,
+            y_scale: 1. / (1. - es * cosph0 * cosph0).sqrt(),
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.es == 0. {
+            self.s_forward(lam, phi, z)
+        } else {
+            self.e_forward(lam, phi, z)
+        }
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if self.es == 0. {
+            self.s_inverse(x, y, z)
+        } else {
+            self.e_inverse(x, y, z)
+        }
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+
+    //
+    // Sphere
+    //
+
+    fn s_forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = lam.sin_cos();
+
+        let y = match self.mode {
+            Mode::EQUIT => {
+                if cosphi * coslam < -EPS_10 {
+                    return Err(Error::ToleranceConditionError);
+                }
+                sinphi
+            }
+            Mode::OBLIQ => {
+                if self.sinph0 * sinphi + self.cosph0 * cosphi * coslam < -EPS_10 {
+                    return Err(Error::ToleranceConditionError);
+                }
+                self.cosph0 * sinphi - self.sinph0 * cosphi * coslam
+            }
+            Mode::N_POLE | Mode::S_POLE => {
+                if (phi - self.phi0).abs() - EPS_10 > FRAC_PI_2 {
+                    return Err(Error::ToleranceConditionError);
+                }
+                if self.mode == Mode::N_POLE {
+                    -cosphi * coslam
+                } else {
+                    cosphi * coslam
+                }
+            }
+        };
+
+        Ok((cosphi * sinlam, y, z))
+    }
+
+    fn s_inverse(&self, mut x: f64, mut y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let rh = x.hypot(y);
+        let mut sinc = rh;
+        if sinc > 1. {
+            if sinc - 1. > EPS_10 {
+                return Err(Error::ToleranceConditionError);
+            }
+            sinc = 1.;
+        }
+        let cosc = (1. - sinc * sinc).sqrt();
+        if rh.abs() <= EPS_10 {
+            return Ok((0., self.phi0, z));
+        }
+
+        let phi = match self.mode {
+            Mode::N_POLE => {
+                y = -y;
+                sinc.acos()
+            }
+            Mode::S_POLE => -sinc.acos(),
+            Mode::EQUIT | Mode::OBLIQ => {
+                let phi = if self.mode == Mode::EQUIT {
+                    let phi = y * sinc / rh;
+                    x *= sinc;
+                    y = cosc * rh;
+                    phi
+                } else {
+                    let phi = cosc * self.sinph0 + y * sinc * self.cosph0 / rh;
+                    y = (cosc - self.sinph0 * phi) * rh;
+                    x *= sinc * self.cosph0;
+                    phi
+                };
+                if phi.abs() >= 1. {
+                    FRAC_PI_2.copysign(phi)
+                } else {
+                    phi.asin()
+                }
+            }
+        };
+
+        let lam = if y == 0. && matches!(self.mode, Mode::OBLIQ | Mode::EQUIT) {
+            if x == 0. {
+                0.
+            } else {
+                FRAC_PI_2.copysign(x)
+            }
+        } else {
+            x.atan2(y)
+        };
+
+        Ok((lam, phi, z))
+    }
+
+    //
+    // Ellipsoid
+    //
+
+    fn e_forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (sinphi, cosphi) = phi.sin_cos();
+        let (sinlam, coslam) = lam.sin_cos();
+
+        // The visibility condition is the same as for the sphere
+        if self.sinph0 * sinphi + self.cosph0 * cosphi * coslam < -EPS_10 {
+            return Err(Error::ToleranceConditionError);
+        }
+
+        let nu = 1. / (1. - self.es * sinphi * sinphi).sqrt();
+        Ok((
+            nu * cosphi * sinlam,
+            nu * (sinphi * self.cosph0 - cosphi * self.sinph0 * coslam)
+                + self.es * (self.nu0 * self.sinph0 - nu * sinphi) * self.cosph0,
+            z,
+        ))
+    }
+
+    fn e_inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let es = self.es;
+
+        match self.mode {
+            Mode::N_POLE | Mode::S_POLE => {
+                // x = nu * cosphi * sinlam
+                // y = -nu * cosphi * coslam * sign(phi0)
+                // => cosphi^2 = rh^2 * (1 - es) / (1 - es * rh^2)
+                let rh2 = x * x + y * y;
+                let phi = if rh2 >= 1. - 1.0e-15 {
+                    if rh2 - 1. > EPS_10 {
+                        return Err(Error::ToleranceConditionError);
+                    }
+                    0.
+                } else {
+                    let phi = (rh2 * self.one_es / (1. - es * rh2)).sqrt().acos();
+                    if self.mode == Mode::N_POLE {
+                        phi
+                    } else {
+                        -phi
+                    }
+                };
+                let lam = if self.mode == Mode::N_POLE {
+                    x.atan2(-y)
+                } else {
+                    x.atan2(y)
+                };
+                Ok((lam, phi, z))
+            }
+            Mode::EQUIT => {
+                // x = nu * cosphi * sinlam
+                // y = nu * sinphi * (1 - es)
+
+                // Equation of the ellipse
+                if x * x + (y / self.b) * (y / self.b) > 1. + 1.0e-11 {
+                    return Err(Error::ToleranceConditionError);
+                }
+
+                let sinphi2 = if y == 0. {
+                    0.
+                } else {
+                    1. / ((self.one_es / y) * (self.one_es / y) + es)
+                };
+                if sinphi2 > 1. - 1.0e-11 {
+                    return Ok((0., FRAC_PI_2.copysign(y), z));
+                }
+                let phi = sinphi2.sqrt().asin().copysign(y);
+                let sinlam = x * ((1. - es * sinphi2) / (1. - sinphi2)).sqrt();
+                let lam = if sinlam.abs() - 1. > -1.0e-15 {
+                    FRAC_PI_2.copysign(x)
+                } else {
+                    sinlam.asin()
+                };
+                Ok((lam, phi, z))
+            }
+            Mode::OBLIQ => {
+                // Using the visibility condition of the forward
+                // projection, the limb is an ellipse centered on
+                // (0, y_shift).
+                let yr = (y - self.y_shift) / self.y_scale;
+                if x * x + yr * yr > 1. + 1.0e-11 {
+                    return Err(Error::ToleranceConditionError);
+                }
+
+                // Start from the spherical solution, this converges
+                // better than (0, phi0) near the poles.
+                let (mut lam, mut phi, _) = self.s_inverse(x, yr, z)?;
+
+                let (sinph0, cosph0) = (self.sinph0, self.cosph0);
+                for _ in 0..20 {
+                    let (sinphi, cosphi) = phi.sin_cos();
+                    let (sinlam, coslam) = lam.sin_cos();
+                    let one_minus_es_sinphi2 = 1. - es * sinphi * sinphi;
+                    let nu = 1. / one_minus_es_sinphi2.sqrt();
+
+                    let x_new = nu * cosphi * sinlam;
+                    let y_new = nu * (sinphi * cosph0 - cosphi * sinph0 * coslam)
+                        + es * (self.nu0 * sinph0 - nu * sinphi) * cosph0;
+
+                    let rho = self.one_es * nu / one_minus_es_sinphi2;
+                    let j11 = -rho * sinphi * sinlam;
+                    let j12 = nu * cosphi * coslam;
+                    let j21 = rho * (cosphi * cosph0 + sinphi * sinph0 * coslam);
+                    let j22 = nu * sinph0 * cosphi * sinlam;
+                    let d = j11 * j22 - j12 * j21;
+
+                    let (dx, dy) = (x - x_new, y - y_new);
+                    let dphi = (j22 * dx - j12 * dy) / d;
+                    let dlam = (-j21 * dx + j11 * dy) / d;
+
+                    phi += dphi;
+                    if phi > FRAC_PI_2 {
+                        phi = PI - phi;
+                        lam = adjlon(lam + PI);
+                    } else if phi < -FRAC_PI_2 {
+                        phi = -PI - phi;
+                        lam = adjlon(lam + PI);
+                    }
+                    lam += dlam;
+                    if dphi.abs() < 1.0e-12 && dlam.abs() < 1.0e-12 {
+                        return Ok((lam, phi, z));
+                    }
+                }
+                Err(Error::InvMeridDistConvError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+    use crate::transform::transform;
+
+    #[test]
+    fn proj_ortho_sp() {
+        let p = Proj::from_proj_string("+proj=ortho +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223322.7605767275, 111695.40119861448, 0.)),
+            ((2., -1., 0.), (223322.7605767275, -111695.40119861448, 0.)),
+            ((-2., 1., 0.), (-223322.7605767275, 111695.40119861448, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223322.7605767275, -111695.40119861448, 0.),
+            ),
+            ((60., 30., 0.), (4800000., 3200000., 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_ortho_el() {
+        let p = Proj::from_proj_string("+proj=ortho +ellps=WGS84").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222560.09599219257, 110568.77482456664, 0.)),
+            ((2., -1., 0.), (222560.09599219257, -110568.77482456664, 0.)),
+            ((-2., 1., 0.), (-222560.09599219257, 110568.77482456664, 0.)),
+            (
+                (-2., -1., 0.),
+                (-222560.09599219257, -110568.77482456664, 0.),
+            ),
+            ((60., 30., 0.), (4787610.688267582, 3170373.7353836377, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+    }
+
+    #[test]
+    fn proj_ortho_el_obliq() {
+        let p = Proj::from_proj_string("+proj=ortho +ellps=WGS84 +lat_0=45").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (222560.09599219257, -4407026.0677153785, 0.)),
+            ((60., 30., 0.), (4787610.688267582, 308643.5438985542, 0.)),
+            (
+                (-100., 40., 0.),
+                (-4818376.377773342, 3505720.7289638366, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+    }
+
+    #[test]
+    fn proj_ortho_el_pole() {
+        let p = Proj::from_proj_string("+proj=ortho +ellps=WGS84 +lat_0=90").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (222560.09599219257, -6373287.279502436, 0.)),
+            ((60., 30., 0.), (4787610.688267582, -2764128.319646418, 0.)),
+            (
+                (-100., 40., 0.),
+                (-4818376.377773342, 849609.7586097632, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+    }
+
+    #[test]
+    fn proj_ortho_limb() {
+        let el = Proj::from_proj_string("+proj=ortho +ellps=WGS84 +lat_0=45").unwrap();
+        let sp = Proj::from_proj_string("+proj=ortho +R=6378137 +lat_0=45").unwrap();
+
+        // Points close to the limb, on the visible side
+        for (lam, phi) in [(89.9f64, 0f64), (-120., 26.6), (180., 45.1), (0., -44.9)] {
+            let (lam, phi) = (lam.to_radians(), phi.to_radians());
+            let (xe, ye, _) = el.projection().forward(lam, phi, 0.).unwrap();
+            let (xs, ys, _) = sp.projection().forward(lam, phi, 0.).unwrap();
+
+            // The spherical approximation is off by kilometers
+            // (coordinates are on the unit ellipsoid)
+            assert!((xe - xs).hypot(ye - ys) > 1.0e-3);
+
+            // The ellipsoidal inverse still converges near the limb
+            let (lam_i, phi_i, _) = el.projection().inverse(xe, ye, 0.).unwrap();
+            assert!((lam_i - lam).abs() < 1.0e-9);
+            assert!((phi_i - phi).abs() < 1.0e-9);
+        }
+
+        // Points on the far side are not visible
+        for p in [&el, &sp] {
+            assert!(p
+                .projection()
+                .forward(0., (-46f64).to_radians(), 0.)
+                .is_err());
+            assert!(p.projection().inverse(0., 1.01, 0.).is_err());
+        }
+    }
+
+    #[test]
+    fn proj_ortho_far_side() {
+        let geo = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+
+        for defn in ["+ellps=WGS84", "+R=6378137"] {
+            for (lat_0, lon_0) in [(0., 0.), (90., 0.), (-90., 30.), (40., -100.)] {
+                let p = Proj::from_proj_string(&format!(
+                    "+proj=ortho {defn} +lat_0={lat_0} +lon_0={lon_0}"
+                ))
+                .unwrap();
+
+                // The antipode of the center is on the back hemisphere
+                let mut pt = (
+                    f64::to_radians(lon_0 + 180.),
+                    f64::to_radians(-lat_0 * 0.9),
+                    0.,
+                );
+                assert!(matches!(
+                    transform(&geo, &p, &mut pt),
+                    Err(Error::ToleranceConditionError)
+                ));
+
+                // Outside the disk of the visible hemisphere (the limb
+                // of the oblique ellipsoid is slightly shifted north)
+                let mut pt = (0., 1.01 * p.data().ellps.a, 0.);
+                assert!(matches!(
+                    transform(&p, &geo, &mut pt),
+                    Err(Error::ToleranceConditionError)
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//!
+//! Patterson Cylindrical
+//!
+//! ref: <https://proj.org/operations/projections/patterson.html>
+//!
+//! patterson: "Patterson Cylindrical" "\n\tCyl., Sph."
+//!
+//! A compromise cylindrical projection designed by Tom Patterson,
+//! Bojan Savric and Bernhard Jenny. The northing is given by a
+//! polynomial in latitude, the easting is the longitude.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::newton;
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { patterson }
+
+const K1: f64 = 1.0148;
+const K2: f64 = 0.23185;
+const K3: f64 = -0.14499;
+const K4: f64 = 0.02406;
+const C1: f64 = K1;
+const C2: f64 = 5. * K2;
+const C3: f64 = 7. * K3;
+const C4: f64 = 9. * K4;
+
+const EPS: f64 = 1e-11;
+const MAX_Y: f64 = 1.790857183;
+const MAX_ITER: usize = 100;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn patterson(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let phi2 = phi * phi;
+        Ok((
+            lam,
+            phi * (K1 + phi2 * phi2 * (K2 + phi2 * (K3 + K4 * phi2))),
+            z,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // make sure y is inside valid range
+        let y = y.clamp(-MAX_Y, MAX_Y);
+
+        // Newton-Raphson
+        let yc = newton(y, EPS, MAX_ITER, |yc| {
+            let y2 = yc * yc;
+            let f = yc * (K1 + y2 * y2 * (K2 + y2 * (K3 + K4 * y2))) - y;
+            let fder = C1 + y2 * y2 * (C2 + y2 * (C3 + C4 * y2));
+            f / fder
+        })
+        .map_err(|_| Error::InverseProjectionFailure)?;
+
+        Ok((x, yc, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::consts::{EPS_10, FRAC_PI_2};
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_patterson() {
+        let p = Proj::from_proj_string("+proj=patterson +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223402.14425527418, 113354.2503977798, 0.)),
+            ((2., -1., 0.), (223402.14425527418, -113354.2503977798, 0.)),
+            ((-2., 1., 0.), (-223402.14425527418, 113354.2503977798, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223402.14425527418, -113354.2503977798, 0.),
+            ),
+            ((30., 45., 0.), (3351032.163829113, 5390833.245994266, 0.)),
+            (
+                (120., -70., 0.),
+                (13404128.655316452, -9137468.32294371, 0.),
+            ),
+            (
+                (-179., 89., 0.),
+                (-19994491.91084704, 11366701.856308272, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_patterson_offsets() {
+        let p = Proj::from_proj_string(
+            "+proj=patterson +ellps=WGS84 +lon_0=10 +x_0=1000000 +y_0=500000",
+        )
+        .unwrap();
+
+        let inputs = [
+            ((12., 1., 0.), (1222638.981586547, 612967.02165146, 0.)),
+            ((40., 45., 0.), (4339584.723798207, 5872417.654235333, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_patterson_inverse_pole() {
+        let p = Proj::from_proj_string("+proj=patterson").unwrap();
+
+        // y beyond the pole is clamped
+        let (_, phi, _) = p.projection().inverse(0., 3., 0.).unwrap();
+        assert!((phi - FRAC_PI_2).abs() < 1.0e-9);
+    }
+}
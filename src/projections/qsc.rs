@@ -0,0 +1,457 @@
+//!
+//! Quadrilateralized Spherical Cube
+//!
+//! ref: <https://proj.org/operations/projections/qsc.html>
+//!
+//! qsc: "Quadrilateralized Spherical Cube" "\n\tAzi, Sph"
+//!
+//! The projection maps a single face of the cube, selected from the
+//! center of projection (`lat_0`, `lon_0`). The six faces are indexed
+//! as follow:
+//!
+//! * 0: front (centered on `lon=0`, `lat=0`)
+//! * 1: right (centered on `lon=90`)
+//! * 2: back (centered on `lon=180`)
+//! * 3: left (centered on `lon=-90`)
+//! * 4: top (north pole)
+//! * 5: bottom (south pole)
+//!
+//! References:
+//!
+//! * \[OL76\] E.M. O'Neill and R.E. Laubscher, "Extended Studies of a
+//!   Quadrilateralized Spherical Cube Earth Data Base", Naval
+//!   Environmental Prediction Research Facility Tech. Report NEPRF 3-76 (CSC), 1976.
+//! * \[LK12\] M. Lambers and A. Kolb, "Ellipsoidal Cube Maps for Accurate
+//!   Rendering of Planetary-Scale Terrain Data", Proc. Pacific Graphics
+//!   (Short Papers), Sep. 2012
+//!
+use crate::errors::Result;
+use crate::math::consts::{EPS_10, FRAC_PI_2, FRAC_PI_4, PI, TAU};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { qsc }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Face {
+    Front = 0,
+    Right = 1,
+    Back = 2,
+    Left = 3,
+    Top = 4,
+    Bottom = 5,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Area {
+    A0,
+    A1,
+    A2,
+    A3,
+}
+
+/// Return the index of the cube face containing the point
+/// (`lon`, `lat`), in radians, on the sphere.
+///
+/// This is the face of the cube centered on the nearest
+/// axis, see the [module](self) documentation for the indices.
+///
+/// ```rust
+/// use proj4rs::projections::qsc::qsc_face;
+///
+/// assert_eq!(qsc_face(0., 0.), 0);
+/// assert_eq!(qsc_face(100f64.to_radians(), 10f64.to_radians()), 1);
+/// assert_eq!(qsc_face(0., 80f64.to_radians()), 4);
+/// ```
+pub fn qsc_face(lon: f64, lat: f64) -> u8 {
+    let (sinlat, coslat) = lat.sin_cos();
+    let (sinlon, coslon) = lon.sin_cos();
+    let (q, r, s) = (coslat * coslon, coslat * sinlon, sinlat);
+
+    let face = if s.abs() >= q.abs() && s.abs() >= r.abs() {
+        if s >= 0. {
+            Face::Top
+        } else {
+            Face::Bottom
+        }
+    } else if q.abs() >= r.abs() {
+        if q >= 0. {
+            Face::Front
+        } else {
+            Face::Back
+        }
+    } else if r >= 0. {
+        Face::Right
+    } else {
+        Face::Left
+    };
+    face as u8
+}
+
+#[inline]
+fn fwd_equat_face_theta(phi: f64, y: f64, x: f64) -> (f64, Area) {
+    if phi < EPS_10 {
+        (0., Area::A0)
+    } else {
+        let theta = y.atan2(x);
+        if theta.abs() <= FRAC_PI_4 {
+            (theta, Area::A0)
+        } else if theta > FRAC_PI_4 && theta <= FRAC_PI_2 + FRAC_PI_4 {
+            (theta - FRAC_PI_2, Area::A1)
+        } else if theta > FRAC_PI_2 + FRAC_PI_4 || theta <= -(FRAC_PI_2 + FRAC_PI_4) {
+            (if theta >= 0. { theta - PI } else { theta + PI }, Area::A2)
+        } else {
+            (theta + FRAC_PI_2, Area::A3)
+        }
+    }
+}
+
+#[inline]
+fn shift_longitude_origin(lon: f64, offset: f64) -> f64 {
+    let slon = lon + offset;
+    if slon < -PI {
+        slon + TAU
+    } else if slon > PI {
+        slon - TAU
+    } else {
+        slon
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    face: Face,
+    es: f64,
+    b: f64,
+    one_minus_f: f64,
+    one_minus_f_squared: f64,
+}
+
+impl Projection {
+    pub fn qsc(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Determine the cube face from the center of projection
+        let face = if p.phi0 >= FRAC_PI_2 - FRAC_PI_4 / 2. {
+            Face::Top
+        } else if p.phi0 <= -(FRAC_PI_2 - FRAC_PI_4 / 2.) {
+            Face::Bottom
+        } else if p.lam0.abs() <= FRAC_PI_4 {
+            Face::Front
+        } else if p.lam0.abs() <= FRAC_PI_2 + FRAC_PI_4 {
+            if p.lam0 > 0. {
+                Face::Right
+            } else {
+                Face::Left
+            }
+        } else {
+            Face::Back
+        };
+
+        // Values for the ellipsoid <-> sphere shift
+        // described in [LK12], on the unit ellipsoid.
+        let es = p.ellps.es;
+        let b = (1. - es).sqrt();
+        let one_minus_f = b;
+
+        Ok(Self {
+            face,
+            es,
+            b,
+            one_minus_f,
+            one_minus_f_squared: one_minus_f * one_minus_f,
+        })
+    }
+
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // Convert the geodetic latitude to a geocentric latitude.
+        // This corresponds to the shift from the ellipsoid to the sphere
+        // described in [LK12].
+        let lat = if self.es != 0. {
+            (self.one_minus_f_squared * phi.tan()).atan()
+        } else {
+            phi
+        };
+
+        // Convert the input lat, lon into theta, phi as used by QSC.
+        // This depends on the cube face and the area on it.
+        // For the top and bottom face, we can compute theta and phi
+        // directly from phi, lam. For the other faces, we must use
+        // unit sphere cartesian coordinates as an intermediate step.
+        let mut lon = lam;
+        let (theta, phi, area) = match self.face {
+            Face::Top => {
+                let (theta, area) = if (FRAC_PI_4..=FRAC_PI_2 + FRAC_PI_4).contains(&lon) {
+                    (lon - FRAC_PI_2, Area::A0)
+                } else if lon > FRAC_PI_2 + FRAC_PI_4 || lon <= -(FRAC_PI_2 + FRAC_PI_4) {
+                    (if lon > 0. { lon - PI } else { lon + PI }, Area::A1)
+                } else if lon > -(FRAC_PI_2 + FRAC_PI_4) && lon <= -FRAC_PI_4 {
+                    (lon + FRAC_PI_2, Area::A2)
+                } else {
+                    (lon, Area::A3)
+                };
+                (theta, FRAC_PI_2 - lat, area)
+            }
+            Face::Bottom => {
+                let (theta, area) = if (FRAC_PI_4..=FRAC_PI_2 + FRAC_PI_4).contains(&lon) {
+                    (-lon + FRAC_PI_2, Area::A0)
+                } else if (-FRAC_PI_4..FRAC_PI_4).contains(&lon) {
+                    (-lon, Area::A1)
+                } else if (-(FRAC_PI_2 + FRAC_PI_4)..-FRAC_PI_4).contains(&lon) {
+                    (-lon - FRAC_PI_2, Area::A2)
+                } else {
+                    (if lon > 0. { -lon + PI } else { -lon - PI }, Area::A3)
+                };
+                (theta, FRAC_PI_2 + lat, area)
+            }
+            _ => {
+                match self.face {
+                    Face::Right => lon = shift_longitude_origin(lon, FRAC_PI_2),
+                    Face::Back => lon = shift_longitude_origin(lon, PI),
+                    Face::Left => lon = shift_longitude_origin(lon, -FRAC_PI_2),
+                    _ => (),
+                }
+                let (sinlat, coslat) = lat.sin_cos();
+                let (sinlon, coslon) = lon.sin_cos();
+                let (q, r, s) = (coslat * coslon, coslat * sinlon, sinlat);
+
+                let (phi, y, x) = match self.face {
+                    Face::Front => (q.acos(), s, r),
+                    Face::Right => (r.acos(), s, -q),
+                    Face::Back => ((-q).acos(), s, -r),
+                    _ => ((-r).acos(), s, q),
+                };
+                let (theta, area) = fwd_equat_face_theta(phi, y, x);
+                (theta, phi, area)
+            }
+        };
+
+        // Compute mu and nu for the area of definition.
+        // For mu, see Eq. (3-21) in [OL76], but note the typos:
+        // compare with Eq. (3-14). For nu, see Eq. (3-38).
+        let mut mu =
+            ((12. / PI) * (theta + (theta.sin() * FRAC_PI_4.cos()).acos() - FRAC_PI_2)).atan();
+        let t = ((1. - phi.cos()) / (mu.cos() * mu.cos()) / (1. - (1. / theta.cos()).atan().cos()))
+            .sqrt();
+
+        // Apply the result to the real area.
+        match area {
+            Area::A0 => (),
+            Area::A1 => mu += FRAC_PI_2,
+            Area::A2 => mu += PI,
+            Area::A3 => mu += PI + FRAC_PI_2,
+        }
+
+        // Now compute x, y from mu and nu
+        Ok((t * mu.cos(), t * mu.sin(), z))
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // Convert the input x, y to the mu and nu angles as used by QSC.
+        // This depends on the area of the cube face.
+        let nu = x.hypot(y).atan();
+        let mut mu = y.atan2(x);
+        let area = if x >= 0. && x >= y.abs() {
+            Area::A0
+        } else if y >= 0. && y >= x.abs() {
+            mu -= FRAC_PI_2;
+            Area::A1
+        } else if x < 0. && -x >= y.abs() {
+            mu = if mu < 0. { mu + PI } else { mu - PI };
+            Area::A2
+        } else {
+            mu += FRAC_PI_2;
+            Area::A3
+        };
+
+        // Compute phi and theta for the area of definition.
+        let t = (PI / 12.) * mu.tan();
+        let theta = (t.sin() / (t.cos() - std::f64::consts::FRAC_1_SQRT_2)).atan();
+        let (cosmu, tannu) = (mu.cos(), nu.tan());
+        let cosphi = (1. - cosmu * cosmu * tannu * tannu * (1. - (1. / theta.cos()).atan().cos()))
+            .clamp(-1., 1.);
+
+        // Apply the result to the real area on the cube face.
+        // For the top and bottom face, we can compute phi and lam directly.
+        // For the other faces, we must use unit sphere cartesian coordinates
+        // as an intermediate step.
+        let (lam, mut phi) = match self.face {
+            Face::Top => (
+                match area {
+                    Area::A0 => theta + FRAC_PI_2,
+                    Area::A1 => {
+                        if theta < 0. {
+                            theta + PI
+                        } else {
+                            theta - PI
+                        }
+                    }
+                    Area::A2 => theta - FRAC_PI_2,
+                    Area::A3 => theta,
+                },
+                FRAC_PI_2 - cosphi.acos(),
+            ),
+            Face::Bottom => (
+                match area {
+                    Area::A0 => -theta + FRAC_PI_2,
+                    Area::A1 => -theta,
+                    Area::A2 => -theta - FRAC_PI_2,
+                    Area::A3 => {
+                        if theta < 0. {
+                            -theta - PI
+                        } else {
+                            -theta + PI
+                        }
+                    }
+                },
+                cosphi.acos() - FRAC_PI_2,
+            ),
+            _ => {
+                // Compute phi and lam via cartesian unit sphere coordinates.
+                let mut q = cosphi;
+                let mut t = q * q;
+                let mut s = if t >= 1. {
+                    0.
+                } else {
+                    (1. - t).sqrt() * theta.sin()
+                };
+                t += s * s;
+                let mut r = if t >= 1. { 0. } else { (1. - t).sqrt() };
+
+                // Rotate q,r,s into the correct area.
+                match area {
+                    Area::A0 => (),
+                    Area::A1 => (r, s) = (-s, r),
+                    Area::A2 => (r, s) = (-r, -s),
+                    Area::A3 => (r, s) = (s, -r),
+                }
+
+                // Rotate q,r,s into the correct cube face.
+                match self.face {
+                    Face::Right => (q, r) = (-r, q),
+                    Face::Back => (q, r) = (-q, -r),
+                    Face::Left => (q, r) = (r, -q),
+                    _ => (),
+                }
+
+                // Now compute phi and lam from the unit sphere coordinates.
+                let lam = r.atan2(q);
+                (
+                    match self.face {
+                        Face::Right => shift_longitude_origin(lam, -FRAC_PI_2),
+                        Face::Back => shift_longitude_origin(lam, -PI),
+                        Face::Left => shift_longitude_origin(lam, FRAC_PI_2),
+                        _ => lam,
+                    },
+                    (-s).acos() - FRAC_PI_2,
+                )
+            }
+        };
+
+        // Apply the shift from the sphere to the ellipsoid as described
+        // in [LK12].
+        if self.es != 0. {
+            let tanphi = phi.tan();
+            let xa = self.b / (tanphi * tanphi + self.one_minus_f_squared).sqrt();
+            phi = ((1. - xa * xa).sqrt() / (self.one_minus_f * xa))
+                .atan()
+                .copysign(phi);
+        }
+
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::qsc_face;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_qsc_el() {
+        let p = Proj::from_proj_string("+proj=qsc +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (304638.450843852, 164123.870923794, 0.)),
+            ((2., -1., 0.), (304638.450843852, -164123.870923794, 0.)),
+            ((-2., 1., 0.), (-304638.450843852, 164123.870923794, 0.)),
+            ((-2., -1., 0.), (-304638.450843852, -164123.870923794, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_qsc_sp() {
+        let p = Proj::from_proj_string("+proj=qsc +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (305863.79240289045, 165827.7227547152, 0.)),
+            ((2., -1., 0.), (305863.79240289045, -165827.7227547152, 0.)),
+            ((-2., 1., 0.), (-305863.79240289045, 165827.7227547152, 0.)),
+            (
+                (-2., -1., 0.),
+                (-305863.79240289045, -165827.7227547152, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_qsc_faces_round_trip() {
+        for (lat_0, lon_0, lon, lat) in [
+            (90., 0., 30., 70.),
+            (-90., 0., -150., -60.),
+            (0., 90., 80., 10.),
+            (0., 180., 0., -20.),
+            (0., -90., 10., 30.),
+        ] {
+            let p = Proj::from_proj_string(&format!(
+                "+proj=qsc +ellps=WGS84 +lat_0={lat_0} +lon_0={lon_0}"
+            ))
+            .unwrap();
+
+            let (lam, phi) = (f64::to_radians(lon), f64::to_radians(lat));
+            let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+            let (lam_i, phi_i, _) = p.projection().inverse(x, y, 0.).unwrap();
+            assert!((lam_i - lam).abs() < 1.0e-10, "{lat_0} {lon_0}");
+            assert!((phi_i - phi).abs() < 1.0e-10, "{lat_0} {lon_0}");
+        }
+    }
+
+    #[test]
+    fn proj_qsc_face() {
+        let faces = [
+            ((0., 0.), 0),
+            ((90., 0.), 1),
+            ((180., 0.), 2),
+            ((-180., 0.), 2),
+            ((-90., 0.), 3),
+            ((0., 90.), 4),
+            ((0., -90.), 5),
+        ];
+        for ((lon, lat), face) in faces {
+            // Points near the face center
+            for (dlon, dlat) in [(0., 0.), (10., 10.), (-20., 5.), (15., -25.)] {
+                let (lon, lat) = (f64::to_radians(lon + dlon), f64::to_radians(lat + dlat));
+                assert_eq!(qsc_face(lon, lat), face, "{lon} {lat}");
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//!
+//! Robinson
+//!
+//! ref: <https://proj.org/operations/projections/robin.html>
+//!
+//! robin: "Robinson" "\n\tPCyl, Sph"
+//!
+//! The projection is defined by a table of lengths of the parallels
+//! and distances to the equator, every 5 degrees of latitude. Values
+//! between the nodes are computed from cubic splines.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{FRAC_PI_2, PI};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { robin }
+
+/// Cubic spline coefficients of an interval of 5 degrees
+#[derive(Debug, Copy, Clone)]
+struct Coefs {
+    c0: f64,
+    c1: f64,
+    c2: f64,
+    c3: f64,
+}
+
+impl Coefs {
+    /// Value at `z` degrees from the start of the interval
+    #[inline(always)]
+    fn v(&self, z: f64) -> f64 {
+        self.c0 + z * (self.c1 + z * (self.c2 + z * self.c3))
+    }
+
+    /// Derivative at `z` degrees from the start of the interval
+    #[inline(always)]
+    fn dv(&self, z: f64) -> f64 {
+        self.c1 + 2. * z * self.c2 + 3. * z * z * self.c3
+    }
+}
+
+// Coefficients are single precision in proj
+macro_rules! coefs {
+    ($c0:expr, $c1:expr, $c2:expr, $c3:expr) => {
+        Coefs {
+            c0: $c0 as f32 as f64,
+            c1: $c1 as f32 as f64,
+            c2: $c2 as f32 as f64,
+            c3: $c3 as f32 as f64,
+        }
+    };
+}
+
+/// Length of the parallels, relative to the equator
+#[rustfmt::skip]
+const X: [Coefs; NODES + 1] = [
+    coefs!(1.0,    2.2199e-17,  -7.15515e-05,  3.1103e-06),
+    coefs!(0.9986, -0.000482243, -2.4897e-05,  -1.3309e-06),
+    coefs!(0.9954, -0.00083103, -4.48605e-05,  -9.86701e-07),
+    coefs!(0.99,   -0.00135364, -5.9661e-05,   3.6777e-06),
+    coefs!(0.9822, -0.00167442, -4.49547e-06,  -5.72411e-06),
+    coefs!(0.973,  -0.00214868, -9.03571e-05,  1.8736e-08),
+    coefs!(0.96,   -0.00305085, -9.00761e-05,  1.64917e-06),
+    coefs!(0.9427, -0.00382792, -6.53386e-05,  -2.6154e-06),
+    coefs!(0.9216, -0.00467746, -0.00010457,   4.81243e-06),
+    coefs!(0.8962, -0.00536223, -3.23831e-05,  -5.43432e-06),
+    coefs!(0.8679, -0.00609363, -0.000113898,  3.32484e-06),
+    coefs!(0.835,  -0.00698325, -6.40253e-05,  9.34959e-07),
+    coefs!(0.7986, -0.00755338, -5.00009e-05,  9.35324e-07),
+    coefs!(0.7597, -0.00798324, -3.5971e-05,   -2.27626e-06),
+    coefs!(0.7186, -0.00851367, -7.01149e-05,  -8.6303e-06),
+    coefs!(0.6732, -0.00986209, -0.000199569,  1.91974e-05),
+    coefs!(0.6213, -0.010418,   8.83923e-05,   6.24051e-06),
+    coefs!(0.5722, -0.00906601, 0.000182,      6.24051e-06),
+    coefs!(0.5322, -0.00677797, 0.000275608,   6.24051e-06),
+];
+
+/// Distance of the parallels to the equator, relative to the pole
+#[rustfmt::skip]
+const Y: [Coefs; NODES + 1] = [
+    coefs!(-5.20417e-18, 0.0124,    1.21431e-18,  -8.45284e-11),
+    coefs!(0.062,        0.0124,    -1.26793e-09, 4.22642e-10),
+    coefs!(0.124,        0.0124,    5.07171e-09,  -1.60604e-09),
+    coefs!(0.186,        0.0123999, -1.90189e-08, 6.00152e-09),
+    coefs!(0.248,        0.0124002, 7.10039e-08,  -2.24e-08),
+    coefs!(0.31,         0.0123992, -2.64997e-07, 8.35986e-08),
+    coefs!(0.372,        0.0124029, 9.88983e-07,  -3.11994e-07),
+    coefs!(0.434,        0.0123893, -3.69093e-06, -4.35621e-07),
+    coefs!(0.4958,       0.0123198, -1.02252e-05, -3.45523e-07),
+    coefs!(0.5571,       0.0121916, -1.54081e-05, -5.82288e-07),
+    coefs!(0.6176,       0.0119938, -2.41424e-05, -5.25327e-07),
+    coefs!(0.6769,       0.011713,  -3.20223e-05, -5.16405e-07),
+    coefs!(0.7346,       0.0113541, -3.97684e-05, -6.09052e-07),
+    coefs!(0.7903,       0.0109107, -4.89042e-05, -1.04739e-06),
+    coefs!(0.8435,       0.0103431, -6.4615e-05,  -1.40374e-09),
+    coefs!(0.8936,       0.00969686, -6.4636e-05, -8.547e-06),
+    coefs!(0.9394,       0.00840947, -0.000192841, -4.2106e-06),
+    coefs!(0.9761,       0.00616527, -0.000256,   -4.2106e-06),
+    coefs!(1.0,          0.00328947, -0.000319159, -4.2106e-06),
+];
+
+const FXC: f64 = 0.8487;
+const FYC: f64 = 1.3523;
+// Number of 5 degrees intervals per radian, and its inverse
+const C1: f64 = 11.459_155_902_616_464;
+const RC1: f64 = 0.087_266_462_599_716_48;
+const NODES: usize = 18;
+const ONEEPS: f64 = 1.000001;
+const EPS: f64 = 1e-10;
+const MAX_ITER: usize = 100;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn robin(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if phi.is_nan() {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let dphi = phi.abs();
+        let i = ((dphi * C1 + 1e-15).floor() as usize).min(NODES);
+        let dphi = (dphi - RC1 * i as f64).to_degrees();
+        let y = Y[i].v(dphi) * FYC;
+        Ok((X[i].v(dphi) * FXC * lam, y.copysign(phi), z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let lam = x / FXC;
+        let phi = (y / FYC).abs();
+        if phi >= 1. {
+            // At the poles
+            if phi > ONEEPS {
+                return Err(Error::CoordTransOutsideProjectionDomain);
+            }
+            return Ok((lam / X[NODES].c0, FRAC_PI_2.copysign(y), z));
+        }
+        if phi.is_nan() {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+
+        // Reduce to the table interval
+        let mut i = (phi * NODES as f64).floor() as usize;
+        loop {
+            if Y[i].c0 > phi {
+                i -= 1;
+            } else if Y[i + 1].c0 <= phi {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        // First guess from linear interpolation, then Newton-Raphson
+        let c = &Y[i];
+        let mut t = 5. * (phi - c.c0) / (Y[i + 1].c0 - c.c0);
+        let mut iter = MAX_ITER;
+        loop {
+            let t1 = (c.v(t) - phi) / c.dv(t);
+            t -= t1;
+            if t1.abs() < EPS {
+                break;
+            }
+            iter -= 1;
+            if iter == 0 {
+                return Err(Error::CoordTransOutsideProjectionDomain);
+            }
+        }
+
+        let lam = lam / X[i].v(t);
+        if lam.abs() > PI {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        Ok((lam, (5. * i as f64 + t).to_radians().copysign(y), z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_robin() {
+        let p = Proj::from_proj_string("+proj=robin +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (189588.423282508, 107318.530350703, 0.)),
+            ((2., -1., 0.), (189588.423282508, -107318.530350703, 0.)),
+            ((-2., 1., 0.), (-189588.423282508, 107318.530350703, 0.)),
+            ((-2., -1., 0.), (-189588.423282508, -107318.530350703, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_robin_nodes() {
+        let p = Proj::from_proj_string("+proj=robin +R=6400000").unwrap();
+
+        // At the nodes of the table, the coordinates are given by
+        // Robinson's lengths of parallels (0.9216, 0.6213) and distances
+        // to the equator (0.4958, 0.9394), up to single precision
+        let inputs = [
+            ((10., 0., 0.), (948006.9991472559, 0., 0.)),
+            ((10., 40., 0.), (873683.2354062183, 4291010.076541901, 0.)),
+            (
+                (-10., -80., 0.),
+                (-588996.7315732995, -8130244.117393494, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_robin_pole() {
+        let p = Proj::from_proj_string("+proj=robin +R=6400000").unwrap();
+
+        let (x, y, _) = p.projection().forward(1., 90f64.to_radians(), 0.).unwrap();
+        assert!((x - 0.5322 * 0.8487).abs() < 1.0e-7);
+        assert!((y - 1.3523).abs() < 1.0e-7);
+
+        let (lam, phi, _) = p.projection().inverse(x, y, 0.).unwrap();
+        assert!((lam - 1.).abs() < 1.0e-7);
+        assert!((phi - 90f64.to_radians()).abs() < 1.0e-7);
+
+        // Beyond the edge of the map
+        assert!(matches!(
+            p.projection().inverse(0., 1.01 * y, 0.),
+            Err(Error::CoordTransOutsideProjectionDomain)
+        ));
+        assert!(matches!(
+            p.projection().inverse(3., 0., 0.),
+            Err(Error::CoordTransOutsideProjectionDomain)
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! Spherical Cross-track Height
+//!
+//! ref: <https://proj.org/operations/projections/sch.html>
+//!
+//! sch: "Spherical Cross-track Height" "\n\tMisc\n\tplat_0= plon_0= phdg_0= [h_0=]"
+//!
+//! Coordinates along (s) and across (c) a track defined by a peg point
+//! and a heading, on the sphere that best fits the ellipsoid at the peg
+//! point in the direction of the heading. Heights are above that sphere.
+//!
+//! Used by radar and InSAR software (i.e JPL ISCE).
+//!
+use crate::errors::{Error, Result};
+use crate::geocent::{geocentric_to_geodetic, geodetic_to_geocentric};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { sch }
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    a: f64,
+    es: f64,
+    b: f64,
+    rcurv: f64,
+    trans_mat: [f64; 9],
+    xyzoff: [f64; 3],
+}
+
+impl Projection {
+    pub fn sch(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let plat = params
+            .try_angular_value("plat_0")?
+            .ok_or(Error::InvalidParameterValue("Missing parameter plat_0"))?;
+        let plon = params
+            .try_angular_value("plon_0")?
+            .ok_or(Error::InvalidParameterValue("Missing parameter plon_0"))?;
+        let phdg = params
+            .try_angular_value("phdg_0")?
+            .ok_or(Error::InvalidParameterValue("Missing parameter phdg_0"))?;
+        let h0 = params.try_value::<f64>("h_0")?.unwrap_or(0.);
+
+        let (a, es, b) = (p.ellps.a, p.ellps.es, p.ellps.b);
+
+        let (slt, clt) = plat.sin_cos();
+        let (slo, clo) = plon.sin_cos();
+        let (shdg, chdg) = phdg.sin_cos();
+
+        // Radius of curvature at the peg point along the heading
+        let temp = (1. - es * slt * slt).sqrt();
+        let reast = a / temp;
+        let rnorth = a * (1. - es) / (temp * temp * temp);
+        let rcurv = h0 + (reast * rnorth) / (reast * chdg * chdg + rnorth * shdg * shdg);
+
+        let trans_mat = [
+            clt * clo,
+            -shdg * slo - slt * clo * chdg,
+            slo * chdg - slt * clo * shdg,
+            clt * slo,
+            clo * shdg - slt * slo * chdg,
+            -clo * chdg - slt * slo * shdg,
+            slt,
+            clt * chdg,
+            clt * shdg,
+        ];
+
+        let (x, y, z) = geodetic_to_geocentric(plon, plat, h0, a, es)?;
+        let xyzoff = [
+            x - rcurv * clt * clo,
+            y - rcurv * clt * slo,
+            z - rcurv * slt,
+        ];
+
+        Ok(Self {
+            a,
+            es,
+            b,
+            rcurv,
+            trans_mat,
+            xyzoff,
+        })
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let m = &self.trans_mat;
+
+        // Geocentric coordinates relative to the center of the local sphere
+        let (x, y, z) = geodetic_to_geocentric(lam, phi, z, self.a, self.es)?;
+        let (x, y, z) = (x - self.xyzoff[0], y - self.xyzoff[1], z - self.xyzoff[2]);
+
+        // Rotate and convert to local lat/long
+        let (lam, phi, h) = geocentric_to_geodetic(
+            m[0] * x + m[3] * y + m[6] * z,
+            m[1] * x + m[4] * y + m[7] * z,
+            m[2] * x + m[5] * y + m[8] * z,
+            self.rcurv,
+            0.,
+            self.rcurv,
+        )?;
+
+        Ok((lam * self.rcurv / self.a, phi * self.rcurv / self.a, h))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let m = &self.trans_mat;
+
+        let (x, y, z) = geodetic_to_geocentric(
+            x * self.a / self.rcurv,
+            y * self.a / self.rcurv,
+            z,
+            self.rcurv,
+            0.,
+        )?;
+
+        geocentric_to_geodetic(
+            m[0] * x + m[1] * y + m[2] * z + self.xyzoff[0],
+            m[3] * x + m[4] * y + m[5] * z + self.xyzoff[1],
+            m[6] * x + m[7] * y + m[8] * z + self.xyzoff[2],
+            self.a,
+            self.es,
+            self.b,
+        )
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_sch() {
+        let p = Proj::from_proj_string("+proj=sch +ellps=WGS84 +plat_0=30 +plon_0=45 +phdg_0=-12")
+            .unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((45., 30., 0.), (0., 0., 0.)),
+            (
+                (45.1, 30.1, 0.),
+                (8843.16659026228, -11733.950168084508, 0.03317532176221515),
+            ),
+            (
+                (44.9, 30.2, 10.),
+                (23692.494349470584, 4808.357750220733, 10.027173989225698),
+            ),
+            (
+                (46., 29., 100.),
+                (-128275.13143179908, -72350.09874434685, 103.30217078156652),
+            ),
+            (
+                (50., 35., 0.),
+                (458826.92589445936, -563838.1044060878, 84.37668045409684),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_sch_missing_peg() {
+        for projstr in [
+            "+proj=sch +plon_0=45 +phdg_0=-12",
+            "+proj=sch +plat_0=30 +phdg_0=-12",
+            "+proj=sch +plat_0=30 +plon_0=45",
+        ] {
+            assert!(matches!(
+                Proj::from_proj_string(projstr),
+                Err(Error::InvalidParameterValue(_))
+            ));
+        }
+    }
+}
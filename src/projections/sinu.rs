@@ -0,0 +1,260 @@
+//!
+//! General Sinusoidal Series
+//!
+//! ref: <https://proj.org/operations/projections/sinu.html>
+//!
+//! sinu: "Sinusoidal (Sanson-Flamsteed)" "\n\tPCyl, Sph&Ell"
+//! gn_sinu: "General Sinusoidal Series" "\n\tPCyl, Sph\n\tm= n="
+//! eck6: "Eckert VI" "\n\tPCyl, Sph"
+//! mbtfps: "McBryde-Thomas Flat-Polar Sinusoidal" "\n\tPCyl, Sph"
+//!
+//! Inverse projections reject points beyond the edge of the map.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{
+    aasin,
+    consts::{EPS_10, FRAC_PI_2, FRAC_PI_4, PI},
+    enfn, inv_mlfn, mlfn, Enfn,
+};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { sinu, gn_sinu, eck6, mbtfps }
+
+const MAX_ITER: usize = 8;
+const LOOP_TOL: f64 = 1.0e-7;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Projection {
+    Ell { es: f64, en: Enfn },
+    Sph { m: f64, n: f64, c_x: f64, c_y: f64 },
+}
+
+use Projection::*;
+
+impl Projection {
+    fn sphere(p: &mut ProjData, m: f64, n: f64) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        let c_y = ((m + 1.) / n).sqrt();
+        Ok(Sph {
+            m,
+            n,
+            c_x: c_y / (m + 1.),
+            c_y,
+        })
+    }
+
+    pub fn sinu(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        let es = p.ellps.es;
+        if es != 0. {
+            Ok(Ell { es, en: enfn(es) })
+        } else {
+            Self::sphere(p, 0., 1.)
+        }
+    }
+
+    pub fn gn_sinu(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        match (params.try_value("m")?, params.try_value("n")?) {
+            (Some(m), Some(n)) if n > 0. && m >= 0. => Self::sphere(p, m, n),
+            _ => Err(Error::InvalidParameterValue("Invalid or missing m or n")),
+        }
+    }
+
+    pub fn eck6(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::sphere(p, 1., 1. + FRAC_PI_2)
+    }
+
+    pub fn mbtfps(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        Self::sphere(p, 0.5, 1. + FRAC_PI_4)
+    }
+
+    pub fn forward(&self, lam: f64, mut phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        match self {
+            Ell { es, en } => {
+                let (s, c) = phi.sin_cos();
+                Ok((lam * c / (1. - es * s * s).sqrt(), mlfn(phi, s, c, *en), z))
+            }
+            Sph { m, n, c_x, c_y } => {
+                if *m == 0. {
+                    if *n != 1. {
+                        phi = aasin(n * phi.sin())?;
+                    }
+                } else {
+                    let k = n * phi.sin();
+                    let mut i = MAX_ITER;
+                    loop {
+                        let v = (m * phi + phi.sin() - k) / (m + phi.cos());
+                        phi -= v;
+                        if v.abs() < LOOP_TOL {
+                            break;
+                        }
+                        i -= 1;
+                        if i == 0 {
+                            return Err(Error::ToleranceConditionError);
+                        }
+                    }
+                }
+                Ok((c_x * lam * (m + phi.cos()), c_y * phi, z))
+            }
+        }
+    }
+
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (lam, phi) = match self {
+            Ell { es, en } => {
+                let phi = inv_mlfn(y, *es, *en)?;
+                let s = phi.abs();
+                if s < FRAC_PI_2 {
+                    let s = phi.sin();
+                    (x * (1. - es * s * s).sqrt() / phi.cos(), phi)
+                } else if s - EPS_10 < FRAC_PI_2 {
+                    (0., phi)
+                } else {
+                    return Err(Error::ToleranceConditionError);
+                }
+            }
+            Sph { m, n, c_x, c_y } => {
+                let y = y / c_y;
+                let phi = if *m != 0. {
+                    aasin((m * y + y.sin()) / n)?
+                } else if *n != 1. {
+                    aasin(y.sin() / n)?
+                } else {
+                    y
+                };
+                (x / (c_x * (m + y.cos())), phi)
+            }
+        };
+        // Beyond the edge of the map
+        if lam.abs() - EPS_10 > PI {
+            return Err(Error::ToleranceConditionError);
+        }
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::consts::EPS_10;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_sinu_el() {
+        let p = Proj::from_proj_string("+proj=sinu +ellps=GRS80").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (222605.299539466, 110574.388554153, 0.)),
+            ((2., -1., 0.), (222605.299539466, -110574.388554153, 0.)),
+            ((-2., 1., 0.), (-222605.299539466, 110574.388554153, 0.)),
+            ((-2., -1., 0.), (-222605.299539466, -110574.388554153, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_sinu_sp() {
+        let p = Proj::from_proj_string("+proj=sinu +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (223368.119026632, 111701.072127637, 0.)),
+            ((2., -1., 0.), (223368.119026632, -111701.072127637, 0.)),
+            ((-2., 1., 0.), (-223368.119026632, 111701.072127637, 0.)),
+            ((-2., -1., 0.), (-223368.119026632, -111701.072127637, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_sinu_eck6() {
+        let p = Proj::from_proj_string("+proj=eck6 +R=6400000").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (197021.605628992, 126640.420733908, 0.)),
+            ((-2., -1., 0.), (-197021.605628992, -126640.420733908, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_sinu_mbtfps() {
+        let p = Proj::from_proj_string("+proj=mbtfps +R=6400000").unwrap();
+
+        let inputs = [
+            ((2., 1., 0.), (204740.117478572, 121864.729719340, 0.)),
+            ((-2., -1., 0.), (-204740.117478572, -121864.729719340, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_sinu_gn_sinu() {
+        let p = Proj::from_proj_string("+proj=gn_sinu +m=1 +n=2 +R=6400000").unwrap();
+
+        let inputs = [((2., 1., 0.), (223385.132504696, 111698.236447187, 0.))];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+
+        assert!(matches!(
+            Proj::from_proj_string("+proj=gn_sinu +m=1 +R=6400000"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+        assert!(matches!(
+            Proj::from_proj_string("+proj=gn_sinu +m=-1 +n=2 +R=6400000"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+
+    #[test]
+    fn proj_sinu_round_trip() {
+        for projstr in [
+            "+proj=sinu +ellps=WGS84",
+            "+proj=sinu +R=6371000",
+            "+proj=eck6 +R=6371000",
+            "+proj=mbtfps +R=6371000",
+        ] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let (lam, phi) = (f64::to_radians(-123.), f64::to_radians(47.5));
+            let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+            let (lam_i, phi_i, _) = p.projection().inverse(x, y, 0.).unwrap();
+            assert!((lam_i - lam).abs() < 1.0e-10, "{projstr}");
+            assert!((phi_i - phi).abs() < 1.0e-10, "{projstr}");
+
+            // Beyond the edge of the map at the same latitude
+            let (x, y, _) = p
+                .projection()
+                .forward(std::f64::consts::PI, phi, 0.)
+                .unwrap();
+            assert!(p.projection().inverse(x * 0.999, y, 0.).is_ok());
+            assert!(matches!(
+                p.projection().inverse(x * 1.001, y, 0.),
+                Err(Error::ToleranceConditionError)
+            ));
+        }
+    }
+}
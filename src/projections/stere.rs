@@ -27,7 +27,7 @@ use crate::math::{
     tsfn,
 };
 use crate::parameters::ParamList;
-use crate::proj::ProjData;
+use crate::proj::{GridSystem, ProjData};
 
 #[inline]
 fn ssfn(phit: f64, sinphi: f64, eccen: f64) -> f64 {
@@ -78,11 +78,8 @@ impl Projection {
     // -----------
     pub fn ups(p: &mut ProjData, params: &ParamList) -> Result<Self> {
         // International Ellipsoid
-        p.phi0 = if params.check_option("south")? {
-            -FRAC_PI_2
-        } else {
-            FRAC_PI_2
-        };
+        let south = params.check_option("south")?;
+        p.phi0 = if south { -FRAC_PI_2 } else { FRAC_PI_2 };
 
         if p.ellps.is_sphere() {
             Err(Error::EllipsoidRequired)
@@ -91,6 +88,7 @@ impl Projection {
             p.x0 = 2_000_000.;
             p.y0 = 2_000_000.;
             p.lam0 = 0.;
+            p.grid = Some(GridSystem::Ups { south });
             Self::init(p, FRAC_PI_2)
         }
     }
@@ -367,6 +365,9 @@ impl Projection {
                     if (phits - FRAC_PI_2).abs() < EPS_10 {
                         2. * p.k0 / ((1. + ecc).powf(1. + ecc) * (1. - ecc).powf(1. - ecc)).sqrt()
                     } else {
+                        // Polar stereographic variant B: this is
+                        // equivalent to the scale factor at the pole
+                        // k0 = akm1 * sqrt((1+e)^(1+e) * (1-e)^(1-e)) / 2
                         let s = phits.sin();
                         let t = s * ecc;
                         phits.cos() / tsfn(phits, s, ecc) / (1. - t * t).sqrt()
@@ -472,7 +473,7 @@ mod tests {
 
         let inputs = [
             ((2., 1., 0.), (2433455.5634384668, -10412543.301512826, 0.)),
-            ((2., -1., 0.), (2448749.1185681992, -10850493.419804076, 0.)),
+            ((2., -1., 0.), (2448749.118568199, -10850493.419804076, 0.)),
             ((-2., 1., 0.), (1566544.4365615332, -10412543.301512826, 0.)),
             (
                 (-2., -1., 0.),
@@ -504,4 +505,80 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_stere_lat_ts_k0() {
+        // EPSG:3413 and EPSG:3031, the scale factor at the pole derived
+        // from lat_ts (EPSG guidance note 7-2, polar stereographic variant B)
+        for (lat_0, lat_ts, k0) in [
+            (90., 70., 0.9698581903263518),
+            (-90., -71., 0.9727690128917969),
+        ] {
+            let p1 = Proj::from_proj_string(&format!(
+                "+proj=stere +lat_0={lat_0} +lat_ts={lat_ts} +lon_0=-45 +datum=WGS84"
+            ))
+            .unwrap();
+            let p2 = Proj::from_proj_string(&format!(
+                "+proj=stere +lat_0={lat_0} +k={k0} +lon_0=-45 +datum=WGS84"
+            ))
+            .unwrap();
+
+            for (lam, phi) in [(0f64, 1f64), (30., 20.), (-120., 45.), (170., 85.)] {
+                let (lam, phi) = (lam.to_radians(), phi.to_radians().copysign(lat_0));
+                let (x1, y1, _) = p1.projection().forward(lam, phi, 0.).unwrap();
+                let (x2, y2, _) = p2.projection().forward(lam, phi, 0.).unwrap();
+                assert!((x1 - x2).abs() < 1.0e-15 && (y1 - y2).abs() < 1.0e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn proj_stere_antarctic() {
+        // EPSG guidance note 7-2, polar stereographic variant B
+        let p = Proj::from_proj_string(
+            "+proj=stere +lat_0=-90 +lat_ts=-71 +lon_0=70 +x_0=6000000 +y_0=6000000 +datum=WGS84",
+        )
+        .unwrap();
+
+        let inputs = [
+            ((70., -90., 0.), (6000000., 6000000., 0.)),
+            ((120., -75., 0.), (7255380.79, 7053389.56, 0.)),
+        ];
+
+        // Published values are rounded to the centimeter
+        test_proj_forward(&p, &inputs, 1.0e-2);
+        test_proj_inverse(&p, &inputs, 1.0e-7);
+    }
+
+    #[test]
+    fn proj_stere_round_trip() {
+        for (defn, lats) in [
+            (
+                "+lat_0=-90 +lat_ts=-71 +datum=WGS84",
+                [-90., -89.5, -71., -45.],
+            ),
+            (
+                "+lat_0=-90 +lat_ts=-71 +R=6371000",
+                [-90., -89.5, -71., -45.],
+            ),
+            ("+lat_0=90 +lat_ts=90 +ellps=GRS80", [90., 89.5, 60., 10.]),
+            ("+lat_0=45 +ellps=GRS80", [45., 89.5, 60., -10.]),
+            ("+lat_0=45 +R=6371000", [45., 89.5, 60., -10.]),
+            ("+lat_0=0 +ellps=GRS80", [45., 80., 0., -45.]),
+        ] {
+            let p = Proj::from_proj_string(&format!("+proj=stere {defn}")).unwrap();
+            for lat in lats {
+                for lon in [-170f64, -45., 0., 30., 120.] {
+                    let (lam, phi) = (lon.to_radians(), f64::to_radians(lat));
+                    let (x, y, _) = p.projection().forward(lam, phi, 0.).unwrap();
+                    let (lam_i, phi_i, _) = p.projection().inverse(x, y, 0.).unwrap();
+                    assert!((phi_i - phi).abs() < 1.0e-10, "{defn} {lon} {lat}");
+                    // Longitude is undefined at the pole
+                    if lat.abs() != 90. {
+                        assert!((lam_i - lam).abs() < 1.0e-10, "{defn} {lon} {lat}");
+                    }
+                }
+            }
+        }
+    }
 }
@@ -7,15 +7,25 @@
 //! The default algorithm is Poder/Ensager except for the spherical case
 //! where the Evenden/Snyder is used
 //!
+//! The Evenden/Snyder series (the classic Gauss-Krüger formulation of
+//! proj before version 6) is selected with `+approx` or
+//! `+algo=evenden_snyder`: it reproduces legacy numbers but diverges
+//! from the exact algorithm far from the central meridian.
+//!
+//! With both algorithms, points farther from the central meridian than
+//! the optional `max_dlon` parameter (in degrees) are rejected. By default,
+//! as in proj, the Evenden/Snyder algorithm rejects points beyond 90° and
+//! the Poder/Engsager algorithm has no limit.
 //!
 
 use crate::errors::{Error, Result};
+use crate::factors::Factors;
 use crate::parameters::ParamList;
 use crate::proj::ProjData;
 use crate::projections::{estmerc, etmerc};
 
 // Projection stub
-super::projection! { tmerc }
+super::projection! { tmerc; factors }
 
 #[derive(Debug, Clone)]
 pub(crate) enum Projection {
@@ -55,6 +65,14 @@ impl Projection {
         }
     }
 
+    /// Analytic factors, only available for the exact algorithm
+    pub fn factors(&self, lam: f64, phi: f64) -> Option<Result<Factors>> {
+        match self {
+            Exact(p) => Some(p.factors(lam, phi)),
+            Approx(_) => None,
+        }
+    }
+
     pub const fn has_inverse() -> bool {
         true
     }
@@ -114,4 +132,93 @@ mod tests {
         test_proj_forward(&p, &inputs, EPS_10);
         test_proj_inverse(&p, &inputs, EPS_10);
     }
+
+    #[test]
+    fn proj_tmerc_factors() {
+        let exact = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +lon_0=9").unwrap();
+        let approx = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +lon_0=9 +approx").unwrap();
+
+        // Analytic factors of the exact algorithm and numeric
+        // factors of the approximate one agree close to the
+        // central meridian
+        let (lon, lat) = (11f64.to_radians(), 50f64.to_radians());
+        assert!(exact.projection().factors(0., lat).is_some());
+        assert!(approx.projection().factors(0., lat).is_none());
+
+        let f_exact = exact.factors(lon, lat).unwrap();
+        let f_approx = approx.factors(lon, lat).unwrap();
+        assert!((f_exact.parallel_scale - f_approx.parallel_scale).abs() < 1.0e-8);
+        assert!((f_exact.meridian_convergence - f_approx.meridian_convergence).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn proj_tmerc_approx_divergence() {
+        let exact = Proj::from_proj_string("+proj=tmerc +ellps=GRS80").unwrap();
+        let approx =
+            Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +algo=evenden_snyder").unwrap();
+
+        let dist = |lon: f64, lat: f64| {
+            let (lam, phi) = (lon.to_radians(), lat.to_radians());
+            let (x1, y1, _) = exact.projection().forward(lam, phi, 0.).unwrap();
+            let (x2, y2, _) = approx.projection().forward(lam, phi, 0.).unwrap();
+            exact.data().ellps.a * (x1 - x2).hypot(y1 - y2)
+        };
+
+        // Both algorithms agree to the millimeter within a UTM zone
+        assert!(dist(3., 45.) < 1.0e-3);
+        // But the series diverges far from the central meridian
+        assert!(dist(20., 45.) > 0.1);
+        assert!(dist(30., 45.) > 1.);
+        assert!(dist(40., 45.) > 100.);
+    }
+
+    #[test]
+    fn proj_tmerc_max_dlon() {
+        use crate::errors::Error;
+
+        for projstr in [
+            "+proj=tmerc +ellps=GRS80 +lon_0=9 +max_dlon=10",
+            "+proj=tmerc +ellps=GRS80 +lon_0=9 +max_dlon=10 +approx",
+            "+proj=tmerc +R=6400000 +lon_0=9 +max_dlon=10",
+            "+proj=utm +zone=32 +ellps=GRS80 +max_dlon=10",
+        ] {
+            let p = Proj::from_proj_string(projstr).unwrap();
+            let phi = 50f64.to_radians();
+
+            // Within the limit
+            assert!(p.projection().forward(5f64.to_radians(), phi, 0.).is_ok());
+
+            // 15 degrees from the central meridian
+            for dlon in [15f64, -15f64] {
+                assert!(
+                    matches!(
+                        p.projection().forward(dlon.to_radians(), phi, 0.),
+                        Err(Error::CoordinateOutOfRange)
+                    ),
+                    "{projstr}"
+                );
+            }
+        }
+
+        // Default limits
+        let exact = Proj::from_proj_string("+proj=tmerc +ellps=GRS80").unwrap();
+        let approx = Proj::from_proj_string("+proj=tmerc +ellps=GRS80 +approx").unwrap();
+        let (lam, phi) = (100f64.to_radians(), 50f64.to_radians());
+        assert!(exact.projection().forward(lam, phi, 0.).is_ok());
+        assert!(matches!(
+            approx.projection().forward(lam, phi, 0.),
+            Err(Error::LatOrLongExceedLimit)
+        ));
+
+        for projstr in [
+            "+proj=tmerc +ellps=GRS80 +max_dlon=0",
+            "+proj=tmerc +ellps=GRS80 +max_dlon=-5",
+            "+proj=tmerc +ellps=GRS80 +max_dlon=200",
+        ] {
+            assert!(matches!(
+                Proj::from_proj_string(projstr),
+                Err(Error::InvalidParameterValue(_))
+            ));
+        }
+    }
 }
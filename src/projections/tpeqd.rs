@@ -0,0 +1,307 @@
+//!
+//! Two Point Equidistant
+//!
+//! ref: <https://proj.org/operations/projections/tpeqd.html>
+//!
+//! tpeqd: "Two Point Equidistant" "\n\tMisc Sph\n\tlat_1= lon_1= lat_2= lon_2="
+//!
+//! Distances from any point to the two control points are true to scale.
+//!
+//! As in proj, distances are computed on the sphere by default. With the
+//! `+geodesic` option, ellipsoidal geodesic distances are used instead
+//! and the inverse is solved iteratively.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::{aacos, aasin, adjlon, asqrt, consts::FRAC_PI_2, geodesic_distance, inverse_2d};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { tpeqd }
+
+/// Tolerance of the geodesic inverse, on the unit ellipsoid
+const GEOD_TOL: f64 = 1.0e-12;
+
+/// Control points and base line on the ellipsoid
+#[derive(Debug, Clone)]
+struct Geodesic {
+    f: f64,
+    lam1: f64,
+    phi1: f64,
+    lam2: f64,
+    phi2: f64,
+    z0: f64,
+    hz0: f64,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    cp1: f64,
+    sp1: f64,
+    cp2: f64,
+    sp2: f64,
+    ccs: f64,
+    cs: f64,
+    sc: f64,
+    r2z0: f64,
+    z02: f64,
+    dlam2: f64,
+    hz0: f64,
+    thz0: f64,
+    rhshz0: f64,
+    ca: f64,
+    sa: f64,
+    lp: f64,
+    lamc: f64,
+    geod: Option<Geodesic>,
+}
+
+impl Projection {
+    pub fn tpeqd(p: &mut ProjData, params: &ParamList) -> Result<Self> {
+        let phi_1 = params.try_angular_value("lat_1")?.unwrap_or(0.);
+        let lam_1 = params.try_angular_value("lon_1")?.unwrap_or(0.);
+        let phi_2 = params.try_angular_value("lat_2")?.unwrap_or(0.);
+        let lam_2 = params.try_angular_value("lon_2")?.unwrap_or(0.);
+
+        if phi_1 == phi_2 && lam_1 == lam_2 {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_1/lon_1/lat_2/lon_2: the 2 points should be distinct.",
+            ));
+        }
+
+        p.lam0 = adjlon(0.5 * (lam_1 + lam_2));
+        let dlam2 = adjlon(lam_2 - lam_1);
+
+        let (sp1, cp1) = phi_1.sin_cos();
+        let (sp2, cp2) = phi_2.sin_cos();
+
+        let z02 = aacos(sp1 * sp2 + cp1 * cp2 * dlam2.cos())?;
+        if z02 == 0. {
+            return Err(Error::InvalidParameterValue(
+                "Invalid value for lat_1/lon_1/lat_2/lon_2: the 2 points should not be antipodal.",
+            ));
+        }
+        let hz0 = 0.5 * z02;
+        let a12 = (cp2 * dlam2.sin()).atan2(cp1 * sp2 - sp1 * cp2 * dlam2.cos());
+        let pp = aasin(cp1 * a12.sin())?;
+
+        let geod = if params.check_option("geodesic")? && p.ellps.is_ellipsoid() {
+            let f = p.ellps.f;
+            let (lam1, lam2) = (-0.5 * dlam2, 0.5 * dlam2);
+            let z0 = geodesic_distance(f, lam1, phi_1, lam2, phi_2)?;
+            Some(Geodesic {
+                f,
+                lam1,
+                phi1: phi_1,
+                lam2,
+                phi2: phi_2,
+                z0,
+                hz0: 0.5 * z0,
+            })
+        } else {
+            // Map from sphere
+            p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+            None
+        };
+
+        Ok(Self {
+            cp1,
+            sp1,
+            cp2,
+            sp2,
+            ccs: cp1 * cp2 * dlam2.sin(),
+            cs: cp1 * sp2,
+            sc: sp1 * cp2,
+            r2z0: 0.5 / z02,
+            z02: z02 * z02,
+            dlam2: 0.5 * dlam2,
+            hz0,
+            thz0: hz0.tan(),
+            rhshz0: 0.5 / hz0.sin(),
+            ca: pp.cos(),
+            sa: pp.sin(),
+            lp: adjlon((cp1 * a12.cos()).atan2(sp1) - hz0),
+            lamc: FRAC_PI_2 - (a12.sin() * sp1).atan2(a12.cos()) - 0.5 * dlam2,
+            geod,
+        })
+    }
+
+    /// Return true if the point is on the left of the base line
+    #[inline]
+    fn is_left(&self, lam: f64, phi: f64) -> bool {
+        let (sp, cp) = phi.sin_cos();
+        let dl1 = lam + self.dlam2;
+        let dl2 = lam - self.dlam2;
+        self.ccs * sp - cp * (self.cs * dl1.sin() - self.sc * dl2.sin()) >= 0.
+    }
+
+    fn forward_geodesic(&self, g: &Geodesic, lam: f64, phi: f64) -> Result<(f64, f64)> {
+        let z1 = geodesic_distance(g.f, g.lam1, g.phi1, lam, phi)?;
+        let z2 = geodesic_distance(g.f, g.lam2, g.phi2, lam, phi)?;
+        let x = (z1 * z1 - z2 * z2) / (2. * g.z0);
+        let y = asqrt(z1 * z1 - (x + g.hz0) * (x + g.hz0));
+        Ok((x, if self.is_left(lam, phi) { y } else { -y }))
+    }
+
+    fn inverse_sphere(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        let cz1 = y.hypot(x + self.hz0).cos();
+        let cz2 = y.hypot(x - self.hz0).cos();
+        let s = cz1 + cz2;
+        let d = cz1 - cz2;
+        let lam = -d.atan2(s * self.thz0);
+        let phi = aacos((self.thz0 * s).hypot(d) * self.rhshz0)?;
+        let phi = if y < 0. { -phi } else { phi };
+
+        // lam, phi now in system relative to P1--P2 base equator
+        let (sp, cp) = phi.sin_cos();
+        let lam = lam - self.lp;
+        let s = lam.cos();
+        Ok((
+            (cp * lam.sin()).atan2(self.sa * cp * s - self.ca * sp) + self.lamc,
+            aasin(self.sa * sp + self.ca * cp * s)?,
+        ))
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        if let Some(g) = &self.geod {
+            let (x, y) = self.forward_geodesic(g, lam, phi)?;
+            return Ok((x, y, z));
+        }
+
+        let (sp, cp) = phi.sin_cos();
+        let dl1 = lam + self.dlam2;
+        let dl2 = lam - self.dlam2;
+        let z1 = aacos(self.sp1 * sp + self.cp1 * cp * dl1.cos())?;
+        let z2 = aacos(self.sp2 * sp + self.cp2 * cp * dl2.cos())?;
+        let (z1, z2) = (z1 * z1, z2 * z2);
+        let t = z1 - z2;
+        let x = self.r2z0 * t;
+        let t = self.z02 - t;
+        let y = self.r2z0 * asqrt(4. * self.z02 * z2 - t * t);
+        Ok((x, if self.is_left(lam, phi) { y } else { -y }, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (lam, phi) = match &self.geod {
+            Some(g) => {
+                // Start from the spherical solution
+                let guess = self.inverse_sphere(x, y)?;
+                inverse_2d(
+                    |lam, phi| self.forward_geodesic(g, lam, phi),
+                    x,
+                    y,
+                    guess,
+                    GEOD_TOL,
+                )?
+            }
+            None => self.inverse_sphere(x, y)?,
+        };
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::math::{consts::EPS_10, geodesic_distance};
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_tpeqd() {
+        let p = Proj::from_proj_string("+proj=tpeqd +R=6400000 +lat_1=0.5 +lat_2=2").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (-27845.882978479094, -223362.43069526734, 0.)),
+            ((2., -1., 0.), (-251293.378764671, -223419.15898589182, 0.)),
+            ((-2., 1., 0.), (-27845.882978479094, 223362.43069526734, 0.)),
+            ((-2., -1., 0.), (-251293.378764671, 223419.15898589182, 0.)),
+            ((30., 45., 0.), (5087603.109061889, -2593353.534850136, 0.)),
+            (
+                (-60., -30., 0.),
+                (-4081415.6158636813, 6009894.6421381505, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, EPS_10);
+    }
+
+    #[test]
+    fn proj_tpeqd_invalid() {
+        assert!(matches!(
+            Proj::from_proj_string("+proj=tpeqd +lat_1=10 +lat_2=10"),
+            Err(Error::InvalidParameterValue(_))
+        ));
+    }
+
+    #[test]
+    fn proj_tpeqd_geodesic() {
+        // Control points far apart
+        const DEFN: &str = "+proj=tpeqd +ellps=WGS84 +lat_1=0 +lon_1=0 +lat_2=45 +lon_2=90";
+        const CONTROLS: [(f64, f64); 2] = [(0., 0.), (90., 45.)];
+        const POINTS: [(f64, f64); 3] = [(30., 60.), (-40., -20.), (120., 10.)];
+
+        // Return the errors on distances to control points
+        let distance_errors = |p: &Proj| {
+            let ellps = &p.data().ellps;
+            let lam0 = p.data().lam0;
+            let fwd = |(lon, lat): (f64, f64)| {
+                p.projection()
+                    .forward(lon.to_radians() - lam0, lat.to_radians(), 0.)
+                    .map(|(x, y, _)| (x, y))
+                    .unwrap()
+            };
+            let mut errors = vec![];
+            for pt in POINTS {
+                let (x, y) = fwd(pt);
+                for ctl in CONTROLS {
+                    let (xc, yc) = fwd(ctl);
+                    let f = 1. / 298.257223563;
+                    let geod = 6378137.
+                        * geodesic_distance(
+                            f,
+                            ctl.0.to_radians(),
+                            ctl.1.to_radians(),
+                            pt.0.to_radians(),
+                            pt.1.to_radians(),
+                        )
+                        .unwrap();
+                    errors.push((ellps.a * (x - xc).hypot(y - yc) - geod).abs());
+                }
+            }
+            errors
+        };
+
+        let sphere = Proj::from_proj_string(DEFN).unwrap();
+        assert!(distance_errors(&sphere).iter().any(|err| *err > 1000.));
+
+        let ellps = Proj::from_proj_string(&format!("{DEFN} +geodesic")).unwrap();
+        for err in distance_errors(&ellps) {
+            assert!(err < 1.0e-6, "distance error {err}");
+        }
+
+        // Round trip
+        let inputs = POINTS.map(|(lon, lat)| {
+            let (x, y, _) = ellps
+                .projection()
+                .forward((lon - 45f64).to_radians(), lat.to_radians(), 0.)
+                .unwrap();
+            ((lon, lat, 0.), (x * 6378137., y * 6378137., 0.))
+        });
+        test_proj_inverse(&ellps, &inputs, 1.0e-9);
+    }
+}
@@ -0,0 +1,197 @@
+//!
+//! van der Grinten (I)
+//!
+//! ref: <https://proj.org/operations/projections/vandg.html>
+//!
+//! vandg: "van der Grinten (I)" "\n\tMisc Sph"
+//!
+//! The whole world is mapped inside a circle of radius π.
+//! The inverse is computed from the closed form solution of
+//! a cubic equation.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{FRAC_PI_2, PI, TAU};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { vandg }
+
+const TOL: f64 = 1.0e-10;
+const THIRD: f64 = 1. / 3.;
+const C2_27: f64 = 2. / 27.;
+const PI4_3: f64 = 4. * PI / 3.;
+const PISQ: f64 = PI * PI;
+const TPISQ: f64 = 2. * PISQ;
+const HPISQ: f64 = 0.5 * PISQ;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn vandg(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let p2 = (phi / FRAC_PI_2).abs();
+        if p2 - TOL > 1. {
+            return Err(Error::ToleranceConditionError);
+        }
+        let p2 = p2.min(1.);
+
+        // On the equator
+        if phi.abs() <= TOL {
+            return Ok((lam, 0., z));
+        }
+        // On the central meridian, or at the poles
+        if lam.abs() <= TOL || (p2 - 1.).abs() < TOL {
+            return Ok((0., (PI * (0.5 * p2.asin()).tan()).copysign(phi), z));
+        }
+
+        let al = 0.5 * (PI / lam - lam / PI).abs();
+        let al2 = al * al;
+        let g = (1. - p2 * p2).sqrt();
+        let g = g / (p2 + g - 1.);
+        let g2 = g * g;
+        let p2 = g * (2. / p2 - 1.);
+        let p2 = p2 * p2;
+        let x = g - p2;
+        let g = p2 + al2;
+        let x = PI * (al * x + (al2 * x * x - g * (g2 - p2)).sqrt()) / g;
+
+        let y = (x / PI).abs();
+        let y = 1. - y * (y + 2. * al);
+        if y < -TOL {
+            return Err(Error::ToleranceConditionError);
+        }
+        let y = if y < 0. { 0. } else { PI * y.sqrt() };
+
+        Ok((x.copysign(lam), y.copysign(phi), z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let x2 = x * x;
+        let ay = y.abs();
+
+        // On the equator
+        if ay < TOL {
+            let t = x2 * x2 + TPISQ * (x2 + HPISQ);
+            let lam = if x.abs() <= TOL {
+                0.
+            } else {
+                0.5 * (x2 - PISQ + t.sqrt()) / x
+            };
+            return Ok((lam, 0., z));
+        }
+
+        let y2 = y * y;
+        let r = x2 + y2;
+        // Outside of the circle bounding the map
+        if r > PISQ {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let r2 = r * r;
+
+        let c1 = -PI * ay * (r + PISQ);
+        let c3 = r2 + TAU * (ay * r + PI * (y2 + PI * (ay + FRAC_PI_2)));
+        let c2 = (c1 + PISQ * (r - 3. * y2)) / c3;
+        let c0 = PI * ay;
+        let al = c1 / c3 - THIRD * c2 * c2;
+        let m = 2. * (-THIRD * al).sqrt();
+        let al_m = al * m;
+        if al_m.abs() < 1.0e-16 {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let d = 3. * (C2_27 * c2 * c2 * c2 + (c0 * c0 - THIRD * c2 * c1) / c3) / al_m;
+
+        let t = d.abs();
+        if t - TOL > 1. {
+            return Err(Error::ToleranceConditionError);
+        }
+        let d = if t > 1. {
+            if d > 0. {
+                0.
+            } else {
+                PI
+            }
+        } else {
+            d.acos()
+        };
+
+        let phi = PI * (m * (d * THIRD + PI4_3).cos() - THIRD * c2);
+        let t = r2 + TPISQ * (x2 - y2 + HPISQ);
+        let lam = if x.abs() <= TOL {
+            0.
+        } else {
+            0.5 * (r - PISQ + t.max(0.).sqrt()) / x
+        };
+
+        Ok((lam, phi.copysign(y), z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_vandg() {
+        let p = Proj::from_proj_string("+proj=vandg +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // Same results as proj gie tests: f64 cancellation near
+        // the equator makes them differ by ~0.1 mm from the exact values
+        let inputs = [
+            ((2., 1., 0.), (223395.249543407, 111704.596633675, 0.)),
+            ((2., -1., 0.), (223395.249543407, -111704.596633675, 0.)),
+            ((-2., 1., 0.), (-223395.249543407, 111704.596633675, 0.)),
+            ((-2., -1., 0.), (-223395.249543407, -111704.596633675, 0.)),
+            ((30., 45., 0.), (3113408.538900587, 5422735.30459109, 0.)),
+            (
+                (-120., -60., 0.),
+                (-11582853.255898634, -8719170.698741544, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_vandg_special_cases() {
+        let p = Proj::from_proj_string("+proj=vandg +R=6400000").unwrap();
+
+        // Equator, central meridian and pole
+        let inputs = [
+            ((30., 0., 0.), (3351032.163829113, 0., 0.)),
+            ((0., 30., 0.), (0., 3449677.340496942, 0.)),
+            ((0., -90., 0.), (0., -6400000. * std::f64::consts::PI, 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-9);
+
+        // Outside of the map
+        assert!(matches!(
+            p.projection().inverse(3., 1., 0.),
+            Err(Error::CoordTransOutsideProjectionDomain)
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+//!
+//! van der Grinten II and III
+//!
+//! ref: <https://proj.org/operations/projections/vandg2.html>
+//!
+//! vandg2: "van der Grinten II" "\n\tMisc Sph"
+//! vandg3: "van der Grinten III" "\n\tMisc Sph"
+//!
+//! There is no closed-form inverse: it is computed by Newton-Raphson
+//! iterations on the forward projection.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::Result;
+use crate::math::{consts::PI, inverse_2d};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { vandg2, vandg3 }
+
+const TOL: f64 = 1.0e-10;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {
+    vdg3: bool,
+}
+
+impl Projection {
+    pub fn vandg2(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self { vdg3: false })
+    }
+
+    pub fn vandg3(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self { vdg3: true })
+    }
+
+    fn fwd(&self, lam: f64, phi: f64) -> (f64, f64) {
+        let bt = (phi / (PI / 2.)).abs();
+        let ct = (1. - bt * bt).max(0.).sqrt();
+
+        if lam.abs() < TOL {
+            return (0., (PI * bt / (1. + ct)).copysign(phi));
+        }
+
+        let at = 0.5 * (PI / lam - lam / PI).abs();
+        let (x, y) = if self.vdg3 {
+            let x1 = bt / (1. + ct);
+            (PI * ((at * at + 1. - x1 * x1).sqrt() - at), PI * x1)
+        } else {
+            let x1 = (ct * (1. + at * at).sqrt() - at * ct * ct) / (1. + at * at * bt * bt);
+            (PI * x1, PI * (1. - x1 * (x1 + 2. * at) + TOL).sqrt())
+        };
+
+        (x.copysign(lam), y.copysign(phi))
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (x, y) = self.fwd(lam, phi);
+        Ok((x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        // The poles map to y = ±π, start halfway
+        let (lam, phi) = inverse_2d(|lam, phi| Ok(self.fwd(lam, phi)), x, y, (x, 0.5 * y), TOL)?;
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_vandg2() {
+        let p = Proj::from_proj_string("+proj=vandg2 +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // proj gie reference values
+        let inputs = [
+            ((2., 1., 0.), (223395.24785043663, 111718.49103722633, 0.)),
+            ((2., -1., 0.), (223395.24785043663, -111718.49103722633, 0.)),
+            ((-2., 1., 0.), (-223395.24785043663, 111718.49103722633, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223395.24785043663, -111718.49103722633, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+
+    #[test]
+    fn proj_vandg3() {
+        let p = Proj::from_proj_string("+proj=vandg3 +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // proj gie reference values
+        let inputs = [
+            ((2., 1., 0.), (223395.24955283134, 111704.51990442065, 0.)),
+            ((2., -1., 0.), (223395.24955283134, -111704.51990442065, 0.)),
+            ((-2., 1., 0.), (-223395.24955283134, 111704.51990442065, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223395.24955283134, -111704.51990442065, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+}
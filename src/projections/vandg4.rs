@@ -0,0 +1,109 @@
+//!
+//! van der Grinten IV
+//!
+//! ref: <https://proj.org/operations/projections/vandg4.html>
+//!
+//! vandg4: "van der Grinten IV" "\n\tMisc Sph"
+//!
+//! There is no closed-form inverse: it is computed by Newton-Raphson
+//! iterations on the forward projection.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::Result;
+use crate::math::{consts::FRAC_PI_2, inverse_2d};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { vandg4 }
+
+const TOL: f64 = 1.0e-10;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn vandg4(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    fn fwd(lam: f64, phi: f64) -> (f64, f64) {
+        if phi.abs() < TOL {
+            return (lam, 0.);
+        }
+        if lam.abs() < TOL || (phi.abs() - FRAC_PI_2).abs() < TOL {
+            return (0., phi);
+        }
+
+        let bt = (phi / FRAC_PI_2).abs();
+        let bt2 = bt * bt;
+        let ct = 0.5 * (bt * (8. - bt * (2. + bt2)) - 5.) / (bt2 * (bt - 1.));
+        let ct2 = ct * ct;
+
+        let dt = lam / FRAC_PI_2;
+        let dt = dt + 1. / dt;
+        let dt = (dt * dt - 4.).sqrt();
+        let dt = if lam.abs() < FRAC_PI_2 { -dt } else { dt };
+        let dt2 = dt * dt;
+
+        let x1 = (bt + ct) * (bt + ct);
+        let t = bt + 3. * ct;
+        let ft = x1 * (bt2 + ct2 * dt2 - 1.)
+            + (1. - bt2) * (bt2 * (t * t + 4. * ct2) + ct2 * (12. * bt * ct + 4. * ct2));
+        let x1 = (dt * (x1 + ct2 - 1.) + 2. * ft.sqrt()) / (4. * x1 + dt2);
+
+        let x = FRAC_PI_2 * x1;
+        let y = FRAC_PI_2 * (1. + dt * x1.abs() - x1 * x1).sqrt();
+
+        (x.copysign(lam), y.copysign(phi))
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (x, y) = Self::fwd(lam, phi);
+        Ok((x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (lam, phi) = inverse_2d(|lam, phi| Ok(Self::fwd(lam, phi)), x, y, (x, y), TOL)?;
+        Ok((lam, phi, z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_vandg4() {
+        let p = Proj::from_proj_string("+proj=vandg4 +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        // proj gie reference values
+        let inputs = [
+            ((2., 1., 0.), (223374.57729435508, 111701.19548415358, 0.)),
+            ((2., -1., 0.), (223374.57729435508, -111701.19548415358, 0.)),
+            ((-2., 1., 0.), (-223374.57729435508, 111701.19548415358, 0.)),
+            (
+                (-2., -1., 0.),
+                (-223374.57729435508, -111701.19548415358, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-8);
+        test_proj_inverse(&p, &inputs, 1.0e-8);
+    }
+}
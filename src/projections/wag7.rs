@@ -0,0 +1,129 @@
+//!
+//! Wagner VII
+//!
+//! ref: <https://proj.org/operations/projections/wag7.html>
+//!
+//! wag7: "Wagner VII" "\n\tMisc Sph"
+//!
+//! Wagner VII is an equal area modification of the Hammer projection:
+//! the forward projection is closed form. Proj does not provide the
+//! inverse, which is derived here from the inverse of the Hammer projection.
+//!
+use crate::ellps::Ellipsoid;
+use crate::errors::{Error, Result};
+use crate::math::consts::{EPS_10, PI};
+use crate::parameters::ParamList;
+use crate::proj::ProjData;
+
+// Projection stub
+super::projection! { wag7 }
+
+// sin(65°): the parallels at ±65° are mapped to the poles
+// of the Hammer projection
+const C_THETA: f64 = 0.9063077870366499;
+const C_X: f64 = 2.66723;
+const C_Y: f64 = 1.24104;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Projection {}
+
+impl Projection {
+    pub fn wag7(p: &mut ProjData, _: &ParamList) -> Result<Self> {
+        // Map from sphere
+        p.ellps = Ellipsoid::sphere(p.ellps.a)?;
+        Ok(Self {})
+    }
+
+    #[inline(always)]
+    pub fn forward(&self, lam: f64, phi: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let sin_theta = C_THETA * phi.sin();
+        let cos_theta = (1. - sin_theta * sin_theta).sqrt();
+        let (sinl, cosl) = (lam / 3.).sin_cos();
+        let d = 1. / (0.5 * (1. + cos_theta * cosl)).sqrt();
+        Ok((C_X * cos_theta * sinl * d, C_Y * sin_theta * d, z))
+    }
+
+    #[inline(always)]
+    pub fn inverse(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        let (x, y) = (x / C_X, y / C_Y);
+        let zz = 1. - 0.25 * (x * x + y * y);
+        if zz < 0.5 - EPS_10 {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let zz = zz.max(0.5).sqrt();
+
+        let sin_phi = zz * y / C_THETA;
+        if sin_phi.abs() > 1. + EPS_10 {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        let lam = 3. * (zz * x).atan2(2. * zz * zz - 1.);
+        if lam.abs() > PI + EPS_10 {
+            return Err(Error::CoordTransOutsideProjectionDomain);
+        }
+        Ok((lam, sin_phi.clamp(-1., 1.).asin(), z))
+    }
+
+    pub const fn has_inverse() -> bool {
+        true
+    }
+
+    pub const fn has_forward() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::Error;
+    use crate::proj::Proj;
+    use crate::tests::utils::{test_proj_forward, test_proj_inverse};
+
+    #[test]
+    fn proj_wag7() {
+        let p = Proj::from_proj_string("+proj=wag7 +R=6400000").unwrap();
+
+        println!("{:#?}", p.projection());
+
+        let inputs = [
+            ((2., 1., 0.), (198601.87695731167, 125637.0457141714, 0.)),
+            ((2., -1., 0.), (198601.87695731167, -125637.0457141714, 0.)),
+            ((-2., 1., 0.), (-198601.87695731167, 125637.0457141714, 0.)),
+            (
+                (-2., -1., 0.),
+                (-198601.87695731167, -125637.0457141714, 0.),
+            ),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+    }
+
+    #[test]
+    fn proj_wag7_round_trip() {
+        let p = Proj::from_proj_string("+proj=wag7 +R=6400000").unwrap();
+
+        let inputs = [
+            ((30., 45., 0.), (2428471.354724495, 5432245.876873992, 0.)),
+            (
+                (-110., -50., 0.),
+                (-8261280.4516132, -6209455.541479483, 0.),
+            ),
+            ((180., 80., 0.), (8516870.067245347, 9056364.456667095, 0.)),
+            ((-180., 0., 0.), (-17070272., 0., 0.)),
+        ];
+
+        test_proj_forward(&p, &inputs, 1.0e-6);
+        test_proj_inverse(&p, &inputs, 1.0e-10);
+
+        // The poles are mapped to lines
+        let (x, y, _) = p.projection().forward(1., 90f64.to_radians(), 0.).unwrap();
+        let (_, phi, _) = p.projection().inverse(x, y, 0.).unwrap();
+        assert!((phi - 90f64.to_radians()).abs() < 1.0e-7);
+
+        // Outside of the map
+        assert!(matches!(
+            p.projection().inverse(3., 0., 0.),
+            Err(Error::CoordTransOutsideProjectionDomain)
+        ));
+    }
+}
@@ -174,6 +174,19 @@ mod tests {
         assert_eq!(r, ("no_defs", None, ""));
     }
 
+    #[test]
+    fn projstring_quoted_value() {
+        let params = parse(r#"+proj=latlong +nadgrids="path with spaces.gsb" +no_defs"#).unwrap();
+        assert_eq!(
+            params.get("nadgrids").and_then(|p| p.value),
+            Some("path with spaces.gsb")
+        );
+        assert!(params.check_option("no_defs").unwrap());
+
+        // Unterminated quote
+        assert!(parse(r#"+proj=latlong +nadgrids="path with spaces.gsb +no_defs"#).is_err());
+    }
+
     #[test]
     fn projstring_collect_tokens() {
         // Check valid projstring
@@ -1,7 +1,6 @@
 //!
 //! Unit tests
 //!
-use env_logger;
 use std::sync::Once;
 
 static INIT: Once = Once::new();
@@ -17,6 +16,8 @@ pub(crate) mod utils {
     use crate::proj::{Proj, ProjData};
     use approx::assert_abs_diff_eq;
 
+    pub(crate) type TestInput = ((f64, f64, f64), (f64, f64, f64));
+
     pub(crate) fn scale(d: &ProjData, xyz: (f64, f64, f64)) -> (f64, f64, f64) {
         (xyz.0 * d.ellps.a + d.x0, xyz.1 * d.ellps.a + d.y0, xyz.2)
     }
@@ -37,11 +38,7 @@ pub(crate) mod utils {
         (lpz.0.to_radians(), lpz.1.to_radians(), lpz.2)
     }
 
-    pub(crate) fn test_proj_forward(
-        p: &Proj,
-        inputs: &[((f64, f64, f64), (f64, f64, f64))],
-        prec: f64,
-    ) {
+    pub(crate) fn test_proj_forward(p: &Proj, inputs: &[TestInput], prec: f64) {
         let d = p.data();
         inputs.iter().for_each(|(input, expect)| {
             let (lam, phi, z) = to_rad(*input);
@@ -53,11 +50,7 @@ pub(crate) mod utils {
         })
     }
 
-    pub(crate) fn test_proj_inverse(
-        p: &Proj,
-        inputs: &[((f64, f64, f64), (f64, f64, f64))],
-        prec: f64,
-    ) {
+    pub(crate) fn test_proj_inverse(p: &Proj, inputs: &[TestInput], prec: f64) {
         let d = p.data();
         inputs.iter().for_each(|(expect, input)| {
             let (x, y, z) = descale(d, *input);
@@ -70,11 +63,16 @@ pub(crate) mod utils {
     }
 }
 
+use crate::errors::Error;
 use crate::proj::Proj;
-use crate::transform::transform;
+use crate::transform::{
+    accuracy_estimate, requires_height, transform, transform_2d, transform_array,
+    transform_lenient, transform_via_ecef, OnError, TransformCache,
+};
 use approx::assert_abs_diff_eq;
 
 #[test]
+#[allow(clippy::excessive_precision)]
 fn test_transform_array() {
     let mut data: Vec<(f64, f64, f64)> = (1..=1_000)
         .map(|_| (2.0f64.to_radians(), 1.0f64.to_radians(), 0.0f64))
@@ -108,3 +106,401 @@ fn test_utm33_grs80() {
     assert_abs_diff_eq!(v1[0].0, 391027.67777461524, epsilon = 1.0e-10);
     assert_abs_diff_eq!(v1[0].1, 5820089.724404063, epsilon = 1.0e-10);
 }
+
+#[test]
+fn test_transform_2d_height() {
+    // EPSG:3006
+    let from = Proj::from_proj_string(concat!(
+        "+proj=utm +zone=33 +ellps=GRS80 ",
+        "+towgs84=0,0,0,0,0,0,0 +units=m +no_defs"
+    ))
+    .unwrap();
+    // EPSG:3021
+    let to = Proj::from_proj_string(concat!(
+        "+proj=tmerc +lon_0=15.808277777799999 +lat_0=0.0 +k=1.0 ",
+        "+x_0=1500000.0 +y_0=0.0 +ellps=bessel ",
+        "+units=m +towgs84=414.1,41.3,603.1,-0.855,2.141,-7.023,0 ",
+        "+no_defs"
+    ))
+    .unwrap();
+
+    assert!(requires_height(&from, &to));
+
+    // Height is assumed to be zero
+    let mut pt_2d = (319180., 6399862.);
+    let mut pt_3d = (319180., 6399862., 0.);
+    transform_2d(&from, &to, &mut pt_2d, false).unwrap();
+    transform(&from, &to, &mut pt_3d).unwrap();
+    assert_eq!(pt_2d, (pt_3d.0, pt_3d.1));
+    assert_abs_diff_eq!(pt_2d.0, 1271137.92755580, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt_2d.1, 6404230.29136189, epsilon = 1.0e-6);
+
+    // Strict mode
+    let mut pt_2d = (319180., 6399862.);
+    assert!(matches!(
+        transform_2d(&from, &to, &mut pt_2d, true),
+        Err(Error::HeightRequired)
+    ));
+
+    // No datum shift: height is not required
+    let to = Proj::from_proj_string("+proj=latlong +ellps=GRS80").unwrap();
+    assert!(!requires_height(&from, &to));
+    assert!(transform_2d(&from, &to, &mut pt_2d, true).is_ok());
+}
+
+#[test]
+fn test_tmerc_southern_hemisphere() {
+    let from = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+
+    // EPSG:2046 - Hartebeesthoek94 / Lo15: south oriented
+    let lo15 = Proj::from_proj_string(concat!(
+        "+proj=tmerc +lat_0=0 +lon_0=15 +k=1 +x_0=0 +y_0=0 ",
+        "+axis=wsu +ellps=WGS84 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs"
+    ))
+    .unwrap();
+
+    // Transverse mercator with southern false northing
+    let tm_south = Proj::from_proj_string(concat!(
+        "+proj=tmerc +lat_0=0 +lon_0=27 +k=0.9996 +x_0=500000 +y_0=10000000 ",
+        "+ellps=WGS84 +units=m +no_defs"
+    ))
+    .unwrap();
+
+    let inputs = [
+        (
+            &lo15,
+            (16.5, -29.5),
+            (-145458.04970964586, 3265627.0071401447),
+        ),
+        (
+            &lo15,
+            (14.25, -33.9),
+            (69370.43183448535, 3752822.534648674),
+        ),
+        (
+            &tm_south,
+            (28.5, -29.5),
+            (645399.866489762, 6735679.243662711),
+        ),
+        (
+            &tm_south,
+            (24.75, -25.75),
+            (274314.4184957142, 7150075.777714511),
+        ),
+    ];
+
+    for (to, (lon, lat), (x, y)) in inputs {
+        let (lon, lat): (f64, f64) = (lon, lat);
+        let mut pt = (lon.to_radians(), lat.to_radians(), 0.);
+        transform(&from, to, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0, x, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(pt.1, y, epsilon = 1.0e-6);
+
+        // Large offsets do not degrade the round trip
+        transform(to, &from, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0.to_degrees(), lon, epsilon = 1.0e-12);
+        assert_abs_diff_eq!(pt.1.to_degrees(), lat, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_axis_order() {
+    let enu = Proj::from_proj_string("+proj=latlong +ellps=WGS84").unwrap();
+    let neu = Proj::from_proj_string("+proj=latlong +ellps=WGS84 +axis=neu").unwrap();
+    let wsd = Proj::from_proj_string("+proj=latlong +ellps=WGS84 +axis=wsd").unwrap();
+
+    let mut pt = (0.1, 0.2, 3.);
+    transform(&enu, &neu, &mut pt).unwrap();
+    assert_eq!(pt, (0.2, 0.1, 3.));
+    transform(&neu, &wsd, &mut pt).unwrap();
+    assert_eq!(pt, (-0.1, -0.2, -3.));
+    transform(&wsd, &enu, &mut pt).unwrap();
+    assert_eq!(pt, (0.1, 0.2, 3.));
+}
+
+#[test]
+fn test_transform_via_ecef() {
+    let from = Proj::from_proj_string("+proj=latlong +ellps=bessel +towgs84=100,200,300").unwrap();
+    let to = Proj::from_proj_string("+proj=geocent +datum=WGS84").unwrap();
+
+    // On the equator at Greenwich, the pivot is the semi-major
+    // axis of Bessel shifted by the towgs84 translation
+    let (ecef, pt) = transform_via_ecef(&from, &to, (0., 0., 0.)).unwrap();
+    assert_abs_diff_eq!(ecef.0, 6377397.155 + 100., epsilon = 1.0e-6);
+    assert_abs_diff_eq!(ecef.1, 200., epsilon = 1.0e-6);
+    assert_abs_diff_eq!(ecef.2, 300., epsilon = 1.0e-6);
+
+    // Transforming to WGS84 geocentric gives back the pivot
+    assert_abs_diff_eq!(pt.0, ecef.0, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.1, ecef.1, epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.2, ecef.2, epsilon = 1.0e-6);
+
+    // Projected source
+    let from = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    let to = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let (ecef, pt) = transform_via_ecef(&from, &to, (500000., 0., 0.)).unwrap();
+    assert_abs_diff_eq!(ecef.0, 6378137. * 3f64.to_radians().cos(), epsilon = 1.0e-6);
+    assert_abs_diff_eq!(ecef.1, 6378137. * 3f64.to_radians().sin(), epsilon = 1.0e-6);
+    assert_abs_diff_eq!(ecef.2, 0., epsilon = 1.0e-6);
+    assert_abs_diff_eq!(pt.0.to_degrees(), 3., epsilon = 1.0e-10);
+}
+
+#[test]
+fn test_accuracy_estimate() {
+    let wgs84 = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let utm = Proj::from_proj_string("+proj=utm +zone=31 +ellps=GRS80 +towgs84=0,0,0").unwrap();
+    let ed50 = Proj::from_proj_string("+proj=latlong +ellps=intl +towgs84=-87,-98,-121").unwrap();
+    let ed50_7 = Proj::from_proj_string(
+        "+proj=latlong +ellps=intl +towgs84=-89.5,-93.8,-123.1,0,0,-0.156,1.2",
+    )
+    .unwrap();
+    let unknown = Proj::from_proj_string("+proj=latlong +ellps=intl").unwrap();
+
+    // No datum shift
+    assert_eq!(accuracy_estimate(&wgs84, &utm), Some(0.));
+    assert_eq!(accuracy_estimate(&ed50, &ed50), Some(0.));
+
+    let acc_3 = accuracy_estimate(&ed50, &wgs84).unwrap();
+    let acc_7 = accuracy_estimate(&ed50_7, &wgs84).unwrap();
+    assert!(acc_7 < acc_3);
+    assert!(accuracy_estimate(&ed50, &ed50_7).unwrap() > acc_3);
+
+    assert_eq!(accuracy_estimate(&unknown, &wgs84), None);
+}
+
+#[test]
+fn test_transform_longlat_datum_shift() {
+    let wgs84 = Proj::from_proj_string("+proj=longlat +datum=WGS84").unwrap();
+    let ed50 = Proj::from_proj_string("+proj=longlat +ellps=intl +towgs84=-87,-98,-121").unwrap();
+
+    // The shift goes through geocentric coordinates: the ED50 height
+    // of points on the WGS84 ellipsoid is not zero
+    let inputs = [
+        (
+            (2., 49.),
+            (2.0012969751713916, 49.000914482468566, -48.42551574449049),
+        ),
+        (
+            (-3.7, 40.4),
+            (-3.698781905245239, 40.40117177982393, -72.97024527759664),
+        ),
+        (
+            (12.5, 41.9),
+            (12.50092606315588, 41.90099008126812, -50.508506859957414),
+        ),
+    ];
+
+    for ((lon, lat), (lon_ed50, lat_ed50, h_ed50)) in inputs {
+        let (lon, lat): (f64, f64) = (lon, lat);
+        let mut pt = (lon.to_radians(), lat.to_radians(), 0.);
+        transform(&wgs84, &ed50, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0.to_degrees(), lon_ed50, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(pt.1.to_degrees(), lat_ed50, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(pt.2, h_ed50, epsilon = 1.0e-6);
+
+        transform(&ed50, &wgs84, &mut pt).unwrap();
+        assert_abs_diff_eq!(pt.0.to_degrees(), lon, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(pt.1.to_degrees(), lat, epsilon = 1.0e-10);
+        assert_abs_diff_eq!(pt.2, 0., epsilon = 1.0e-6);
+    }
+
+    // The null grid leaves geographic coordinates unchanged
+    let null = Proj::from_proj_string("+proj=longlat +ellps=GRS80 +nadgrids=@null").unwrap();
+    let mut pt = (2f64.to_radians(), 49f64.to_radians(), 0.);
+    transform(&wgs84, &null, &mut pt).unwrap();
+    assert_abs_diff_eq!(pt.0.to_degrees(), 2., epsilon = 1.0e-10);
+    assert_abs_diff_eq!(pt.1.to_degrees(), 49., epsilon = 1.0e-10);
+}
+
+#[test]
+fn test_transform_cache() {
+    let wgs84 = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let utm = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+    // Same definition as `utm`
+    let utm2 = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84 +no_defs").unwrap();
+
+    let mut cache = TransformCache::new();
+    assert!(cache.is_empty());
+
+    let t1 = cache.get(&wgs84, &utm).unwrap();
+    let t2 = cache.get(&wgs84, &utm2).unwrap();
+    assert!(std::sync::Arc::ptr_eq(&t1, &t2));
+    assert_eq!(cache.len(), 1);
+
+    // The reverse transformation is another entry
+    let t3 = cache.get(&utm, &wgs84).unwrap();
+    assert!(!std::sync::Arc::ptr_eq(&t1, &t3));
+    assert_eq!(cache.len(), 2);
+
+    // Same results as the transform function
+    let mut pt1 = (3f64.to_radians(), 45f64.to_radians(), 0.);
+    let mut pt2 = pt1;
+    t2.transform(&mut pt1).unwrap();
+    transform(&wgs84, &utm, &mut pt2).unwrap();
+    assert_eq!(pt1, pt2);
+
+    // Equivalent definitions written differently share the same entry
+    let utm3 = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84 +units=m").unwrap();
+    let t4 = cache.get(&wgs84, &utm3).unwrap();
+    assert!(std::sync::Arc::ptr_eq(&t1, &t4));
+    assert_eq!(cache.len(), 2);
+
+    // The datum shift is decided once
+    assert!(!t1.datum_shift());
+    let ed50 = Proj::from_proj_string("+proj=latlong +ellps=intl +towgs84=-87,-98,-121").unwrap();
+    assert!(cache.get(&ed50, &utm).unwrap().datum_shift());
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_transform_array_many_points() {
+    const N: usize = 100_000;
+
+    let from = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let to = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+
+    let points: Vec<(f64, f64, f64)> = (0..N)
+        .map(|i| {
+            let t = i as f64 / N as f64;
+            ((6. * t).to_radians(), (80. * t).to_radians(), 0.)
+        })
+        .collect();
+
+    // Per point
+    let expected: Vec<_> = points
+        .iter()
+        .map(|pt| {
+            let mut pt = *pt;
+            transform(&from, &to, &mut pt).unwrap();
+            pt
+        })
+        .collect();
+
+    // Batch
+    let mut xs: Vec<_> = points.iter().map(|pt| pt.0).collect();
+    let mut ys: Vec<_> = points.iter().map(|pt| pt.1).collect();
+    let mut zs: Vec<_> = points.iter().map(|pt| pt.2).collect();
+    transform_array(&from, &to, &mut xs, &mut ys, &mut zs, OnError::FailFast).unwrap();
+
+    expected.iter().enumerate().for_each(|(i, pt)| {
+        assert_eq!((xs[i], ys[i], zs[i]), *pt);
+    });
+}
+
+#[test]
+fn test_transform_array_on_error() {
+    let from = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let to = Proj::from_proj_string("+proj=merc +datum=WGS84").unwrap();
+
+    // The pole cannot be projected with merc
+    let input = (
+        [0., 0.1, 0.2],
+        [0.5, std::f64::consts::FRAC_PI_2, -0.5],
+        [0., 0., 0.],
+    );
+
+    let (mut xs, mut ys, mut zs) = input;
+    assert!(matches!(
+        transform_array(&from, &to, &mut xs, &mut ys, &mut zs, OnError::FailFast),
+        Err(Error::ToleranceConditionError)
+    ));
+
+    let (mut xs, mut ys, mut zs) = input;
+    transform_array(&from, &to, &mut xs, &mut ys, &mut zs, OnError::SetNaN).unwrap();
+    assert!(xs[1].is_nan() && ys[1].is_nan() && zs[1].is_nan());
+    for i in [0, 2] {
+        let mut pt = (input.0[i], input.1[i], input.2[i]);
+        transform(&from, &to, &mut pt).unwrap();
+        assert_eq!((xs[i], ys[i], zs[i]), pt);
+    }
+
+    // Arrays of different lengths
+    assert!(matches!(
+        transform_array(&from, &to, &mut xs, &mut ys[..2], &mut zs, OnError::SetNaN),
+        Err(Error::InvalidNumberOfCoordinates)
+    ));
+}
+
+#[test]
+fn test_transform_lenient() {
+    let from = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+    let to = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+
+    let input: Vec<(f64, f64, f64)> = (0..10)
+        .map(|i| {
+            let lat = if i == 6 { f64::NAN } else { 5. * i as f64 };
+            (3f64.to_radians(), lat.to_radians(), 0.)
+        })
+        .collect();
+
+    let mut points = input.clone();
+    let failed = transform_lenient(&from, &to, points.as_mut_slice()).unwrap();
+    assert_eq!(failed, [6]);
+
+    // The valid points are transformed
+    for (i, (pt, out)) in input.iter().zip(&points).enumerate() {
+        if i == 6 {
+            assert!(out.0.is_nan() && out.1.is_nan());
+        } else {
+            let mut pt = *pt;
+            transform(&from, &to, &mut pt).unwrap();
+            assert_eq!(*out, pt);
+        }
+    }
+
+    // Failures at different steps of the transformation
+    let from = Proj::from_proj_string("+proj=merc +datum=WGS84").unwrap();
+    let to = Proj::from_proj_string("+proj=merc +datum=WGS84 +lon_0=10").unwrap();
+    let mut points = [
+        (f64::NAN, 0., 0.),
+        (1000., 1000., 0.),
+        // Mapped to the pole, which cannot be projected back
+        (1000., 1.0e9, 0.),
+        (1000., f64::INFINITY, 0.),
+    ];
+    let failed = transform_lenient(&from, &to, &mut points[..]).unwrap();
+    assert_eq!(failed, [0, 2, 3]);
+    assert!(!points[1].0.is_nan());
+
+    // Invalid transformation
+    let no_inverse = Proj::from_proj_string("+proj=gins8 +R=6400000").unwrap();
+    assert!(matches!(
+        transform_lenient(&no_inverse, &to, &mut points[..]),
+        Err(Error::NoInverseProjectionDefined)
+    ));
+}
+
+#[test]
+fn test_transform_vunits() {
+    let geo = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+
+    // Only the height is scaled, with or without datum shift
+    for (dst, dst_ft) in [
+        (
+            "+proj=utm +zone=31 +datum=WGS84",
+            "+proj=utm +zone=31 +datum=WGS84 +vunits=ft",
+        ),
+        (
+            "+proj=latlong +ellps=intl +towgs84=-87,-98,-121",
+            "+proj=latlong +ellps=intl +towgs84=-87,-98,-121 +vunits=ft",
+        ),
+    ] {
+        let dst = Proj::from_proj_string(dst).unwrap();
+        let dst_ft = Proj::from_proj_string(dst_ft).unwrap();
+        assert_eq!(dst_ft.units(), dst.units());
+        assert_eq!(dst_ft.vertical_crs().units(), "ft");
+
+        let mut pt = (3f64.to_radians(), 45f64.to_radians(), 100.);
+        let mut pt_ft = pt;
+        transform(&geo, &dst, &mut pt).unwrap();
+        transform(&geo, &dst_ft, &mut pt_ft).unwrap();
+        assert_eq!((pt_ft.0, pt_ft.1), (pt.0, pt.1));
+        assert_abs_diff_eq!(pt_ft.2, pt.2 / 0.3048, epsilon = 1.0e-9);
+
+        // And back
+        transform(&dst_ft, &geo, &mut pt_ft).unwrap();
+        assert_abs_diff_eq!(pt_ft.2, 100., epsilon = 1.0e-6);
+    }
+}
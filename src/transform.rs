@@ -10,6 +10,9 @@ use crate::math::adjlon;
 use crate::math::consts::{EPS_12, FRAC_PI_2};
 use crate::proj::{Axis, Proj, ProjType};
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 pub trait TransformClosure: FnMut(f64, f64, f64) -> Result<(f64, f64, f64)> {}
 impl<F: FnMut(f64, f64, f64) -> Result<(f64, f64, f64)>> TransformClosure for F {}
 
@@ -85,12 +88,24 @@ where
         return Err(Error::NoForwardProjectionDefined);
     }
 
+    let datum_shift = datum_shift_required(src.datum(), dst.datum());
+    transform_steps(src, dst, datum_shift, points)
+}
+
+// Apply the transformation steps, the projections
+// have been checked by the caller
+fn transform_steps<P>(src: &Proj, dst: &Proj, datum_shift: bool, points: &mut P) -> Result<()>
+where
+    P: Transform + ?Sized,
+{
     adjust_axes(src, Inverse, points)?;
     height_unit(src, Inverse, points)?;
     projected_to_geographic(src, points)?;
     prime_meridian(src, Inverse, points)?;
 
-    datum_transform(src, dst, points)?;
+    if datum_shift {
+        datum_transform(src, dst, points)?;
+    }
 
     prime_meridian(dst, Forward, points)?;
     geographic_to_projected(dst, points)?;
@@ -100,9 +115,417 @@ where
 
     Ok(())
 }
+
+/// Transform a single point from `src` to `dst` CRS and return the
+/// intermediate geocentric (ECEF) coordinates along with the result
+///
+/// The geocentric pivot is expressed in meters relative to WGS84,
+/// which is the hub used for datum shifts: this is useful for
+/// checking `towgs84` parameters. If the source datum is unknown,
+/// the pivot is relative to the source ellipsoid.
+///
+/// Return `(pivot, result)`.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::transform::transform_via_ecef;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +ellps=bessel +towgs84=100,200,300").unwrap();
+/// let dst = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+///
+/// let (ecef, _) = transform_via_ecef(&src, &dst, (0., 0., 0.)).unwrap();
+/// assert_eq!(ecef, (6377397.155 + 100., 200., 300.));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn transform_via_ecef(
+    src: &Proj,
+    dst: &Proj,
+    pt: (f64, f64, f64),
+) -> Result<((f64, f64, f64), (f64, f64, f64))> {
+    let mut result = pt;
+    transform(src, dst, &mut result)?;
+
+    let mut pivot = pt;
+    adjust_axes(src, Inverse, &mut pivot)?;
+    height_unit(src, Inverse, &mut pivot)?;
+    projected_to_geographic(src, &mut pivot)?;
+    prime_meridian(src, Inverse, &mut pivot)?;
+
+    let (x, y, z) = pivot;
+    Ok((src.datum().to_geocentric_pivot(x, y, z)?, result))
+}
+
+/// Return an approximate accuracy, in meters, of the transformation
+/// from `src` to `dst`
+///
+/// This mirrors the accuracy metadata of PROJ operations: grid based
+/// shifts are accurate to about 0.05 m, 7-parameter shifts to about 1 m and
+/// 3-parameter shifts to about 5 m. The transformation is exact if no
+/// datum shift is required.
+///
+/// Return `None` if the accuracy is unknown, i.e. one of the datums
+/// is unknown.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::transform::accuracy_estimate;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+/// assert_eq!(accuracy_estimate(&src, &dst), Some(0.));
+/// ```
+pub fn accuracy_estimate(src: &Proj, dst: &Proj) -> Option<f64> {
+    let (src_datum, dst_datum) = (src.datum(), dst.datum());
+    if src_datum.is_identical_to(dst_datum) {
+        Some(0.)
+    } else {
+        // Errors of both conversions to the WGS84 hub add up
+        Some(src_datum.accuracy()? + dst_datum.accuracy()?)
+    }
+}
+
+/// Return true if the transformation from `src` to `dst` depends
+/// on the height of the input coordinates
+///
+/// This is the case when a datum shift is applied or when one
+/// of the projections is geocentric.
+pub fn requires_height(src: &Proj, dst: &Proj) -> bool {
+    src.is_geocent() || dst.is_geocent() || datum_shift_required(src.datum(), dst.datum())
+}
+
+/// Transform 2D coordinates from `src` to `dst` CRS.
+///
+/// The height of the coordinates is assumed to be zero. In `strict` mode,
+/// [`Error::HeightRequired`] is returned if the transformation
+/// depends on the height (see [`requires_height`]).
+pub fn transform_2d<P>(src: &Proj, dst: &Proj, points: &mut P, strict: bool) -> Result<()>
+where
+    P: Transform + ?Sized,
+{
+    if strict && requires_height(src, dst) {
+        return Err(Error::HeightRequired);
+    }
+    transform(src, dst, points)
+}
+
+/// Transform coordinates from `src` to `dst` CRS, skipping the
+/// coordinates that fail
+///
+/// Unlike [`transform`], an error on a coordinate does not stop the
+/// processing: the coordinates that fail, including those with a NaN
+/// `x` or `y` value, are set to NaN and the others are transformed.
+///
+/// Return the indices of the failed coordinates, in the order the
+/// coordinates are visited. An error is returned only if the
+/// transformation itself is invalid.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::transform::transform_lenient;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+///
+/// let mut points = [(0.05, 0., 0.), (f64::NAN, 0., 0.), (0.06, 0., 0.)];
+/// let failed = transform_lenient(&src, &dst, &mut points[..]).unwrap();
+/// assert_eq!(failed, [1]);
+/// ```
+pub fn transform_lenient<P>(src: &Proj, dst: &Proj, points: &mut P) -> Result<Vec<usize>>
+where
+    P: Transform + ?Sized,
+{
+    let mut lenient = Lenient {
+        points,
+        failed: vec![],
+    };
+    transform(src, dst, &mut lenient)?;
+    Ok(lenient.failed)
+}
+
+struct Lenient<'a, P: ?Sized> {
+    points: &'a mut P,
+    // Sorted indices of failed coordinates
+    failed: Vec<usize>,
+}
+
+impl<P: Transform + ?Sized> Transform for Lenient<'_, P> {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        const FAILED: (f64, f64, f64) = (f64::NAN, f64::NAN, f64::NAN);
+
+        let failed = &mut self.failed;
+        let (mut index, mut pos) = (0, 0);
+        self.points.transform_coordinates(&mut |x, y, z| {
+            let i = index;
+            index += 1;
+            // Failed at a previous step
+            if failed.get(pos) == Some(&i) {
+                pos += 1;
+                return Ok(FAILED);
+            }
+            let rv = if x.is_nan() || y.is_nan() {
+                Err(Error::NanCoordinateValue)
+            } else {
+                f(x, y, z)
+            };
+            match rv {
+                Ok((x, y, z)) if !(x.is_nan() || y.is_nan()) => Ok((x, y, z)),
+                _ => {
+                    failed.insert(pos, i);
+                    pos += 1;
+                    Ok(FAILED)
+                }
+            }
+        })
+    }
+}
+
+// ---------------------------------
+// Batch transformation
+// ---------------------------------
+
+/// Error strategy of [`transform_array`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OnError {
+    /// Stop at the first point that fails and return the error
+    ///
+    /// The arrays are left partially transformed: each step of the
+    /// transformation is applied to the whole arrays, so the points
+    /// may be at different steps of the transformation.
+    #[default]
+    FailFast,
+    /// Set the coordinates of the points that fail to NaN and continue
+    SetNaN,
+}
+
+struct Arrays<'a> {
+    xs: &'a mut [f64],
+    ys: &'a mut [f64],
+    zs: &'a mut [f64],
+    on_error: OnError,
+}
+
+impl Transform for Arrays<'_> {
+    fn transform_coordinates<F: TransformClosure>(&mut self, f: &mut F) -> Result<()> {
+        let mut points = self
+            .xs
+            .iter_mut()
+            .zip(self.ys.iter_mut())
+            .zip(self.zs.iter_mut());
+        match self.on_error {
+            OnError::FailFast => points.try_for_each(|((x, y), z)| {
+                (*x, *y, *z) = f(*x, *y, *z)?;
+                Ok(())
+            }),
+            OnError::SetNaN => {
+                points
+                    // Skip points failed at a previous step
+                    .filter(|((x, y), z)| !(x.is_nan() && y.is_nan() && z.is_nan()))
+                    .for_each(|((x, y), z)| {
+                        (*x, *y, *z) = f(*x, *y, *z).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+                    });
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Transform coordinates stored in separate arrays from `src`
+/// to `dst` CRS
+///
+/// The projections are checked once and each step of the
+/// transformation is applied to the whole arrays, which is faster
+/// than transforming the points one by one.
+///
+/// Error on a single point is handled according to `on_error`:
+/// with [`OnError::SetNaN`], all the coordinates of the points
+/// that fail are set to NaN. With [`OnError::FailFast`], the content
+/// of the arrays is unspecified when an error is returned.
+///
+/// Return [`Error::InvalidNumberOfCoordinates`] if the arrays
+/// have different lengths.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::transform::{transform_array, OnError};
+///
+/// let src = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+///
+/// let mut xs = [3f64.to_radians(), 1000.];
+/// let mut ys = [0., 0.];
+/// let mut zs = [0., 0.];
+///
+/// transform_array(&src, &dst, &mut xs, &mut ys, &mut zs, OnError::SetNaN).unwrap();
+/// assert!((xs[0] - 500000.).abs() < 1.0e-6);
+/// assert!(xs[1].is_nan());
+/// ```
+pub fn transform_array(
+    src: &Proj,
+    dst: &Proj,
+    xs: &mut [f64],
+    ys: &mut [f64],
+    zs: &mut [f64],
+    on_error: OnError,
+) -> Result<()> {
+    if xs.len() != ys.len() || xs.len() != zs.len() {
+        return Err(Error::InvalidNumberOfCoordinates);
+    }
+    transform(
+        src,
+        dst,
+        &mut Arrays {
+            xs,
+            ys,
+            zs,
+            on_error,
+        },
+    )
+}
+
+// ---------------------------------
+// Transformation cache
+// ---------------------------------
+
+/// A prepared transformation between two CRS
+///
+/// The availability of the inverse projection of the source and
+/// of the forward projection of the destination, and the need for
+/// a datum shift, are checked once when the transformation is created.
+#[derive(Debug)]
+pub struct Transformation {
+    src: Proj,
+    dst: Proj,
+    datum_shift: bool,
+}
+
+impl Transformation {
+    /// Prepare the transformation from `src` to `dst`
+    pub fn new(src: &Proj, dst: &Proj) -> Result<Self> {
+        if !src.has_inverse() {
+            return Err(Error::NoInverseProjectionDefined);
+        }
+        if !dst.has_forward() {
+            return Err(Error::NoForwardProjectionDefined);
+        }
+        Ok(Self {
+            src: src.clone(),
+            dst: dst.clone(),
+            datum_shift: datum_shift_required(src.datum(), dst.datum()),
+        })
+    }
+
+    /// Return the source CRS
+    pub fn src(&self) -> &Proj {
+        &self.src
+    }
+
+    /// Return the destination CRS
+    pub fn dst(&self) -> &Proj {
+        &self.dst
+    }
+
+    /// Return true if a datum shift is applied
+    pub fn datum_shift(&self) -> bool {
+        self.datum_shift
+    }
+
+    /// Transform `points` from the source to the destination CRS
+    pub fn transform<P>(&self, points: &mut P) -> Result<()>
+    where
+        P: Transform + ?Sized,
+    {
+        transform_steps(&self.src, &self.dst, self.datum_shift, points)
+    }
+}
+
+/// Cache of prepared transformations
+///
+/// Transformations are looked up by equivalence of the source and
+/// destination CRS (see [`Proj::is_equivalent_to`]): equivalent
+/// definitions that are not written the same way share the same
+/// transformation. The canonical projstrings of the CRS (see
+/// [`Proj::to_proj_string`]) are remembered, so that looking up
+/// the same definitions again does not compare the projections.
+///
+/// ```rust
+/// use proj4rs::Proj;
+/// use proj4rs::transform::TransformCache;
+///
+/// let src = Proj::from_proj_string("+proj=latlong +datum=WGS84").unwrap();
+/// let dst = Proj::from_proj_string("+proj=utm +zone=31 +datum=WGS84").unwrap();
+///
+/// let mut cache = TransformCache::new();
+/// let t = cache.get(&src, &dst).unwrap();
+///
+/// let mut pt = (3f64.to_radians(), 0., 0.);
+/// t.transform(&mut pt).unwrap();
+/// assert!((pt.0 - 500000.).abs() < 1.0e-6);
+/// ```
+#[derive(Debug, Default)]
+pub struct TransformCache {
+    entries: Vec<Arc<Transformation>>,
+    // Canonical projstrings of the looked up CRS
+    keys: HashMap<(String, String), Arc<Transformation>>,
+}
+
+impl TransformCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the transformation from `src` to `dst`, preparing it on
+    /// first use
+    pub fn get(&mut self, src: &Proj, dst: &Proj) -> Result<Arc<Transformation>> {
+        let key = (src.to_proj_string(), dst.to_proj_string());
+        if let Some(t) = self.keys.get(&key) {
+            return Ok(t.clone());
+        }
+        let t = match self
+            .entries
+            .iter()
+            .find(|t| t.src.is_equivalent_to(src) && t.dst.is_equivalent_to(dst))
+        {
+            Some(t) => t.clone(),
+            None => {
+                let t = Arc::new(Transformation::new(src, dst)?);
+                self.entries.push(t.clone());
+                t
+            }
+        };
+        self.keys.insert(key, t.clone());
+        Ok(t)
+    }
+
+    /// Return the number of cached transformations
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all cached transformations
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.keys.clear();
+    }
+}
+
 // ---------------------------------
 // Datum transformation
 // ---------------------------------
+
+// Return false if the datums are identical is respect
+// to datum transformation.
+// As of PROJ 4 behavior, we prevent datum transformation
+// if either the source or destination are of an unknown datum type.
+fn datum_shift_required(src_datum: &Datum, dst_datum: &Datum) -> bool {
+    !(src_datum.no_datum() || dst_datum.no_datum() || src_datum.is_identical_to(dst_datum))
+}
+
+// Apply the datum shift, required as checked by `datum_shift_required`
 fn datum_transform<P>(src: &Proj, dst: &Proj, points: &mut P) -> Result<()>
 where
     P: Transform + ?Sized,
@@ -110,14 +533,6 @@ where
     let src_datum = src.datum();
     let dst_datum = dst.datum();
 
-    // Return true if the datums are identical is respect
-    // to datum transformation.
-    // As of PROJ 4 behavior, we prevent datum transformation
-    // if either the source or destination are of an unknown datum type.
-    if src_datum.no_datum() || dst_datum.no_datum() || src_datum.is_identical_to(dst_datum) {
-        return Ok(());
-    }
-
     points.transform_coordinates(&mut |x, y, z| Datum::transform(src_datum, dst_datum, x, y, z))
 }
 // ---------------------------------
@@ -323,8 +738,8 @@ fn normalize_axis<P: Transform + ?Sized>(axis: &Axis, points: &mut P) -> Result<
         let (mut x_out, mut y_out, mut z_out) = (x, y, z);
         axis.iter().enumerate().for_each(|(i, axe)| {
             let value = match i {
-                1 => x,
-                2 => y,
+                0 => x,
+                1 => y,
                 _ => z,
             };
             match axe {
@@ -359,8 +774,8 @@ fn denormalize_axis<P: Transform + ?Sized>(axis: &Axis, points: &mut P) -> Resul
                 _ => unreachable!(),
             };
             match i {
-                1 => x_out = value,
-                2 => y_out = value,
+                0 => x_out = value,
+                1 => y_out = value,
                 _ => z_out = value,
             }
         });
@@ -391,3 +806,39 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::consts::PI;
+    use crate::nadgrids::{catalog, Grid, GridId, Lp};
+
+    #[test]
+    fn accuracy_estimate_grids() {
+        // Null shift grid covering the whole world
+        let lp = |lam: f64, phi: f64| Lp { lam, phi };
+        let grid = Grid {
+            id: GridId::from(*b"ACCURACY"),
+            lineage: GridId::root(),
+            ll: lp(-PI, -FRAC_PI_2),
+            ur: lp(PI, FRAC_PI_2),
+            del: lp(PI, FRAC_PI_2),
+            lim: lp(3., 3.),
+            epsilon: 1.0e-4,
+            cvs: (0..9).map(|_| lp(0., 0.)).collect(),
+        };
+        catalog::add_grid("accuracy_estimate.gsb".into(), grid).unwrap();
+
+        let src =
+            Proj::from_proj_string("+proj=latlong +ellps=intl +nadgrids=accuracy_estimate.gsb")
+                .unwrap();
+        let src_3 =
+            Proj::from_proj_string("+proj=latlong +ellps=intl +towgs84=-87,-98,-121").unwrap();
+        let dst = Proj::from_proj_string("+proj=latlong +ellps=GRS80 +towgs84=0,0,0").unwrap();
+
+        // Grid shifts are more accurate than 3-parameter shifts
+        let grid_accuracy = accuracy_estimate(&src, &dst).unwrap();
+        assert!(grid_accuracy > 0.);
+        assert!(grid_accuracy < accuracy_estimate(&src_3, &dst).unwrap());
+    }
+}